@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use crate::domain::entities::attachment::{Attachment, AttachmentFile, AttachmentID, AttachmentUpload};
+use crate::domain::entities::auth::AuthUser;
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[async_trait]
+pub trait AttachmentUseCase: Send + Sync {
+    // ขนาดไฟล์สูงสุด (byte) ให้ handler หยุดอ่าน multipart ทันทีที่เกิน
+    fn max_size_bytes(&self) -> usize;
+    // ตรวจสิทธิ์อัปโหลดก่อน handler อ่านไฟล์ เพื่อไม่ให้ผู้ไม่มีสิทธิ์ส่งไฟล์ใหญ่มาค้างใน memory
+    async fn authorize_upload(&self, task_id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn list_attachment(&self, task_id: i64, actor: &AuthUser) -> Result<Vec<Attachment>, CustomError>;
+    async fn upload_attachment(&self, upload: AttachmentUpload, actor: &AuthUser) -> Result<AttachmentID, CustomError>;
+    async fn download_attachment(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<AttachmentFile, CustomError>;
+    async fn delete_attachment(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+}
@@ -1,8 +1,19 @@
 use async_trait::async_trait;
-use crate::domain::entities::auth::{Login, LoginToken};
+use crate::domain::entities::auth::{AuthUser, ChangePassword, Login, LoginToken, Logout, Register, ResetPassword, UpdateUser, UserCreateEntity, UserID, UserProfile};
 use crate::shared::exceptions::custom_error::CustomError;
 
 #[async_trait]
 pub trait AuthUseCase {
     async fn login(&self, payload: Login) -> Result<LoginToken, CustomError>;
-}
\ No newline at end of file
+    async fn refresh(&self, refresh_token: String) -> Result<LoginToken, CustomError>;
+    async fn logout(&self, payload: Logout) -> Result<(), CustomError>;
+    async fn register(&self, payload: Register) -> Result<UserID, CustomError>;
+    async fn me(&self, actor: &AuthUser) -> Result<UserProfile, CustomError>;
+    async fn change_password(&self, payload: ChangePassword) -> Result<(), CustomError>;
+    async fn list_users(&self, actor: &AuthUser) -> Result<Vec<UserProfile>, CustomError>;
+    async fn get_user(&self, id: i64, actor: &AuthUser) -> Result<UserProfile, CustomError>;
+    async fn create_user(&self, payload: UserCreateEntity, actor: &AuthUser) -> Result<UserID, CustomError>;
+    async fn update_user(&self, payload: UpdateUser, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn deactivate_user(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn reset_password(&self, payload: ResetPassword, actor: &AuthUser) -> Result<(), CustomError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::domain::entities::auth::AuthUser;
+use crate::domain::entities::comment::{CommentCreateEntity, CommentFilter, CommentID, CommentPage, UpdateComment};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[async_trait]
+pub trait CommentUseCase: Send + Sync {
+    async fn list_comment(&self, filter: CommentFilter, actor: &AuthUser) -> Result<CommentPage, CustomError>;
+    async fn create_comment(&self, comment: CommentCreateEntity, actor: &AuthUser) -> Result<CommentID, CustomError>;
+    async fn update_comment(&self, task_id: i64, comment: UpdateComment, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn delete_comment(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::domain::entities::auth::AuthUser;
+use crate::domain::entities::label::{Label, LabelCreateEntity, LabelID, UpdateLabel};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[async_trait]
+pub trait LabelUseCase: Send + Sync {
+    async fn list_label(&self) -> Result<Vec<Label>, CustomError>;
+    async fn get_label(&self, id: i64) -> Result<Label, CustomError>;
+    async fn create_label(&self, label: LabelCreateEntity, actor: &AuthUser) -> Result<LabelID, CustomError>;
+    async fn update_label(&self, label: UpdateLabel, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn delete_label(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+}
@@ -1,10 +1,21 @@
 use async_trait::async_trait;
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::domain::entities::master_data::{MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus};
+use crate::domain::entities::auth::AuthUser;
+use crate::domain::entities::master_data::{
+    MasterDataCreateEntity, MasterDataDeactivate, MasterDataID, MasterDataItem, MasterDataKind, MasterDataPriorityLevels, MasterDataRole,
+    MasterDataTaskStatus, TaskStatusTransition, UpdateMasterData,
+};
 
 #[async_trait]
 pub trait MasterDataUseCase: Send + Sync {
     async fn list_task_status(&self) -> Result<Vec<MasterDataTaskStatus>, CustomError>;
     async fn list_role(&self) -> Result<Vec<MasterDataRole>, CustomError>;
     async fn list_priority_levels(&self) -> Result<Vec<MasterDataPriorityLevels>, CustomError>;
+    async fn list_task_status_transition(&self, from_status_id: i64) -> Result<Vec<TaskStatusTransition>, CustomError>;
+    async fn list_master_data(&self, kind: MasterDataKind, actor: AuthUser) -> Result<Vec<MasterDataItem>, CustomError>;
+    async fn create_master_data(&self, kind: MasterDataKind, item: MasterDataCreateEntity, actor: AuthUser) -> Result<MasterDataID, CustomError>;
+    async fn update_master_data(&self, kind: MasterDataKind, item: UpdateMasterData, actor: AuthUser) -> Result<(), CustomError>;
+    async fn activate_master_data(&self, kind: MasterDataKind, id: i64, actor: AuthUser) -> Result<(), CustomError>;
+    async fn deactivate_master_data(&self, kind: MasterDataKind, deactivate: MasterDataDeactivate, actor: AuthUser) -> Result<(), CustomError>;
+    async fn reorder_master_data(&self, kind: MasterDataKind, ids: Vec<i64>, actor: AuthUser) -> Result<Vec<MasterDataItem>, CustomError>;
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::domain::entities::auth::AuthUser;
+use crate::domain::entities::project::{Project, ProjectCreateEntity, ProjectFilter, ProjectID, ProjectMember, ProjectMemberSet, UpdateProject};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[async_trait]
+pub trait ProjectUseCase: Send + Sync {
+    async fn list_project(&self, filter: ProjectFilter, actor: &AuthUser) -> Result<Vec<Project>, CustomError>;
+    async fn get_project(&self, id: i64, actor: &AuthUser) -> Result<Project, CustomError>;
+    async fn create_project(&self, project: ProjectCreateEntity, actor: &AuthUser) -> Result<ProjectID, CustomError>;
+    async fn update_project(&self, project: UpdateProject, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn archive_project(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn unarchive_project(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn delete_project(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn list_member(&self, project_id: i64, actor: &AuthUser) -> Result<Vec<ProjectMember>, CustomError>;
+    async fn set_member(&self, member: ProjectMemberSet, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn remove_member(&self, project_id: i64, user_id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+}
@@ -1,14 +1,30 @@
 use async_trait::async_trait;
-use crate::domain::entities::task::{Task, TaskCreateEntity, TaskID, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
+use crate::domain::entities::auth::AuthUser;
+use crate::domain::entities::master_data::MasterDataRef;
+use crate::domain::entities::task::{PatchTask, Task, TaskAssign, TaskBulk, TaskBulkResult, TaskDependency, TaskHistoryFilter, TaskHistoryPage, TaskCreateEntity, TaskExpand, TaskFilter, TaskID, TaskImport, TaskImportResult, TaskLabelAdd, TaskPage, TaskSearchPage, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
 use crate::shared::exceptions::custom_error::CustomError;
 
 #[async_trait]
 pub trait TaskUseCase: Send + Sync {
-    async fn list_task(&self) -> Result<Vec<Task>, CustomError>;
-    async fn get_task(&self, id: i64) -> Result<Task, CustomError>;
-    async fn create_task(&self, task: TaskCreateEntity) -> Result<TaskID, CustomError>;
-    async fn update_task(&self, task: UpdateTask) -> Result<(), CustomError>;
-    async fn update_task_status(&self, task: UpdateTaskStatus) -> Result<(), CustomError>;
-    async fn update_task_priority_levels(&self, task: UpdateTaskPriorityLevels) -> Result<(), CustomError>;
-    async fn delete_task(&self, id: i64) -> Result<(), CustomError>;
-}
\ No newline at end of file
+    async fn list_task(&self, filter: TaskFilter, actor: &AuthUser) -> Result<TaskPage, CustomError>;
+    async fn search_task(&self, filter: TaskFilter, actor: &AuthUser) -> Result<TaskSearchPage, CustomError>;
+    async fn get_task(&self, id: i64, expand: TaskExpand, actor: &AuthUser) -> Result<Task, CustomError>;
+    async fn list_subtask(&self, task_id: i64, filter: TaskFilter, actor: &AuthUser) -> Result<TaskPage, CustomError>;
+    async fn create_task(&self, task: TaskCreateEntity<MasterDataRef>, actor: &AuthUser) -> Result<TaskID, CustomError>;
+    async fn import_task(&self, import: TaskImport<MasterDataRef>, actor: &AuthUser) -> Result<TaskImportResult, CustomError>;
+    async fn update_task(&self, task: UpdateTask<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn patch_task(&self, task: PatchTask<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn update_task_status(&self, task: UpdateTaskStatus<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn update_task_priority_levels(&self, task: UpdateTaskPriorityLevels<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn delete_task(&self, id: i64, version: Option<i32>, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn assign_task(&self, assign: TaskAssign, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn bulk_task(&self, bulk: TaskBulk<MasterDataRef>, actor: &AuthUser) -> Result<TaskBulkResult, CustomError>;
+    async fn unassign_task(&self, task_id: i64, user_id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn add_labels(&self, labels: TaskLabelAdd, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn remove_label(&self, task_id: i64, label_id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn add_dependency(&self, dependency: TaskDependency, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn remove_dependency(&self, task_id: i64, blocked_by_task_id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn list_trash(&self, filter: TaskFilter, actor: &AuthUser) -> Result<TaskPage, CustomError>;
+    async fn restore_task(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError>;
+    async fn list_task_history(&self, filter: TaskHistoryFilter, actor: &AuthUser) -> Result<TaskHistoryPage, CustomError>;
+}
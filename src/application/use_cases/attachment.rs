@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::application::interfaces::attachment::AttachmentUseCase;
+use crate::application::use_cases::task_access::{task_actor, TaskAccess};
+use crate::domain::entities::attachment::{Attachment, AttachmentCreateEntity, AttachmentFile, AttachmentID, AttachmentUpload};
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::repositories::attachment::AttachmentRepositories;
+use crate::domain::repositories::file_storage::FileStorage;
+use crate::domain::repositories::project::ProjectRepositories;
+use crate::domain::repositories::task::TaskRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{
+    ATTACHMENT_CONTENT_TYPE_NOT_ALLOWED, ATTACHMENT_FILE_REQUIRED, ATTACHMENT_NOT_FOUND, ATTACHMENT_TOO_LARGE, PERMISSION_DENIED,
+};
+
+// ความยาวชื่อไฟล์สูงสุดตาม column file_name
+const MAX_FILE_NAME_LENGTH: usize = 255;
+
+pub struct AttachmentUseCaseImpl<A: AttachmentRepositories, T: TaskRepositories, P: ProjectRepositories> {
+    repository: A,
+    task_repository: T,
+    project_repository: P,
+    storage: Arc<dyn FileStorage>,
+    max_size_bytes: usize,
+    allowed_content_types: Vec<String>,
+}
+
+impl<A: AttachmentRepositories, T: TaskRepositories, P: ProjectRepositories> AttachmentUseCaseImpl<A, T, P> {
+    pub fn new(
+        repository: A,
+        task_repository: T,
+        project_repository: P,
+        storage: Arc<dyn FileStorage>,
+        max_size_bytes: usize,
+        allowed_content_types: Vec<String>,
+    ) -> Self {
+        Self { repository, task_repository, project_repository, storage, max_size_bytes, allowed_content_types }
+    }
+
+    // ไฟล์แนบต้องเป็นของ task ใน path
+    async fn get_attachment_of(&self, task_id: i64, id: i64) -> Result<Attachment, CustomError> {
+        let attachment = self.repository.get_attachment(id).await?;
+        if attachment.task_id != task_id {
+            return Err(CustomError::NotFound(format!("{}: {}", ATTACHMENT_NOT_FOUND, id)));
+        }
+
+        Ok(attachment)
+    }
+}
+
+#[async_trait]
+impl<A: AttachmentRepositories, T: TaskRepositories, P: ProjectRepositories> AttachmentUseCase for AttachmentUseCaseImpl<A, T, P> {
+    fn max_size_bytes(&self) -> usize {
+        self.max_size_bytes
+    }
+
+    async fn authorize_upload(&self, task_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        task_actor(&self.task_repository, &self.project_repository, task_id, actor, TaskAccess::Write).await.map(|_| ())
+    }
+
+    async fn list_attachment(&self, task_id: i64, actor: &AuthUser) -> Result<Vec<Attachment>, CustomError> {
+        task_actor(&self.task_repository, &self.project_repository, task_id, actor, TaskAccess::Read).await?;
+
+        self.repository.list_attachment(task_id).await
+    }
+
+    async fn upload_attachment(&self, upload: AttachmentUpload, actor: &AuthUser) -> Result<AttachmentID, CustomError> {
+        task_actor(&self.task_repository, &self.project_repository, upload.task_id, actor, TaskAccess::Write).await?;
+
+        if upload.data.is_empty() {
+            return Err(CustomError::ValidationError(ATTACHMENT_FILE_REQUIRED.to_string()));
+        }
+        if upload.data.len() > self.max_size_bytes {
+            return Err(CustomError::PayloadTooLarge(format!("{}: {} bytes", ATTACHMENT_TOO_LARGE, self.max_size_bytes)));
+        }
+        let content_type = upload.content_type.to_lowercase();
+        if !self.allowed_content_types.contains(&content_type) {
+            return Err(CustomError::UnsupportedMediaType(format!("{}: {}", ATTACHMENT_CONTENT_TYPE_NOT_ALLOWED, content_type)));
+        }
+
+        // ชื่อไฟล์เก็บไว้แสดงผลอย่างเดียว ส่วน key ใน storage สุ่มใหม่เสมอ
+        let storage_key = format!("tasks/{}/{}", upload.task_id, hex::encode(rand::random::<[u8; 16]>()));
+        let attachment = AttachmentCreateEntity {
+            task_id: upload.task_id,
+            file_name: sanitize_file_name(&upload.file_name),
+            content_type: content_type.clone(),
+            size_bytes: upload.data.len() as i64,
+            storage_key: storage_key.clone(),
+            created_by: upload.created_by,
+        };
+
+        self.storage.put_object(&storage_key, &content_type, upload.data).await?;
+        match self.repository.create_attachment(attachment).await {
+            Ok(id) => Ok(AttachmentID { id }),
+            Err(e) => {
+                // บันทึก metadata ไม่สำเร็จ ลบไฟล์ทิ้งเพื่อไม่ให้ค้างใน storage
+                if let Err(delete_error) = self.storage.delete_object(&storage_key).await {
+                    log::warn!("Failed to delete orphan attachment file {}: {}", storage_key, delete_error);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn download_attachment(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<AttachmentFile, CustomError> {
+        task_actor(&self.task_repository, &self.project_repository, task_id, actor, TaskAccess::Read).await?;
+
+        let attachment = self.get_attachment_of(task_id, id).await?;
+        let data = self.storage.get_object(&attachment.storage_key).await?;
+
+        Ok(AttachmentFile { attachment, data })
+    }
+
+    // ลบได้เฉพาะผู้อัปโหลด หรือ Admin ของ project (รวม Admin ของระบบ)
+    async fn delete_attachment(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        let project_actor = task_actor(&self.task_repository, &self.project_repository, task_id, actor, TaskAccess::Write).await?;
+
+        let attachment = self.get_attachment_of(task_id, id).await?;
+        if attachment.created_by != actor.id && project_actor.role != Role::Admin {
+            return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+        }
+
+        // ลบ metadata ก่อน ถ้าลบไฟล์ไม่สำเร็จจะเหลือแค่ไฟล์ค้าง ไม่มี metadata ที่ชี้ไปหาไฟล์ที่หายไป
+        self.repository.delete_attachment(id).await?;
+        if let Err(e) = self.storage.delete_object(&attachment.storage_key).await {
+            log::warn!("Failed to delete attachment file {}: {}", attachment.storage_key, e);
+        }
+
+        Ok(())
+    }
+}
+
+// ตัด path ที่ติดมากับชื่อไฟล์ออก และจำกัดความยาวตาม column
+fn sanitize_file_name(file_name: &str) -> String {
+    let file_name = file_name.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    if file_name.is_empty() {
+        return "file".to_string();
+    }
+
+    file_name.chars().take(MAX_FILE_NAME_LENGTH).collect()
+}
@@ -1,36 +1,276 @@
 use async_trait::async_trait;
-use bcrypt::verify;
+use bcrypt::{hash, verify, DEFAULT_COST};
+use chrono::{Duration, Utc};
+use log::warn;
 use crate::application::interfaces::auth::AuthUseCase;
-use crate::domain::entities::auth::{Login, LoginToken};
+use crate::domain::entities::auth::{AuthUser, ChangePassword, Login, LoginToken, Logout, RefreshTokenCreate, Register, ResetPassword, Role, UpdateUser, UserCreateEntity, UserID, UserProfile};
 use crate::domain::repositories::auth::AuthRepositories;
+use crate::domain::repositories::master_data::MasterDataRepositories;
 use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{
+    CANNOT_DEACTIVATE_SELF, INVALID_CURRENT_PASSWORD, INVALID_REFRESH_TOKEN, INVALID_ROLE, PERMISSION_DENIED,
+    REFRESH_TOKEN_REUSED, REGISTRATION_DISABLED, ROLE_NOT_FOUND, USERNAME_ALREADY_EXISTS, USER_DEACTIVATED,
+};
 use crate::shared::middleware::jwt::create_token;
+use crate::shared::utils::token::{generate_token, hash_token};
 
-pub struct AuthUseCaseImpl<T: AuthRepositories> {
+pub struct AuthUseCaseImpl<T: AuthRepositories, M: MasterDataRepositories> {
     repository: T,
+    master_data_repository: M,
     jwt_secret: String,
+    refresh_token_expire_seconds: i64,
+    allow_registration: bool,
 }
 
-impl<T: AuthRepositories> AuthUseCaseImpl<T> {
-    pub fn new(repository: T, jwt_secret: String) -> Self {
-        Self { repository, jwt_secret }
+impl<T: AuthRepositories, M: MasterDataRepositories> AuthUseCaseImpl<T, M> {
+    pub fn new(
+        repository: T,
+        master_data_repository: M,
+        jwt_secret: String,
+        refresh_token_expire_seconds: i64,
+        allow_registration: bool,
+    ) -> Self {
+        Self { repository, master_data_repository, jwt_secret, refresh_token_expire_seconds, allow_registration }
+    }
+
+    // ตรวจว่า role_id ที่จะกำหนดให้ผู้ใช้งานมีอยู่จริงและ active
+    async fn ensure_role(&self, role_id: i64) -> Result<(), CustomError> {
+        let roles = self.master_data_repository.list_role().await?;
+        if !roles.iter().any(|role| role.id == role_id) {
+            return Err(CustomError::ValidationError(INVALID_ROLE.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn insert_user(&self, mut user: UserCreateEntity) -> Result<UserID, CustomError> {
+        if self.repository.username_exists(&user.username).await? {
+            return Err(CustomError::DataConflict(format!("{}: {}", USERNAME_ALREADY_EXISTS, user.username)));
+        }
+
+        user.password = hash_password(&user.password)?;
+        self.repository.create_user(user).await.map(|id| UserID { id })
+    }
+
+    // แปลง role_id ของผู้ใช้งานเป็น Role โดยอ้างอิงจาก master_data_role ที่ active อยู่
+    async fn resolve_role(&self, role_id: Option<i64>) -> Result<Role, CustomError> {
+        let role_id = role_id.ok_or(CustomError::Forbidden(ROLE_NOT_FOUND.to_string()))?;
+        let roles = self.master_data_repository.list_role().await?;
+
+        roles
+            .iter()
+            .find(|role| role.id == role_id)
+            .and_then(|role| Role::from_code(&role.code))
+            .ok_or(CustomError::Forbidden(ROLE_NOT_FOUND.to_string()))
+    }
+
+    fn new_refresh_token(&self, user_id: i64, family_id: String) -> (String, RefreshTokenCreate) {
+        let refresh_token = generate_token();
+        let entity = RefreshTokenCreate {
+            token_hash: hash_token(&refresh_token),
+            user_id,
+            family_id,
+            expires_at: (Utc::now() + Duration::seconds(self.refresh_token_expire_seconds)).naive_utc(),
+        };
+
+        (refresh_token, entity)
     }
 }
 
 #[async_trait]
-impl<T: AuthRepositories> AuthUseCase for AuthUseCaseImpl<T> {
+impl<T: AuthRepositories, M: MasterDataRepositories> AuthUseCase for AuthUseCaseImpl<T, M> {
     async fn login(&self, payload: Login) -> Result<LoginToken, CustomError> {
         let user = self.repository.user_exists(&payload.username).await?;
         let is_valid = verify(&payload.password, &user.password).map_err(|e| CustomError::BusinessError(format!("Password verification failed: {}", e)))?;
 
         if is_valid {
-            let token = create_token(user.id, self.jwt_secret.as_str());
+            if !user.active {
+                return Err(CustomError::Forbidden(USER_DEACTIVATED.to_string()));
+            }
+
+            let role = self.resolve_role(user.role_id).await?;
+            let token = create_token(user.id, role, self.jwt_secret.as_str());
+
+            // login แต่ละครั้งเริ่ม family ใหม่ของ refresh token
+            let (refresh_token, entity) = self.new_refresh_token(user.id, generate_token());
+            self.repository.create_refresh_token(entity).await?;
 
             Ok(LoginToken {
                 token,
+                refresh_token,
             })
         } else {
             Err(CustomError::Unauthorized("Invalid credentials".to_string()))
         }
     }
+
+    async fn refresh(&self, refresh_token: String) -> Result<LoginToken, CustomError> {
+        let token_hash = hash_token(&refresh_token);
+        let current = self
+            .repository
+            .get_refresh_token(&token_hash)
+            .await?
+            .ok_or(CustomError::Unauthorized(INVALID_REFRESH_TOKEN.to_string()))?;
+
+        // token ที่ถูก rotate/ยกเลิกไปแล้วถูกนำกลับมาใช้ซ้ำ ถือว่าถูกขโมย ยกเลิกทั้ง family
+        if current.revoked_at.is_some() {
+            warn!("Refresh token reuse detected for user {}", current.user_id);
+            self.repository.revoke_refresh_token_family(&current.family_id).await?;
+            return Err(CustomError::Unauthorized(REFRESH_TOKEN_REUSED.to_string()));
+        }
+
+        if current.expires_at <= Utc::now().naive_utc() {
+            return Err(CustomError::Unauthorized(INVALID_REFRESH_TOKEN.to_string()));
+        }
+
+        // โหลด role ใหม่ทุกครั้ง เผื่อมีการเปลี่ยน role ระหว่างทาง
+        let user = self.repository.get_user(current.user_id).await?;
+        if !user.active {
+            return Err(CustomError::Forbidden(USER_DEACTIVATED.to_string()));
+        }
+        let role = self.resolve_role(user.role_id).await?;
+
+        let (new_refresh_token, entity) = self.new_refresh_token(user.id, current.family_id.clone());
+        if !self.repository.rotate_refresh_token(&token_hash, entity).await? {
+            warn!("Refresh token reuse detected for user {}", current.user_id);
+            self.repository.revoke_refresh_token_family(&current.family_id).await?;
+            return Err(CustomError::Unauthorized(REFRESH_TOKEN_REUSED.to_string()));
+        }
+
+        Ok(LoginToken {
+            token: create_token(user.id, role, self.jwt_secret.as_str()),
+            refresh_token: new_refresh_token,
+        })
+    }
+
+    async fn logout(&self, payload: Logout) -> Result<(), CustomError> {
+        self.repository.revoke_access_token(&payload.jti, payload.user_id, payload.expires_at).await?;
+
+        if let Some(refresh_token) = payload.refresh_token {
+            // ยกเลิกเฉพาะ token ของผู้ใช้งานคนนี้เท่านั้น
+            if let Some(current) = self.repository.get_refresh_token(&hash_token(&refresh_token)).await? {
+                if current.user_id == payload.user_id {
+                    self.repository.revoke_refresh_token_family(&current.family_id).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn register(&self, payload: Register) -> Result<UserID, CustomError> {
+        if !self.allow_registration {
+            return Err(CustomError::Forbidden(REGISTRATION_DISABLED.to_string()));
+        }
+
+        // สมัครเองได้ role Member เสมอ
+        let roles = self.master_data_repository.list_role().await?;
+        let member_role = roles
+            .iter()
+            .find(|role| Role::from_code(&role.code) == Some(Role::Member))
+            .ok_or(CustomError::SystemError(ROLE_NOT_FOUND.to_string()))?;
+
+        self.insert_user(UserCreateEntity {
+            username: payload.username,
+            password: payload.password,
+            role_id: member_role.id,
+            created_by: 0,
+        }).await
+    }
+
+    async fn me(&self, actor: &AuthUser) -> Result<UserProfile, CustomError> {
+        self.repository.get_user_profile(actor.id).await
+    }
+
+    async fn change_password(&self, payload: ChangePassword) -> Result<(), CustomError> {
+        let user = self.repository.get_user(payload.user_id).await?;
+        let is_valid = verify(&payload.current_password, &user.password).map_err(|e| CustomError::BusinessError(format!("Password verification failed: {}", e)))?;
+        if !is_valid {
+            return Err(CustomError::ValidationError(INVALID_CURRENT_PASSWORD.to_string()));
+        }
+
+        self.repository.update_password(user.id, hash_password(&payload.new_password)?, user.id).await?;
+
+        // บังคับให้ทุกอุปกรณ์ login ใหม่หลังเปลี่ยนรหัสผ่าน
+        self.repository.revoke_user_refresh_tokens(user.id).await
+    }
+
+    async fn list_users(&self, actor: &AuthUser) -> Result<Vec<UserProfile>, CustomError> {
+        ensure_admin(actor)?;
+
+        self.repository.list_users().await
+    }
+
+    async fn get_user(&self, id: i64, actor: &AuthUser) -> Result<UserProfile, CustomError> {
+        ensure_admin(actor)?;
+
+        self.repository.get_user_profile(id).await
+    }
+
+    async fn create_user(&self, payload: UserCreateEntity, actor: &AuthUser) -> Result<UserID, CustomError> {
+        ensure_admin(actor)?;
+        self.ensure_role(payload.role_id).await?;
+
+        self.insert_user(payload).await
+    }
+
+    async fn update_user(&self, payload: UpdateUser, actor: &AuthUser) -> Result<(), CustomError> {
+        ensure_admin(actor)?;
+        if payload.id == actor.id && !payload.active {
+            return Err(CustomError::BusinessError(CANNOT_DEACTIVATE_SELF.to_string()));
+        }
+
+        self.repository.get_user_profile(payload.id).await?;
+        self.ensure_role(payload.role_id).await?;
+
+        let user_id = payload.id;
+        let active = payload.active;
+        self.repository.update_user(payload).await?;
+
+        if !active {
+            self.repository.revoke_user_refresh_tokens(user_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn deactivate_user(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        ensure_admin(actor)?;
+        if id == actor.id {
+            return Err(CustomError::BusinessError(CANNOT_DEACTIVATE_SELF.to_string()));
+        }
+
+        let user = self.repository.get_user_profile(id).await?;
+        let role_id = user.role_id.ok_or(CustomError::BusinessError(ROLE_NOT_FOUND.to_string()))?;
+
+        self.repository.update_user(UpdateUser {
+            id,
+            role_id,
+            active: false,
+            updated_by: actor.id,
+        }).await?;
+
+        self.repository.revoke_user_refresh_tokens(id).await
+    }
+
+    async fn reset_password(&self, payload: ResetPassword, actor: &AuthUser) -> Result<(), CustomError> {
+        ensure_admin(actor)?;
+
+        self.repository.get_user_profile(payload.id).await?;
+        self.repository.update_password(payload.id, hash_password(&payload.new_password)?, payload.updated_by).await?;
+
+        self.repository.revoke_user_refresh_tokens(payload.id).await
+    }
+}
+
+fn ensure_admin(actor: &AuthUser) -> Result<(), CustomError> {
+    if actor.role != Role::Admin {
+        return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+    }
+
+    Ok(())
+}
+
+fn hash_password(password: &str) -> Result<String, CustomError> {
+    hash(password, DEFAULT_COST).map_err(|e| CustomError::InternalError(format!("Password hashing failed: {}", e)))
 }
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use crate::application::interfaces::comment::CommentUseCase;
+use crate::application::use_cases::task_access::{task_actor, TaskAccess};
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::entities::comment::{Comment, CommentCreateEntity, CommentFilter, CommentID, CommentPage, UpdateComment};
+use crate::domain::repositories::comment::CommentRepositories;
+use crate::domain::repositories::project::ProjectRepositories;
+use crate::domain::repositories::task::TaskRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{COMMENT_NOT_FOUND, PERMISSION_DENIED};
+
+pub struct CommentUseCaseImpl<C: CommentRepositories, T: TaskRepositories, P: ProjectRepositories> {
+    repository: C,
+    task_repository: T,
+    project_repository: P,
+}
+
+impl<C: CommentRepositories, T: TaskRepositories, P: ProjectRepositories> CommentUseCaseImpl<C, T, P> {
+    pub fn new(repository: C, task_repository: T, project_repository: P) -> Self {
+        Self { repository, task_repository, project_repository }
+    }
+
+    // แก้ไข/ลบได้เฉพาะผู้เขียน หรือ Admin ของ project (รวม Admin ของระบบ)
+    async fn get_comment_for(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<Comment, CustomError> {
+        let project_actor = task_actor(&self.task_repository, &self.project_repository, task_id, actor, TaskAccess::Write).await?;
+
+        let comment = self.repository.get_comment(id).await?;
+        if comment.task_id != task_id {
+            return Err(CustomError::NotFound(format!("{}: {}", COMMENT_NOT_FOUND, id)));
+        }
+        if comment.created_by != actor.id && project_actor.role != Role::Admin {
+            return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+        }
+
+        Ok(comment)
+    }
+}
+
+#[async_trait]
+impl<C: CommentRepositories, T: TaskRepositories, P: ProjectRepositories> CommentUseCase for CommentUseCaseImpl<C, T, P> {
+    async fn list_comment(&self, filter: CommentFilter, actor: &AuthUser) -> Result<CommentPage, CustomError> {
+        task_actor(&self.task_repository, &self.project_repository, filter.task_id, actor, TaskAccess::Read).await?;
+
+        self.repository.list_comment(filter).await
+    }
+
+    async fn create_comment(&self, comment: CommentCreateEntity, actor: &AuthUser) -> Result<CommentID, CustomError> {
+        task_actor(&self.task_repository, &self.project_repository, comment.task_id, actor, TaskAccess::Write).await?;
+
+        self.repository.create_comment(comment).await.map(|id| CommentID { id })
+    }
+
+    async fn update_comment(&self, task_id: i64, comment: UpdateComment, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_comment_for(task_id, comment.id, actor).await?;
+
+        self.repository.update_comment(comment).await
+    }
+
+    async fn delete_comment(&self, task_id: i64, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_comment_for(task_id, id, actor).await?;
+
+        self.repository.delete_comment(id).await
+    }
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use crate::application::interfaces::label::LabelUseCase;
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::entities::label::{Label, LabelCreateEntity, LabelID, UpdateLabel};
+use crate::domain::repositories::label::LabelRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{INVALID_LABEL_COLOR, INVALID_LABEL_NAME, LABEL_ALREADY_EXISTS, PERMISSION_DENIED};
+
+pub struct LabelUseCaseImpl<L: LabelRepositories> {
+    repository: L,
+}
+
+impl<L: LabelRepositories> LabelUseCaseImpl<L> {
+    pub fn new(repository: L) -> Self {
+        Self { repository }
+    }
+
+    // ชื่อห้ามซ้ำ (ไม่สนตัวพิมพ์) และห้ามมี , เพราะใช้เป็นตัวคั่นใน GET /task?label=
+    async fn ensure_label(&self, name: &str, color: &str, exclude_id: Option<i64>) -> Result<(), CustomError> {
+        if name.is_empty() || name.contains(',') {
+            return Err(CustomError::ValidationError(INVALID_LABEL_NAME.to_string()));
+        }
+        if !is_hex_color(color) {
+            return Err(CustomError::ValidationError(INVALID_LABEL_COLOR.to_string()));
+        }
+        if self.repository.label_name_exists(name.to_string(), exclude_id).await? {
+            return Err(CustomError::DataConflict(format!("{}: {}", LABEL_ALREADY_EXISTS, name)));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<L: LabelRepositories> LabelUseCase for LabelUseCaseImpl<L> {
+    async fn list_label(&self) -> Result<Vec<Label>, CustomError> {
+        self.repository.list_label().await
+    }
+
+    async fn get_label(&self, id: i64) -> Result<Label, CustomError> {
+        self.repository.get_label(id).await
+    }
+
+    async fn create_label(&self, mut label: LabelCreateEntity, actor: &AuthUser) -> Result<LabelID, CustomError> {
+        ensure_manager(actor)?;
+        label.name = label.name.trim().to_string();
+        label.color = label.color.to_lowercase();
+        self.ensure_label(&label.name, &label.color, None).await?;
+
+        self.repository.create_label(label).await.map(|id| LabelID { id })
+    }
+
+    async fn update_label(&self, mut label: UpdateLabel, actor: &AuthUser) -> Result<(), CustomError> {
+        ensure_manager(actor)?;
+        self.repository.get_label(label.id).await?;
+        label.name = label.name.trim().to_string();
+        label.color = label.color.to_lowercase();
+        self.ensure_label(&label.name, &label.color, Some(label.id)).await?;
+
+        self.repository.update_label(label).await
+    }
+
+    async fn delete_label(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        ensure_manager(actor)?;
+        self.repository.get_label(id).await?;
+
+        self.repository.delete_label(id).await
+    }
+}
+
+// จัดการ label ได้เฉพาะ Admin และ Manager ของระบบ ส่วนการติด label ให้ task ใช้สิทธิ์แก้ไข task
+fn ensure_manager(actor: &AuthUser) -> Result<(), CustomError> {
+    if !matches!(actor.role, Role::Admin | Role::Manager) {
+        return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+    }
+
+    Ok(())
+}
+
+fn is_hex_color(color: &str) -> bool {
+    color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -1,21 +1,31 @@
 use async_trait::async_trait;
+use std::collections::HashSet;
 use crate::application::interfaces::master_data::MasterDataUseCase;
-use crate::domain::entities::master_data::{MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus};
-use crate::domain::repositories::master_data::MasterDataRepositories;
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::entities::master_data::{
+    MasterDataCreateEntity, MasterDataDeactivate, MasterDataID, MasterDataItem, MasterDataKind, MasterDataPriorityLevels, MasterDataRole,
+    MasterDataTaskStatus, MasterDataTaskStatusTransition, TaskStatusTransition, UpdateMasterData,
+};
+use crate::domain::repositories::master_data::{MasterDataAdminRepositories, MasterDataRepositories};
 use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{
+    INVALID_MASTER_DATA_CODE, INVALID_MASTER_DATA_ORDER, INVALID_MASTER_DATA_REPLACEMENT, INVALID_ROLE_CODE, LAST_ACTIVE_MASTER_DATA,
+    MASTER_DATA_CODE_ALREADY_EXISTS, MASTER_DATA_REQUIRED, PERMISSION_DENIED, TASK_STATUS_NOT_FOUND,
+};
 
-pub struct MasterDataUseCaseImpl<T: MasterDataRepositories> {
+pub struct MasterDataUseCaseImpl<T: MasterDataRepositories, A: MasterDataAdminRepositories> {
     repository: T,
+    admin_repository: A,
 }
 
-impl<T: MasterDataRepositories> MasterDataUseCaseImpl<T> {
-    pub fn new(repository: T) -> Self {
-        Self { repository }
+impl<T: MasterDataRepositories, A: MasterDataAdminRepositories> MasterDataUseCaseImpl<T, A> {
+    pub fn new(repository: T, admin_repository: A) -> Self {
+        Self { repository, admin_repository }
     }
 }
 
 #[async_trait]
-impl<T: MasterDataRepositories> MasterDataUseCase for MasterDataUseCaseImpl<T> {
+impl<T: MasterDataRepositories, A: MasterDataAdminRepositories> MasterDataUseCase for MasterDataUseCaseImpl<T, A> {
     async fn list_task_status(&self) -> Result<Vec<MasterDataTaskStatus>, CustomError> {
         self.repository.list_task_status().await
     }
@@ -25,4 +35,144 @@ impl<T: MasterDataRepositories> MasterDataUseCase for MasterDataUseCaseImpl<T> {
     async fn list_priority_levels(&self) -> Result<Vec<MasterDataPriorityLevels>, CustomError> {
         self.repository.list_priority_levels().await
     }
+    async fn list_task_status_transition(&self, from_status_id: i64) -> Result<Vec<TaskStatusTransition>, CustomError> {
+        let statuses = self.repository.list_task_status().await?;
+        if !statuses.iter().any(|status| status.id == from_status_id) {
+            return Err(CustomError::NotFound(format!("{}: {}", TASK_STATUS_NOT_FOUND, from_status_id)));
+        }
+
+        let transitions = self.repository.list_task_status_transition(from_status_id).await?;
+
+        // รวมแถวที่ไปสถานะเดียวกันแต่ต่าง role ให้เป็นรายการเดียว
+        let mut items: Vec<TaskStatusTransition> = Vec::new();
+        for transition in &transitions {
+            if items.iter().any(|item| item.id == transition.to_status_id) {
+                continue;
+            }
+
+            let rows: Vec<&MasterDataTaskStatusTransition> = transitions
+                .iter()
+                .filter(|row| row.to_status_id == transition.to_status_id)
+                .collect();
+
+            // มีแถวที่ไม่จำกัด role แปลว่าทุก role เปลี่ยนได้
+            let roles = if rows.iter().any(|row| row.role_code.is_none()) {
+                Vec::new()
+            } else {
+                rows.iter().filter_map(|row| row.role_code.clone()).collect()
+            };
+
+            items.push(TaskStatusTransition {
+                id: transition.to_status_id,
+                title: transition.title.clone(),
+                code: transition.code.clone(),
+                roles,
+            });
+        }
+
+        Ok(items)
+    }
+    async fn list_master_data(&self, kind: MasterDataKind, actor: AuthUser) -> Result<Vec<MasterDataItem>, CustomError> {
+        ensure_admin(&actor)?;
+
+        self.admin_repository.list_master_data(kind).await
+    }
+
+    async fn create_master_data(&self, kind: MasterDataKind, item: MasterDataCreateEntity, actor: AuthUser) -> Result<MasterDataID, CustomError> {
+        ensure_admin(&actor)?;
+
+        let item = MasterDataCreateEntity { title: item.title.trim().to_string(), code: item.code.trim().to_string(), ..item };
+        if !is_master_data_code(&item.code) {
+            return Err(CustomError::ValidationError(INVALID_MASTER_DATA_CODE.to_string()));
+        }
+        // สิทธิ์ของแต่ละ role ผูกกับ code ในระบบ จึงเพิ่มได้เฉพาะ code ที่ระบบรู้จัก
+        if kind == MasterDataKind::Role && Role::from_code(&item.code).is_none() {
+            return Err(CustomError::ValidationError(INVALID_ROLE_CODE.to_string()));
+        }
+        if self.admin_repository.master_data_code_exists(kind, item.code.clone()).await? {
+            return Err(CustomError::DataConflict(format!("{}: {}", MASTER_DATA_CODE_ALREADY_EXISTS, item.code)));
+        }
+
+        let id = self.admin_repository.create_master_data(kind, item).await?;
+
+        Ok(MasterDataID { id })
+    }
+
+    async fn update_master_data(&self, kind: MasterDataKind, item: UpdateMasterData, actor: AuthUser) -> Result<(), CustomError> {
+        ensure_admin(&actor)?;
+
+        self.admin_repository.get_master_data(kind, item.id).await?;
+
+        let item = UpdateMasterData { title: item.title.trim().to_string(), ..item };
+        self.admin_repository.update_master_data(kind, item).await
+    }
+
+    async fn activate_master_data(&self, kind: MasterDataKind, id: i64, actor: AuthUser) -> Result<(), CustomError> {
+        ensure_admin(&actor)?;
+
+        self.admin_repository.get_master_data(kind, id).await?;
+        self.admin_repository.activate_master_data(kind, id, actor.id).await
+    }
+
+    async fn deactivate_master_data(&self, kind: MasterDataKind, deactivate: MasterDataDeactivate, actor: AuthUser) -> Result<(), CustomError> {
+        ensure_admin(&actor)?;
+
+        let item = self.admin_repository.get_master_data(kind, deactivate.id).await?;
+        if !item.active {
+            return Ok(());
+        }
+        if is_required_by_system(kind, &item.code) {
+            return Err(CustomError::BusinessError(format!("{}: {}", MASTER_DATA_REQUIRED, item.code)));
+        }
+
+        let items = self.admin_repository.list_master_data(kind).await?;
+        if !items.iter().any(|other| other.active && other.id != item.id) {
+            return Err(CustomError::BusinessError(LAST_ACTIVE_MASTER_DATA.to_string()));
+        }
+        if let Some(replacement_id) = deactivate.replacement_id {
+            if !items.iter().any(|other| other.id == replacement_id && other.active && other.id != item.id) {
+                return Err(CustomError::ValidationError(INVALID_MASTER_DATA_REPLACEMENT.to_string()));
+            }
+        }
+
+        self.admin_repository
+            .deactivate_master_data(kind, MasterDataDeactivate { updated_by: actor.id, ..deactivate })
+            .await
+    }
+
+    async fn reorder_master_data(&self, kind: MasterDataKind, ids: Vec<i64>, actor: AuthUser) -> Result<Vec<MasterDataItem>, CustomError> {
+        ensure_admin(&actor)?;
+
+        let items = self.admin_repository.list_master_data(kind).await?;
+        let unique_ids: HashSet<i64> = ids.iter().copied().collect();
+        if ids.len() != items.len() || unique_ids.len() != ids.len() || !items.iter().all(|item| unique_ids.contains(&item.id)) {
+            return Err(CustomError::ValidationError(INVALID_MASTER_DATA_ORDER.to_string()));
+        }
+
+        self.admin_repository.reorder_master_data(kind, ids, actor.id).await?;
+        self.admin_repository.list_master_data(kind).await
+    }
+}
+
+fn ensure_admin(actor: &AuthUser) -> Result<(), CustomError> {
+    if actor.role != Role::Admin {
+        return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+    }
+
+    Ok(())
+}
+
+// code ใช้อ้างอิงจาก client เช่น "IN_PROGRESS" จึงจำกัดรูปแบบ
+fn is_master_data_code(code: &str) -> bool {
+    code.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+        && code.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+// COMPLETED ใช้ตรวจ dependency ของ task, ADMIN ต้องมีเสมอ และ MEMBER เป็น role เริ่มต้นตอนสมัครสมาชิก
+fn is_required_by_system(kind: MasterDataKind, code: &str) -> bool {
+    match kind {
+        MasterDataKind::TaskStatus => code == "COMPLETED",
+        MasterDataKind::PriorityLevels => false,
+        MasterDataKind::Role => matches!(Role::from_code(code), Some(Role::Admin | Role::Member)),
+    }
 }
\ No newline at end of file
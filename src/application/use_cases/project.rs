@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use crate::application::interfaces::project::ProjectUseCase;
+use crate::application::use_cases::task_access::project_role;
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::entities::project::{Project, ProjectCreateEntity, ProjectFilter, ProjectID, ProjectMember, ProjectMemberSet, UpdateProject};
+use crate::domain::repositories::master_data::MasterDataRepositories;
+use crate::domain::repositories::project::ProjectRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{INVALID_PROJECT_MEMBER, INVALID_ROLE, LAST_PROJECT_ADMIN, PERMISSION_DENIED, PROJECT_ARCHIVED, PROJECT_HAS_TASK, PROJECT_MEMBER_NOT_FOUND};
+
+pub struct ProjectUseCaseImpl<P: ProjectRepositories, M: MasterDataRepositories> {
+    repository: P,
+    master_data_repository: M,
+}
+
+impl<P: ProjectRepositories, M: MasterDataRepositories> ProjectUseCaseImpl<P, M> {
+    pub fn new(repository: P, master_data_repository: M) -> Self {
+        Self { repository, master_data_repository }
+    }
+
+    // ดึง project พร้อมตรวจสิทธิ์ Admin ของระบบเข้าถึงได้ทุก project ส่วนคนอื่นต้องเป็นสมาชิก
+    async fn get_project_for(&self, id: i64, actor: &AuthUser, action: ProjectAction) -> Result<Project, CustomError> {
+        let project = self.repository.get_project(id).await?;
+
+        let role = project_role(&self.repository, id, actor).await?;
+
+        // จัดการ project และสมาชิกได้เฉพาะ Admin ของ project
+        if matches!(action, ProjectAction::Manage) && role != Role::Admin {
+            return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+        }
+
+        Ok(project)
+    }
+
+    // role ที่จะให้สมาชิกต้องมีอยู่จริงและ active
+    async fn ensure_role(&self, role_id: i64) -> Result<(), CustomError> {
+        let roles = self.master_data_repository.list_role().await?;
+        if !roles.iter().any(|role| role.id == role_id) {
+            return Err(CustomError::ValidationError(format!("{}: {}", INVALID_ROLE, role_id)));
+        }
+
+        Ok(())
+    }
+
+    // กันไม่ให้ project ไม่เหลือ Admin (ลบหรือลด role ของ Admin คนสุดท้าย)
+    async fn ensure_admin_remains(&self, project_id: i64, user_id: i64, new_role_id: Option<i64>) -> Result<(), CustomError> {
+        let members = self.repository.list_member(project_id).await?;
+        let is_admin = |member: &ProjectMember| Role::from_code(&member.role) == Some(Role::Admin);
+
+        let remaining_admin = members
+            .iter()
+            .filter(|member| is_admin(member))
+            .any(|member| member.user_id != user_id || Some(member.role_id) == new_role_id);
+        let was_admin = members.iter().any(|member| member.user_id == user_id && is_admin(member));
+
+        if was_admin && !remaining_admin {
+            return Err(CustomError::BusinessError(LAST_PROJECT_ADMIN.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P: ProjectRepositories, M: MasterDataRepositories> ProjectUseCase for ProjectUseCaseImpl<P, M> {
+    async fn list_project(&self, mut filter: ProjectFilter, actor: &AuthUser) -> Result<Vec<Project>, CustomError> {
+        if actor.role != Role::Admin {
+            filter.member_id = Some(actor.id);
+        }
+
+        self.repository.list_project(filter).await
+    }
+
+    async fn get_project(&self, id: i64, actor: &AuthUser) -> Result<Project, CustomError> {
+        self.get_project_for(id, actor, ProjectAction::Read).await
+    }
+
+    async fn create_project(&self, project: ProjectCreateEntity, actor: &AuthUser) -> Result<ProjectID, CustomError> {
+        // สร้าง project ได้เฉพาะ Admin และ Manager ของระบบ
+        if !matches!(actor.role, Role::Admin | Role::Manager) {
+            return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+        }
+
+        self.repository.create_project(project).await.map(|id| ProjectID { id })
+    }
+
+    async fn update_project(&self, project: UpdateProject, actor: &AuthUser) -> Result<(), CustomError> {
+        let current = self.get_project_for(project.id, actor, ProjectAction::Manage).await?;
+        if current.archived_at.is_some() {
+            return Err(CustomError::BusinessError(PROJECT_ARCHIVED.to_string()));
+        }
+
+        self.repository.update_project(project).await
+    }
+
+    async fn archive_project(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_project_for(id, actor, ProjectAction::Manage).await?;
+
+        self.repository.set_project_archived(id, Some(actor.id)).await
+    }
+
+    async fn unarchive_project(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_project_for(id, actor, ProjectAction::Manage).await?;
+
+        self.repository.set_project_archived(id, None).await
+    }
+
+    async fn delete_project(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_project_for(id, actor, ProjectAction::Manage).await?;
+
+        // ลบได้เฉพาะ project ที่ไม่มี task แล้ว (รวมในถังขยะ) ที่เหลือให้ใช้ archive แทน
+        if self.repository.count_task(id).await? > 0 {
+            return Err(CustomError::DataConflict(PROJECT_HAS_TASK.to_string()));
+        }
+
+        self.repository.delete_project(id).await
+    }
+
+    async fn list_member(&self, project_id: i64, actor: &AuthUser) -> Result<Vec<ProjectMember>, CustomError> {
+        self.get_project_for(project_id, actor, ProjectAction::Read).await?;
+
+        self.repository.list_member(project_id).await
+    }
+
+    async fn set_member(&self, member: ProjectMemberSet, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_project_for(member.project_id, actor, ProjectAction::Manage).await?;
+        self.ensure_role(member.role_id).await?;
+
+        if !self.repository.is_active_user(member.user_id).await? {
+            return Err(CustomError::ValidationError(format!("{}: {}", INVALID_PROJECT_MEMBER, member.user_id)));
+        }
+        self.ensure_admin_remains(member.project_id, member.user_id, Some(member.role_id)).await?;
+
+        self.repository.set_member(member).await
+    }
+
+    async fn remove_member(&self, project_id: i64, user_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_project_for(project_id, actor, ProjectAction::Manage).await?;
+        self.ensure_admin_remains(project_id, user_id, None).await?;
+
+        if !self.repository.remove_member(project_id, user_id).await? {
+            return Err(CustomError::NotFound(format!("{}: {}", PROJECT_MEMBER_NOT_FOUND, user_id)));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ProjectAction {
+    Read,
+    Manage,
+}
@@ -1,67 +1,746 @@
 
 use async_trait::async_trait;
 use crate::application::interfaces::task::TaskUseCase;
-use crate::domain::entities::task::{Task, TaskCreateEntity, TaskID, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
+use crate::application::use_cases::task_access::{ensure_task_visible, project_role};
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::entities::master_data::{MasterDataPriorityLevels, MasterDataRef, MasterDataTaskStatus, MasterDataTaskStatusTransition};
+use crate::domain::entities::task::{PatchTask, Task, TaskAssign, TaskBulk, TaskBulkAction, TaskBulkItem, TaskBulkItemResult, TaskBulkMode, TaskBulkResult, TaskDependency, TaskHistoryFilter, TaskHistoryPage, TaskCreateEntity, TaskExpand, TaskFilter, TaskID, TaskImport, TaskImportError, TaskImportResult, TaskLabelAdd, TaskPage, TaskSearchPage, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
+use crate::domain::repositories::master_data::MasterDataRepositories;
+use crate::domain::repositories::project::ProjectRepositories;
 use crate::domain::repositories::task::TaskRepositories;
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::shared::exceptions::error_message::TASK_NOT_FOUND;
+use chrono::NaiveDateTime;
+use std::collections::{HashMap, HashSet};
+use crate::shared::exceptions::error_message::{
+    ASSIGNEE_NOT_FOUND, EMPTY_TASK_PATCH, INVALID_ASSIGNEE, INVALID_LABEL, INVALID_PARENT_TASK, INVALID_PRIORITY_LEVELS, INVALID_STATUS_TRANSITION,
+    INVALID_TASK_DEPENDENCY, INVALID_TASK_IMPORT_SIZE, INVALID_TASK_SCHEDULE, INVALID_TASK_STATUS, PARENT_TASK_IN_TRASH, PARENT_TASK_PROJECT_MISMATCH, PERMISSION_DENIED,
+    PROJECT_ARCHIVED, SEARCH_QUERY_REQUIRED, TASK_BLOCKED, TASK_BULK_DUPLICATE_TASK, TASK_BULK_TOO_LARGE, TASK_DEPENDENCY_CYCLE, TASK_DEPENDENCY_NOT_FOUND, TASK_DEPENDENCY_PROJECT_MISMATCH, TASK_HAS_SUBTASK,
+    TASK_HIERARCHY_CYCLE, TASK_HIERARCHY_TOO_DEEP, TASK_LABEL_NOT_FOUND,
+};
 
-pub struct TaskUseCaseImpl<T: TaskRepositories> {
+// จำนวนชั้นสูงสุดของ task รวม task ระดับบนสุด
+const MAX_TASK_DEPTH: usize = 5;
+
+// จำนวน task รวมทุก operation ใน bulk ครั้งเดียว
+const MAX_BULK_ITEMS: usize = 500;
+
+// จำนวนแถวสูงสุดของไฟล์ import
+const MAX_IMPORT_ROWS: usize = 1000;
+
+pub struct TaskUseCaseImpl<T: TaskRepositories, M: MasterDataRepositories, P: ProjectRepositories> {
     repository: T,
+    master_data_repository: M,
+    project_repository: P,
 }
 
-impl<T: TaskRepositories> TaskUseCaseImpl<T> {
-    pub fn new(repository: T) -> Self {
-        Self { repository }
+impl<T: TaskRepositories, M: MasterDataRepositories, P: ProjectRepositories> TaskUseCaseImpl<T, M, P> {
+    pub fn new(repository: T, master_data_repository: M, project_repository: P) -> Self {
+        Self { repository, master_data_repository, project_repository }
+    }
+
+    // แปลง id/code ของสถานะเป็น id โดยต้องเป็นรายการที่ active อยู่เท่านั้น
+    async fn resolve_task_status(&self, task_status: &MasterDataRef) -> Result<i64, CustomError> {
+        find_task_status(&self.master_data_repository.list_task_status().await?, task_status)
+    }
+
+    async fn resolve_priority_levels(&self, priority_levels: &MasterDataRef) -> Result<i64, CustomError> {
+        find_priority_levels(&self.master_data_repository.list_priority_levels().await?, priority_levels)
+    }
+
+    // ตรวจสิทธิ์ตาม role ใน project (ดู project_role) และสถานะเก็บเข้าคลังของ project
+    // คืน actor ที่ role เปลี่ยนเป็น role ใน project สำหรับใช้ตรวจสิทธิ์ต่อ
+    async fn project_actor(&self, project_id: i64, actor: &AuthUser, action: TaskAction) -> Result<AuthUser, CustomError> {
+        let project = self.project_repository.get_project(project_id).await?;
+
+        let role = project_role(&self.project_repository, project_id, actor).await?;
+        let project_actor = AuthUser { role, ..*actor };
+        authorize(&project_actor, action)?;
+
+        // project ที่เก็บเข้าคลังแล้วอ่านได้อย่างเดียว
+        if project.archived_at.is_some() && !matches!(action, TaskAction::Read) {
+            return Err(CustomError::BusinessError(PROJECT_ARCHIVED.to_string()));
+        }
+
+        Ok(project_actor)
+    }
+
+    // ดึง task พร้อมตรวจสิทธิ์ตาม role ใน project ของ task
+    // Member ต้องเป็นผู้สร้างหรือผู้รับผิดชอบ task เท่านั้น
+    async fn get_task_for(&self, id: i64, actor: &AuthUser, action: TaskAction) -> Result<(Task, AuthUser), CustomError> {
+        let task = self.repository.get_task(id).await?;
+        let actor = self.task_actor(&task, actor, action).await?;
+
+        Ok((task, actor))
+    }
+
+    async fn task_actor(&self, task: &Task, actor: &AuthUser, action: TaskAction) -> Result<AuthUser, CustomError> {
+        let actor = self.project_actor(task.project_id, actor, action).await?;
+        ensure_task_visible(task, &actor)?;
+
+        Ok(actor)
+    }
+
+    // ย้าย task ไป project อื่นต้องมีสิทธิ์สร้าง task ใน project ปลายทางด้วย
+    // task ที่มี subtask ย้ายไม่ได้ เพราะ subtask ต้องอยู่ใน project เดียวกับ task แม่
+    async fn ensure_project_move(&self, current: &Task, project_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        if current.project_id != project_id {
+            self.project_actor(project_id, actor, TaskAction::Create).await?;
+            if current.subtask_count > 0 {
+                return Err(CustomError::BusinessError(TASK_HAS_SUBTASK.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    // task แม่ต้องอยู่ใน project เดียวกัน ห้ามเป็นตัวเองหรือ subtask ของตัวเอง และความลึกรวมไม่เกิน MAX_TASK_DEPTH
+    // task_id = None คือ task ที่กำลังสร้างใหม่
+    async fn ensure_parent(&self, task_id: Option<i64>, parent_task_id: i64, project_id: i64) -> Result<(), CustomError> {
+        let parent = self.repository.get_task(parent_task_id).await.map_err(|e| match e {
+            CustomError::NotFound(_) => CustomError::ValidationError(format!("{}: {}", INVALID_PARENT_TASK, parent_task_id)),
+            e => e,
+        })?;
+        if parent.project_id != project_id {
+            return Err(CustomError::ValidationError(format!("{}: {}", PARENT_TASK_PROJECT_MISMATCH, parent_task_id)));
+        }
+
+        let ancestor_ids = self.repository.task_ancestor_ids(parent_task_id).await?;
+        let subtask_depth = match task_id {
+            Some(task_id) if ancestor_ids.contains(&task_id) => {
+                return Err(CustomError::ValidationError(format!("{}: {}", TASK_HIERARCHY_CYCLE, parent_task_id)));
+            }
+            Some(task_id) => self.repository.subtask_depth(task_id).await? as usize,
+            None => 0,
+        };
+        if ancestor_ids.len() + 1 + subtask_depth > MAX_TASK_DEPTH {
+            return Err(CustomError::ValidationError(format!("{}: {}", TASK_HIERARCHY_TOO_DEEP, MAX_TASK_DEPTH)));
+        }
+
+        Ok(())
+    }
+
+    // ตรวจว่าเปลี่ยนสถานะตาม workflow ได้ (สถานะเดิมหรือ task ที่ยังไม่มีสถานะไม่ต้องตรวจ)
+    async fn ensure_status_transition(&self, task: &Task, to_status_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        let from_status_id = match task.task_status_id {
+            Some(from_status_id) if from_status_id != to_status_id => from_status_id,
+            _ => return Ok(()),
+        };
+
+        let transitions: Vec<MasterDataTaskStatusTransition> = self.master_data_repository
+            .list_task_status_transition(from_status_id)
+            .await?
+            .into_iter()
+            .filter(|transition| match &transition.role_code {
+                Some(role_code) => Role::from_code(role_code) == Some(actor.role),
+                None => true,
+            })
+            .collect();
+
+        if transitions.iter().any(|transition| transition.to_status_id == to_status_id) {
+            return Ok(());
+        }
+
+        let mut allowed: Vec<&str> = transitions.iter().map(|transition| transition.code.as_str()).collect();
+        allowed.sort_unstable();
+        allowed.dedup();
+        let allowed = if allowed.is_empty() { "-".to_string() } else { allowed.join(", ") };
+
+        Err(CustomError::BusinessError(format!("{}. Allowed next status: {}", INVALID_STATUS_TRANSITION, allowed)))
+    }
+
+    // ปิด task (เปลี่ยนเป็น COMPLETED) ไม่ได้ถ้ายังมี task ที่ block อยู่และยังไม่เสร็จ
+    async fn ensure_not_blocked(&self, task: &Task, to_status_id: i64) -> Result<(), CustomError> {
+        if task.task_status_id == Some(to_status_id) {
+            return Ok(());
+        }
+
+        let completed = self.master_data_repository
+            .list_task_status()
+            .await?
+            .iter()
+            .any(|status| status.id == to_status_id && status.code == "COMPLETED");
+        if !completed {
+            return Ok(());
+        }
+
+        let open_blocker_ids = self.repository.open_blocker_ids(task.id).await?;
+        if !open_blocker_ids.is_empty() {
+            let ids: Vec<String> = open_blocker_ids.iter().map(|id| id.to_string()).collect();
+            return Err(CustomError::BusinessError(format!("{}: {}", TASK_BLOCKED, ids.join(", "))));
+        }
+
+        Ok(())
+    }
+
+    // ผู้รับผิดชอบต้องเป็น user ที่ยังเปิดใช้งานและเป็นสมาชิกของ project
+    async fn ensure_active_users(&self, user_ids: &[i64], project_id: i64) -> Result<(), CustomError> {
+        let active_ids = self.repository.active_user_ids(user_ids.to_vec(), project_id).await?;
+        let invalid_ids: Vec<String> = user_ids
+            .iter()
+            .filter(|id| !active_ids.contains(id))
+            .map(|id| id.to_string())
+            .collect();
+
+        if !invalid_ids.is_empty() {
+            return Err(CustomError::ValidationError(format!("{}: {}", INVALID_ASSIGNEE, invalid_ids.join(", "))));
+        }
+
+        Ok(())
+    }
+
+    // ตรวจสิทธิ์ วันที่ task แม่ และแปลง master data ก่อนสร้าง task
+    async fn prepare_task(&self, task: TaskCreateEntity<MasterDataRef>, actor: &AuthUser) -> Result<TaskCreateEntity, CustomError> {
+        self.project_actor(task.project_id, actor, TaskAction::Create).await?;
+        validate_schedule(task.start_date, task.due_date)?;
+        if let Some(parent_task_id) = task.parent_task_id {
+            self.ensure_parent(None, parent_task_id, task.project_id).await?;
+        }
+
+        let task_status_id = self.resolve_task_status(&task.task_status_id).await?;
+        let priority_levels_id = self.resolve_priority_levels(&task.priority_levels_id).await?;
+        Ok(task_create_entity(task, task_status_id, priority_levels_id))
+    }
+
+    // ตรวจแถวของไฟล์ import โดยใช้ master data ที่โหลดไว้แล้ว (สิทธิ์ใน project ตรวจแยกครั้งเดียวต่อ project)
+    async fn prepare_import_task(
+        &self,
+        task: TaskCreateEntity<MasterDataRef>,
+        task_status: &[MasterDataTaskStatus],
+        priority_levels: &[MasterDataPriorityLevels],
+    ) -> Result<TaskCreateEntity, CustomError> {
+        validate_schedule(task.start_date, task.due_date)?;
+        if let Some(parent_task_id) = task.parent_task_id {
+            self.ensure_parent(None, parent_task_id, task.project_id).await?;
+        }
+
+        let task_status_id = find_task_status(task_status, &task.task_status_id)?;
+        let priority_levels_id = find_priority_levels(priority_levels, &task.priority_levels_id)?;
+        Ok(task_create_entity(task, task_status_id, priority_levels_id))
+    }
+
+    // แปลง master data ใน operation ครั้งเดียวก่อนใช้กับทุก task
+    async fn resolve_bulk_action(&self, action: TaskBulkAction<MasterDataRef>) -> Result<TaskBulkAction, CustomError> {
+        Ok(match action {
+            TaskBulkAction::UpdateStatus(task_status) => TaskBulkAction::UpdateStatus(self.resolve_task_status(&task_status).await?),
+            TaskBulkAction::UpdatePriority(priority_levels) => TaskBulkAction::UpdatePriority(self.resolve_priority_levels(&priority_levels).await?),
+            TaskBulkAction::Assign(mut user_ids) => {
+                user_ids.sort_unstable();
+                user_ids.dedup();
+                TaskBulkAction::Assign(user_ids)
+            }
+            TaskBulkAction::Delete => TaskBulkAction::Delete,
+        })
+    }
+
+    // ตรวจสิทธิ์และเงื่อนไขแบบเดียวกับ endpoint เดี่ยวของแต่ละ operation
+    async fn ensure_bulk_item(&self, item: &TaskBulkItem, actor: &AuthUser) -> Result<(), CustomError> {
+        match &item.action {
+            TaskBulkAction::UpdateStatus(task_status_id) => {
+                let (current, project_actor) = self.get_task_for(item.task_id, actor, TaskAction::Update).await?;
+                self.ensure_status_transition(&current, *task_status_id, &project_actor).await?;
+                self.ensure_not_blocked(&current, *task_status_id).await
+            }
+            TaskBulkAction::UpdatePriority(..) => self.get_task_for(item.task_id, actor, TaskAction::Update).await.map(|_| ()),
+            TaskBulkAction::Assign(user_ids) => {
+                let (task, _) = self.get_task_for(item.task_id, actor, TaskAction::Update).await?;
+                self.ensure_active_users(user_ids, task.project_id).await
+            }
+            TaskBulkAction::Delete => self.get_task_for(item.task_id, actor, TaskAction::Delete).await.map(|_| ()),
+        }
     }
 }
 
 #[async_trait]
-impl<T: TaskRepositories> TaskUseCase for TaskUseCaseImpl<T> {
-    async fn list_task(&self) -> Result<Vec<Task>, CustomError> {
-        self.repository.list_task().await
+impl<T: TaskRepositories, M: MasterDataRepositories, P: ProjectRepositories> TaskUseCase for TaskUseCaseImpl<T, M, P> {
+    async fn list_task(&self, mut filter: TaskFilter, actor: &AuthUser) -> Result<TaskPage, CustomError> {
+        if let Some(project_id) = filter.project_id {
+            self.project_actor(project_id, actor, TaskAction::Read).await?;
+        }
+
+        // เห็นเฉพาะ task ใน project ที่เป็นสมาชิก (role Member เห็นเฉพาะที่สร้างเองหรือได้รับมอบหมาย)
+        if actor.role != Role::Admin {
+            filter.member_id = Some(actor.id);
+        }
+        filter.deleted = false;
+
+        self.repository.list_task(filter).await
     }
 
-    async fn get_task(&self, id: i64) -> Result<Task, CustomError> {
-        self.repository.get_task(id).await
+    async fn search_task(&self, mut filter: TaskFilter, actor: &AuthUser) -> Result<TaskSearchPage, CustomError> {
+        filter.search = filter.search.map(|search| search.trim().to_string()).filter(|search| !search.is_empty());
+        if filter.search.is_none() {
+            return Err(CustomError::ValidationError(SEARCH_QUERY_REQUIRED.to_string()));
+        }
+        if let Some(project_id) = filter.project_id {
+            self.project_actor(project_id, actor, TaskAction::Read).await?;
+        }
+
+        // สิทธิ์เดียวกับ list_task
+        if actor.role != Role::Admin {
+            filter.member_id = Some(actor.id);
+        }
+        filter.deleted = false;
+
+        self.repository.search_task(filter).await
+    }
+
+    async fn get_task(&self, id: i64, expand: TaskExpand, actor: &AuthUser) -> Result<Task, CustomError> {
+        if expand.is_empty() {
+            return self.get_task_for(id, actor, TaskAction::Read).await.map(|(task, _)| task);
+        }
+
+        let task = self.repository.get_task_expanded(id, expand).await?;
+        self.task_actor(&task, actor, TaskAction::Read).await?;
+
+        Ok(task)
+    }
+
+    async fn list_subtask(&self, task_id: i64, mut filter: TaskFilter, actor: &AuthUser) -> Result<TaskPage, CustomError> {
+        let (task, _) = self.get_task_for(task_id, actor, TaskAction::Read).await?;
+
+        filter.project_id = Some(task.project_id);
+        filter.parent_task_id = Some(task_id);
+        if actor.role != Role::Admin {
+            filter.member_id = Some(actor.id);
+        }
+        filter.deleted = false;
+
+        self.repository.list_task(filter).await
     }
 
-    async fn create_task(&self, task: TaskCreateEntity) -> Result<TaskID, CustomError> {
+    async fn create_task(&self, task: TaskCreateEntity<MasterDataRef>, actor: &AuthUser) -> Result<TaskID, CustomError> {
+        let task = self.prepare_task(task, actor).await?;
+
         self.repository.create_task(task).await.map(|id| TaskID { id })
     }
 
-    async fn update_task(&self, task: UpdateTask) -> Result<(), CustomError> {
-        if !self.repository.task_exists(task.id).await? {
-            return Err(not_found_error(TASK_NOT_FOUND, task.id));
+    async fn import_task(&self, import: TaskImport<MasterDataRef>, actor: &AuthUser) -> Result<TaskImportResult, CustomError> {
+        let total = import.rows.len();
+        if total == 0 || total > MAX_IMPORT_ROWS {
+            return Err(CustomError::ValidationError(INVALID_TASK_IMPORT_SIZE.to_string()));
+        }
+
+        // ตรวจทุกแถวด้วยเงื่อนไขเดียวกับการสร้าง task ทีละรายการ
+        // master data โหลดครั้งเดียว และสิทธิ์ใน project ตรวจครั้งเดียวต่อ project
+        let task_status = self.master_data_repository.list_task_status().await?;
+        let priority_levels = self.master_data_repository.list_priority_levels().await?;
+        let mut project_checks: HashMap<i64, Result<(), String>> = HashMap::new();
+        let mut tasks = Vec::with_capacity(total);
+        let mut errors = Vec::new();
+        for row in import.rows {
+            let task = match row.task {
+                Ok(task) => {
+                    let project_check = match project_checks.get(&task.project_id) {
+                        Some(project_check) => project_check.clone(),
+                        None => {
+                            let project_check = self.project_actor(task.project_id, actor, TaskAction::Create)
+                                .await
+                                .map(|_| ())
+                                .map_err(|e| e.to_string());
+                            project_checks.insert(task.project_id, project_check.clone());
+                            project_check
+                        }
+                    };
+                    match project_check {
+                        Ok(()) => self.prepare_import_task(task, &task_status, &priority_levels).await.map_err(|e| e.to_string()),
+                        Err(error) => Err(error),
+                    }
+                }
+                Err(error) => Err(error),
+            };
+            match task {
+                Ok(task) => tasks.push(task),
+                Err(error) => errors.push(TaskImportError { row: row.row, error }),
+            }
         }
 
+        let valid = tasks.len();
+        let task_ids = if import.dry_run || !errors.is_empty() {
+            Vec::new()
+        } else {
+            self.repository.create_tasks(tasks).await?
+        };
+
+        Ok(TaskImportResult {
+            dry_run: import.dry_run,
+            total,
+            valid,
+            imported: task_ids.len(),
+            task_ids,
+            errors,
+        })
+    }
+
+    async fn update_task(&self, task: UpdateTask<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError> {
+        let (current, project_actor) = self.get_task_for(task.id, actor, TaskAction::Update).await?;
+        self.ensure_project_move(&current, task.project_id, actor).await?;
+        validate_schedule(task.start_date, task.due_date)?;
+        if let Some(parent_task_id) = task.parent_task_id {
+            if task.parent_task_id != current.parent_task_id || task.project_id != current.project_id {
+                self.ensure_parent(Some(task.id), parent_task_id, task.project_id).await?;
+            }
+        }
+
+        let task_status_id = self.resolve_task_status(&task.task_status_id).await?;
+        self.ensure_status_transition(&current, task_status_id, &project_actor).await?;
+        self.ensure_not_blocked(&current, task_status_id).await?;
+
+        let task = UpdateTask {
+            task_status_id,
+            priority_levels_id: self.resolve_priority_levels(&task.priority_levels_id).await?,
+            id: task.id,
+            project_id: task.project_id,
+            parent_task_id: task.parent_task_id,
+            title: task.title,
+            description: task.description,
+            start_date: task.start_date,
+            due_date: task.due_date,
+            updated_by: task.updated_by,
+            version: task.version,
+        };
+
         self.repository.update_task(task).await
     }
 
-    async fn update_task_status(&self, task: UpdateTaskStatus) -> Result<(), CustomError> {
-        if !self.repository.task_exists(task.id).await? {
-            return Err(not_found_error(TASK_NOT_FOUND, task.id));
+    async fn patch_task(&self, task: PatchTask<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError> {
+        if task.is_empty() {
+            return Err(CustomError::ValidationError(EMPTY_TASK_PATCH.to_string()));
         }
 
+        let (current, project_actor) = self.get_task_for(task.id, actor, TaskAction::Update).await?;
+        if let Some(project_id) = task.project_id {
+            self.ensure_project_move(&current, project_id, actor).await?;
+        }
+
+        // ตรวจ task แม่จากค่าหลังแก้ไข เมื่อเปลี่ยน task แม่หรือย้าย project
+        if task.parent_task_id.is_some() || task.project_id.is_some() {
+            let project_id = task.project_id.unwrap_or(current.project_id);
+            if let Some(parent_task_id) = task.parent_task_id.unwrap_or(current.parent_task_id) {
+                self.ensure_parent(Some(task.id), parent_task_id, project_id).await?;
+            }
+        }
+
+        // ตรวจช่วงวันที่จากค่าหลังแก้ไข (field ที่ไม่ส่งมาใช้ค่าเดิม)
+        validate_schedule(
+            task.start_date.unwrap_or(current.start_date),
+            task.due_date.unwrap_or(current.due_date),
+        )?;
+
+        let task_status_id = match &task.task_status_id {
+            Some(task_status_id) => {
+                let task_status_id = self.resolve_task_status(task_status_id).await?;
+                self.ensure_status_transition(&current, task_status_id, &project_actor).await?;
+                self.ensure_not_blocked(&current, task_status_id).await?;
+                Some(task_status_id)
+            }
+            None => None,
+        };
+        let priority_levels_id = match &task.priority_levels_id {
+            Some(priority_levels_id) => Some(self.resolve_priority_levels(priority_levels_id).await?),
+            None => None,
+        };
+
+        let task = PatchTask {
+            task_status_id,
+            priority_levels_id,
+            id: task.id,
+            project_id: task.project_id,
+            parent_task_id: task.parent_task_id,
+            title: task.title,
+            description: task.description,
+            start_date: task.start_date,
+            due_date: task.due_date,
+            updated_by: task.updated_by,
+            version: task.version,
+        };
+
+        self.repository.patch_task(task).await
+    }
+
+    async fn update_task_status(&self, task: UpdateTaskStatus<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError> {
+        let (current, project_actor) = self.get_task_for(task.id, actor, TaskAction::Update).await?;
+
+        let task_status_id = self.resolve_task_status(&task.task_status_id).await?;
+        self.ensure_status_transition(&current, task_status_id, &project_actor).await?;
+        self.ensure_not_blocked(&current, task_status_id).await?;
+
+        let task = UpdateTaskStatus {
+            task_status_id,
+            id: task.id,
+            updated_by: task.updated_by,
+            version: task.version,
+        };
+
         self.repository.update_task_status(task).await
     }
 
-    async fn update_task_priority_levels(&self, task: UpdateTaskPriorityLevels) -> Result<(), CustomError> {
-        if !self.repository.task_exists(task.id).await? {
-            return Err(not_found_error(TASK_NOT_FOUND, task.id));
-        }
+    async fn update_task_priority_levels(&self, task: UpdateTaskPriorityLevels<MasterDataRef>, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_task_for(task.id, actor, TaskAction::Update).await?;
+
+        let task = UpdateTaskPriorityLevels {
+            priority_levels_id: self.resolve_priority_levels(&task.priority_levels_id).await?,
+            id: task.id,
+            updated_by: task.updated_by,
+            version: task.version,
+        };
 
         self.repository.update_task_priority_levels(task).await
     }
 
-    async fn delete_task(&self, id: i64) -> Result<(), CustomError> {
-        if !self.repository.task_exists(id).await? {
-            return Err(not_found_error(TASK_NOT_FOUND, id));
+    async fn delete_task(&self, id: i64, version: Option<i32>, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_task_for(id, actor, TaskAction::Delete).await?;
+
+        self.repository.delete_task(id, actor.id, version).await
+    }
+
+    async fn assign_task(&self, mut assign: TaskAssign, actor: &AuthUser) -> Result<(), CustomError> {
+        let (task, _) = self.get_task_for(assign.task_id, actor, TaskAction::Update).await?;
+
+        assign.user_ids.sort_unstable();
+        assign.user_ids.dedup();
+        self.ensure_active_users(&assign.user_ids, task.project_id).await?;
+
+        self.repository.assign_task(assign).await
+    }
+
+    async fn bulk_task(&self, bulk: TaskBulk<MasterDataRef>, actor: &AuthUser) -> Result<TaskBulkResult, CustomError> {
+        let total: usize = bulk.operations.iter().map(|operation| operation.task_ids.len()).sum();
+        if total > MAX_BULK_ITEMS {
+            return Err(CustomError::ValidationError(TASK_BULK_TOO_LARGE.to_string()));
+        }
+
+        // ทุกรายการถูกตรวจกับสถานะก่อนเริ่ม bulk จึงให้ task หนึ่งอยู่ได้ใน operation เดียว
+        let mut operation_of_task = HashMap::new();
+        for (operation_index, operation) in bulk.operations.iter().enumerate() {
+            for task_id in &operation.task_ids {
+                if *operation_of_task.entry(*task_id).or_insert(operation_index) != operation_index {
+                    return Err(CustomError::ValidationError(format!("{}: operations[{}] task {}", TASK_BULK_DUPLICATE_TASK, operation_index, task_id)));
+                }
+            }
+        }
+
+        // ตรวจทุกรายการก่อนเขียน: atomic หยุดที่ error แรก ส่วน bestEffort เก็บ error ไว้ตอบกลับ
+        let mut checked: Vec<Result<TaskBulkItem, TaskBulkItemResult>> = Vec::with_capacity(total);
+        for (operation_index, operation) in bulk.operations.into_iter().enumerate() {
+            let code = operation.action.code();
+            // task ซ้ำใน operation เดียวกันทำครั้งเดียว
+            let mut seen = HashSet::new();
+            let mut task_ids = operation.task_ids;
+            task_ids.retain(|task_id| seen.insert(*task_id));
+
+            let action = match self.resolve_bulk_action(operation.action).await {
+                Ok(action) => action,
+                Err(e) if bulk.mode == TaskBulkMode::Atomic => return Err(e.with_context(&format!("operations[{}]", operation_index))),
+                Err(e) => {
+                    checked.extend(task_ids.into_iter().map(|task_id| Err(TaskBulkItemResult::new(operation_index, code, task_id, Some(e.to_string())))));
+                    continue;
+                }
+            };
+
+            for task_id in task_ids {
+                let item = TaskBulkItem { operation_index, task_id, action: action.clone() };
+                match self.ensure_bulk_item(&item, actor).await {
+                    Ok(()) => checked.push(Ok(item)),
+                    Err(e) if bulk.mode == TaskBulkMode::Atomic => return Err(e.with_context(&item.context())),
+                    Err(e) => checked.push(Err(TaskBulkItemResult::new(operation_index, code, task_id, Some(e.to_string())))),
+                }
+            }
+        }
+
+        let items: Vec<TaskBulkItem> = checked.iter().filter_map(|item| item.as_ref().ok().cloned()).collect();
+        let mut executed = if items.is_empty() {
+            Vec::new()
+        } else {
+            self.repository.bulk_task(items, bulk.mode, actor.id).await?
+        }
+        .into_iter();
+
+        // คงลำดับตาม request โดยแทนรายการที่ตรวจผ่านด้วยผลจาก repository
+        let items: Vec<TaskBulkItemResult> = checked
+            .into_iter()
+            .filter_map(|item| match item {
+                Ok(_) => executed.next(),
+                Err(result) => Some(result),
+            })
+            .collect();
+        let succeeded = items.iter().filter(|item| item.success).count();
+
+        Ok(TaskBulkResult {
+            mode: bulk.mode,
+            succeeded,
+            failed: items.len() - succeeded,
+            items,
+        })
+    }
+
+    async fn unassign_task(&self, task_id: i64, user_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_task_for(task_id, actor, TaskAction::Update).await?;
+
+        if !self.repository.unassign_task(task_id, user_id, actor.id).await? {
+            return Err(not_found_error(ASSIGNEE_NOT_FOUND, user_id));
+        }
+
+        Ok(())
+    }
+
+    async fn add_labels(&self, mut labels: TaskLabelAdd, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_task_for(labels.task_id, actor, TaskAction::Update).await?;
+
+        labels.label_ids.sort_unstable();
+        labels.label_ids.dedup();
+        let existing_ids = self.repository.existing_label_ids(labels.label_ids.clone()).await?;
+        let invalid_ids: Vec<String> = labels
+            .label_ids
+            .iter()
+            .filter(|id| !existing_ids.contains(id))
+            .map(|id| id.to_string())
+            .collect();
+        if !invalid_ids.is_empty() {
+            return Err(CustomError::ValidationError(format!("{}: {}", INVALID_LABEL, invalid_ids.join(", "))));
+        }
+
+        self.repository.add_labels(labels).await
+    }
+
+    async fn remove_label(&self, task_id: i64, label_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_task_for(task_id, actor, TaskAction::Update).await?;
+
+        if !self.repository.remove_label(task_id, label_id, actor.id).await? {
+            return Err(not_found_error(TASK_LABEL_NOT_FOUND, label_id));
+        }
+
+        Ok(())
+    }
+
+    async fn add_dependency(&self, dependency: TaskDependency, actor: &AuthUser) -> Result<(), CustomError> {
+        let (task, _) = self.get_task_for(dependency.task_id, actor, TaskAction::Update).await?;
+
+        let blocker = self.repository.get_task(dependency.blocked_by_task_id).await.map_err(|e| match e {
+            CustomError::NotFound(_) => CustomError::ValidationError(format!("{}: {}", INVALID_TASK_DEPENDENCY, dependency.blocked_by_task_id)),
+            e => e,
+        })?;
+        if blocker.project_id != task.project_id {
+            return Err(CustomError::ValidationError(format!("{}: {}", TASK_DEPENDENCY_PROJECT_MISMATCH, blocker.id)));
+        }
+
+        // วนกลับ: task ที่จะ block ถูก task นี้ block อยู่แล้ว (ทางตรงหรือทางอ้อม)
+        if blocker.id == task.id || self.repository.transitive_blocker_ids(blocker.id).await?.contains(&task.id) {
+            return Err(CustomError::ValidationError(format!("{}: {}", TASK_DEPENDENCY_CYCLE, blocker.id)));
+        }
+
+        self.repository.add_dependency(dependency).await
+    }
+
+    async fn remove_dependency(&self, task_id: i64, blocked_by_task_id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        self.get_task_for(task_id, actor, TaskAction::Update).await?;
+
+        if !self.repository.remove_dependency(task_id, blocked_by_task_id, actor.id).await? {
+            return Err(not_found_error(TASK_DEPENDENCY_NOT_FOUND, blocked_by_task_id));
         }
-        self.repository.delete_task(id).await
+
+        Ok(())
+    }
+
+    async fn list_trash(&self, mut filter: TaskFilter, actor: &AuthUser) -> Result<TaskPage, CustomError> {
+        // ดูถังขยะและกู้คืนได้เฉพาะผู้ที่มีสิทธิ์ลบ (Admin ของ project)
+        if let Some(project_id) = filter.project_id {
+            self.project_actor(project_id, actor, TaskAction::Delete).await?;
+        }
+        if actor.role != Role::Admin {
+            filter.member_id = Some(actor.id);
+        }
+        filter.deleted = true;
+
+        self.repository.list_task(filter).await
+    }
+
+    async fn restore_task(&self, id: i64, actor: &AuthUser) -> Result<(), CustomError> {
+        let task = self.repository.get_deleted_task(id).await?;
+        self.project_actor(task.project_id, actor, TaskAction::Delete).await?;
+
+        // subtask ที่ task แม่ยังอยู่ในถังขยะต้องกู้คืน task แม่ก่อน
+        if let Some(parent_task_id) = task.parent_task_id {
+            match self.repository.get_task(parent_task_id).await {
+                Err(CustomError::NotFound(_)) => return Err(CustomError::BusinessError(PARENT_TASK_IN_TRASH.to_string())),
+                Err(e) => return Err(e),
+                Ok(_) => {}
+            }
+        }
+
+        self.repository.restore_task(id, actor.id).await
+    }
+
+    async fn list_task_history(&self, filter: TaskHistoryFilter, actor: &AuthUser) -> Result<TaskHistoryPage, CustomError> {
+        self.get_task_for(filter.task_id, actor, TaskAction::Read).await?;
+
+        self.repository.list_task_history(filter).await
+    }
+}
+
+#[derive(Clone, Copy)]
+enum TaskAction {
+    Read,
+    Create,
+    Update,
+    Delete,
+}
+
+// ตารางสิทธิ์ตาม role ใน project ของ task
+// Admin: ทำได้ทุกอย่าง, Manager: ลบไม่ได้, Member: สร้าง/แก้ไข/อ่าน เฉพาะที่สร้างเองหรือได้รับมอบหมาย, Viewer: อ่านอย่างเดียว
+fn authorize(actor: &AuthUser, action: TaskAction) -> Result<(), CustomError> {
+    let allowed = match actor.role {
+        Role::Admin => true,
+        Role::Manager | Role::Member => !matches!(action, TaskAction::Delete),
+        Role::Viewer => matches!(action, TaskAction::Read),
+    };
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()))
+    }
+}
+
+fn validate_schedule(start_date: Option<NaiveDateTime>, due_date: Option<NaiveDateTime>) -> Result<(), CustomError> {
+    if let (Some(start_date), Some(due_date)) = (start_date, due_date) {
+        if start_date > due_date {
+            return Err(CustomError::ValidationError(INVALID_TASK_SCHEDULE.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+fn find_task_status(task_status: &[MasterDataTaskStatus], task_status_ref: &MasterDataRef) -> Result<i64, CustomError> {
+    task_status
+        .iter()
+        .find(|status| task_status_ref.matches(status.id, &status.code))
+        .map(|status| status.id)
+        .ok_or(CustomError::ValidationError(format!("{}: {}", INVALID_TASK_STATUS, task_status_ref)))
+}
+
+fn find_priority_levels(priority_levels: &[MasterDataPriorityLevels], priority_levels_ref: &MasterDataRef) -> Result<i64, CustomError> {
+    priority_levels
+        .iter()
+        .find(|priority| priority_levels_ref.matches(priority.id, &priority.code))
+        .map(|priority| priority.id)
+        .ok_or(CustomError::ValidationError(format!("{}: {}", INVALID_PRIORITY_LEVELS, priority_levels_ref)))
+}
+
+fn task_create_entity(task: TaskCreateEntity<MasterDataRef>, task_status_id: i64, priority_levels_id: i64) -> TaskCreateEntity {
+    TaskCreateEntity {
+        task_status_id,
+        priority_levels_id,
+        project_id: task.project_id,
+        parent_task_id: task.parent_task_id,
+        title: task.title,
+        description: task.description,
+        start_date: task.start_date,
+        due_date: task.due_date,
+        created_by: task.created_by,
     }
 }
 
 fn not_found_error(item: &str, id: i64) -> CustomError {
     CustomError::NotFound(format!("{}: {}", item, id))
-}
\ No newline at end of file
+}
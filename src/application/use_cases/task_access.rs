@@ -0,0 +1,59 @@
+use crate::domain::entities::auth::{AuthUser, Role};
+use crate::domain::entities::task::Task;
+use crate::domain::repositories::project::ProjectRepositories;
+use crate::domain::repositories::task::TaskRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{PERMISSION_DENIED, PROJECT_ARCHIVED};
+
+// สิทธิ์ของข้อมูลที่ผูกกับ task (ความคิดเห็น, ไฟล์แนบ)
+#[derive(Clone, Copy)]
+pub enum TaskAccess {
+    Read,
+    Write,
+}
+
+// role ของ actor ใน project: Admin ของระบบเป็น Admin ทุก project ส่วนคนอื่นต้องเป็นสมาชิก
+pub async fn project_role<P: ProjectRepositories>(project_repository: &P, project_id: i64, actor: &AuthUser) -> Result<Role, CustomError> {
+    match actor.role {
+        Role::Admin => Ok(Role::Admin),
+        _ => project_repository
+            .member_role(project_id, actor.id)
+            .await?
+            .ok_or(CustomError::Forbidden(PERMISSION_DENIED.to_string())),
+    }
+}
+
+// Member เห็นเฉพาะ task ที่สร้างเองหรือได้รับมอบหมาย (actor ต้องมี role ใน project แล้ว)
+pub fn ensure_task_visible(task: &Task, actor: &AuthUser) -> Result<(), CustomError> {
+    if actor.role == Role::Member && task.created_by != actor.id && !task.assignees.contains(&actor.id) {
+        return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+    }
+
+    Ok(())
+}
+
+// ตรวจสิทธิ์เข้าถึง task ตามกติกาเดียวกับการอ่าน task แล้วคืน actor ที่ role เปลี่ยนเป็น role ใน project
+// Write ได้ทุก role ยกเว้น Viewer และ project ต้องยังไม่ถูกเก็บเข้าคลัง
+pub async fn task_actor<T: TaskRepositories, P: ProjectRepositories>(
+    task_repository: &T,
+    project_repository: &P,
+    task_id: i64,
+    actor: &AuthUser,
+    access: TaskAccess,
+) -> Result<AuthUser, CustomError> {
+    let task = task_repository.get_task(task_id).await?;
+    let project = project_repository.get_project(task.project_id).await?;
+    let task_actor = AuthUser { role: project_role(project_repository, task.project_id, actor).await?, ..*actor };
+    ensure_task_visible(&task, &task_actor)?;
+
+    if matches!(access, TaskAccess::Write) {
+        if task_actor.role == Role::Viewer {
+            return Err(CustomError::Forbidden(PERMISSION_DENIED.to_string()));
+        }
+        if project.archived_at.is_some() {
+            return Err(CustomError::BusinessError(PROJECT_ARCHIVED.to_string()));
+        }
+    }
+
+    Ok(task_actor)
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Attachment {
+    pub id: i64,
+    pub task_id: i64,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    // ใช้ภายในเท่านั้น ไม่ส่งออกไปใน response
+    #[serde(skip)]
+    pub storage_key: String,
+    pub created_by: i64,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AttachmentID {
+    pub id: i64,
+}
+
+pub struct AttachmentCreateEntity {
+    pub task_id: i64,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_by: i64,
+}
+
+// ไฟล์ที่อัปโหลดเข้ามา (อ่านทั้งไฟล์ไว้ใน memory แล้ว ขนาดไม่เกินที่ตั้งใน config)
+pub struct AttachmentUpload {
+    pub task_id: i64,
+    pub file_name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+    pub created_by: i64,
+}
+
+pub struct AttachmentFile {
+    pub attachment: Attachment,
+    pub data: Vec<u8>,
+}
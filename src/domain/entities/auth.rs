@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime;
 use serde_derive::{Deserialize, Serialize};
 
 pub struct Login {
@@ -7,9 +8,133 @@ pub struct Login {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LoginToken {
     pub token: String,
+    #[serde(rename = "refreshToken")]
+    pub refresh_token: String,
 }
 
 pub struct User {
     pub id: i64,
     pub password: String,
-}
\ No newline at end of file
+    pub role_id: Option<i64>,
+    pub active: bool,
+}
+
+// Role ของผู้ใช้งาน ผูกกับ code ใน master_data_role
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Role {
+    Admin,
+    Manager,
+    Member,
+    Viewer,
+}
+
+impl Role {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "ADMIN" => Some(Role::Admin),
+            "MANAGER" => Some(Role::Manager),
+            "MEMBER" => Some(Role::Member),
+            "VIEWER" => Some(Role::Viewer),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Role::Admin => "ADMIN",
+            Role::Manager => "MANAGER",
+            Role::Member => "MEMBER",
+            Role::Viewer => "VIEWER",
+        }
+    }
+}
+
+// ผลตรวจ access token กับฐานข้อมูลใน JwtMiddleware
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessTokenStatus {
+    Valid,
+    // token ถูกยกเลิกตอน logout
+    Revoked,
+    // ผู้ใช้ถูกปิดบัญชีหรือ role ไม่ตรงกับใน token แล้ว
+    UserChanged,
+}
+
+// ผู้ใช้งานที่ผ่านการยืนยันตัวตนแล้ว (ถอดมาจาก JWT)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuthUser {
+    pub id: i64,
+    pub role: Role,
+}
+
+pub struct RefreshToken {
+    pub user_id: i64,
+    pub family_id: String,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshTokenCreate {
+    pub token_hash: String,
+    pub user_id: i64,
+    pub family_id: String,
+    pub expires_at: NaiveDateTime,
+}
+
+// ข้อมูลของ access token ปัจจุบันที่ต้องยกเลิกตอน logout
+pub struct Logout {
+    pub user_id: i64,
+    pub jti: String,
+    pub expires_at: NaiveDateTime,
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UserProfile {
+    pub id: i64,
+    pub username: String,
+    pub role_id: Option<i64>,
+    pub role: Option<String>,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UserID {
+    pub id: i64,
+}
+
+pub struct Register {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserCreateEntity {
+    pub username: String,
+    pub password: String,
+    pub role_id: i64,
+    pub created_by: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UpdateUser {
+    pub id: i64,
+    pub role_id: i64,
+    pub active: bool,
+    pub updated_by: i64,
+}
+
+pub struct ChangePassword {
+    pub user_id: i64,
+    pub current_password: String,
+    pub new_password: String,
+}
+
+pub struct ResetPassword {
+    pub id: i64,
+    pub new_password: String,
+    pub updated_by: i64,
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::task::TaskCursor;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Comment {
+    pub id: i64,
+    pub task_id: i64,
+    pub body: String,
+    pub created_by: i64,
+    pub created_at: NaiveDateTime,
+    // มีค่าเมื่อแก้ไขแล้ว
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommentID {
+    pub id: i64,
+}
+
+pub struct CommentCreateEntity {
+    pub task_id: i64,
+    pub body: String,
+    pub created_by: i64,
+}
+
+// แก้ไขได้เฉพาะข้อความ
+pub struct UpdateComment {
+    pub id: i64,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentFilter {
+    pub task_id: i64,
+    pub cursor: Option<TaskCursor>,
+    pub limit: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CommentPage {
+    pub items: Vec<Comment>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
@@ -0,0 +1,40 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Label {
+    pub id: i64,
+    pub name: String,
+    // #rrggbb
+    pub color: String,
+    pub created_by: i64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+    pub updated_by: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct LabelID {
+    pub id: i64,
+}
+
+pub struct LabelCreateEntity {
+    pub name: String,
+    pub color: String,
+    pub created_by: i64,
+}
+
+pub struct UpdateLabel {
+    pub id: i64,
+    pub name: String,
+    pub color: String,
+    pub updated_by: i64,
+}
+
+// label ที่แสดงใน task
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskLabel {
+    pub id: i64,
+    pub name: String,
+    pub color: String,
+}
@@ -1,22 +1,138 @@
+use chrono::NaiveDateTime;
 use serde_derive::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MasterDataTaskStatus {
     pub id: i64,
     pub title: String,
     pub code: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MasterDataRole {
     pub id: i64,
     pub title: String,
     pub code: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MasterDataPriorityLevels {
     pub id: i64,
     pub title: String,
     pub code: String,
+}
+
+// เส้นทางการเปลี่ยนสถานะ 1 รายการ role_code เป็น None หมายถึงทุก role
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasterDataTaskStatusTransition {
+    pub from_status_id: i64,
+    pub to_status_id: i64,
+    pub title: String,
+    pub code: String,
+    pub role_code: Option<String>,
+}
+
+// สถานะถัดไปที่เปลี่ยนได้ roles ว่างหมายถึงทุก role
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct TaskStatusTransition {
+    pub id: i64,
+    pub title: String,
+    pub code: String,
+    pub roles: Vec<String>,
+}
+
+// อ้างอิง master data ได้ทั้ง id และ code เช่น 7250066663482068992 หรือ "IN_PROGRESS"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MasterDataRef {
+    Id(i64),
+    Code(String),
+}
+
+impl MasterDataRef {
+    pub fn matches(&self, id: i64, code: &str) -> bool {
+        match self {
+            MasterDataRef::Id(value) => *value == id,
+            // id ที่ส่งมาเป็น string (เลยช่วง number ของ JavaScript) ให้เทียบเป็น id
+            MasterDataRef::Code(value) => value.parse::<i64>() == Ok(id) || value.eq_ignore_ascii_case(code),
+        }
+    }
+}
+
+impl std::fmt::Display for MasterDataRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MasterDataRef::Id(value) => write!(f, "{}", value),
+            MasterDataRef::Code(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// ตาราง master data ที่ Admin จัดการได้ ใช้เป็นส่วนหนึ่งของ path เช่น /master-data/task-status
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MasterDataKind {
+    #[serde(rename = "task-status")]
+    TaskStatus,
+    #[serde(rename = "priority-levels")]
+    PriorityLevels,
+    #[serde(rename = "role")]
+    Role,
+}
+
+impl MasterDataKind {
+    pub fn code(&self) -> &'static str {
+        match self {
+            MasterDataKind::TaskStatus => "task-status",
+            MasterDataKind::PriorityLevels => "priority-levels",
+            MasterDataKind::Role => "role",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "task-status" => Some(MasterDataKind::TaskStatus),
+            "priority-levels" => Some(MasterDataKind::PriorityLevels),
+            "role" => Some(MasterDataKind::Role),
+            _ => None,
+        }
+    }
+}
+
+// master data สำหรับหน้า Admin รวมรายการที่ปิดใช้งานแล้ว
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct MasterDataItem {
+    pub id: i64,
+    pub seq: i32,
+    pub title: String,
+    pub code: String,
+    pub active: bool,
+    pub created_by: i64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+    pub updated_by: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct MasterDataID {
+    pub id: i64,
+}
+
+pub struct MasterDataCreateEntity {
+    pub title: String,
+    pub code: String,
+    pub created_by: i64,
+}
+
+// แก้ไขได้เฉพาะชื่อ code ใช้อ้างอิงจาก client และในเงื่อนไขของระบบจึงเปลี่ยนไม่ได้
+pub struct UpdateMasterData {
+    pub id: i64,
+    pub title: String,
+    pub updated_by: i64,
+}
+
+// replacement_id: ย้ายข้อมูลที่ยังอ้างอิงอยู่ไปใช้ค่านี้ก่อนปิดใช้งาน
+pub struct MasterDataDeactivate {
+    pub id: i64,
+    pub replacement_id: Option<i64>,
+    pub updated_by: i64,
 }
\ No newline at end of file
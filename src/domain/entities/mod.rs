@@ -1,3 +1,7 @@
 pub mod master_data;
 pub mod task;
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod project;
+pub mod comment;
+pub mod attachment;
+pub mod label;
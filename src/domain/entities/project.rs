@@ -0,0 +1,60 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Project {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    // มีค่าเมื่อเก็บเข้าคลังแล้ว
+    pub archived_at: Option<NaiveDateTime>,
+    pub archived_by: Option<i64>,
+    pub created_by: i64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+    pub updated_by: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProjectID {
+    pub id: i64,
+}
+
+// ผู้สร้างจะถูกเพิ่มเป็นสมาชิก role Admin ของ project
+pub struct ProjectCreateEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub created_by: i64,
+}
+
+pub struct UpdateProject {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub updated_by: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct ProjectFilter {
+    // เฉพาะ project ที่ผู้ใช้งานคนนี้เป็นสมาชิก (None = ทั้งหมด)
+    pub member_id: Option<i64>,
+    pub archived: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProjectMember {
+    pub user_id: i64,
+    pub username: String,
+    pub role_id: i64,
+    pub role: String,
+    pub added_by: i64,
+    pub added_at: NaiveDateTime,
+}
+
+// เพิ่มสมาชิกใหม่หรือเปลี่ยน role ของสมาชิกเดิม
+pub struct ProjectMemberSet {
+    pub project_id: i64,
+    pub user_id: i64,
+    pub role_id: i64,
+    pub added_by: i64,
+}
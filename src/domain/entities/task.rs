@@ -1,12 +1,21 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use chrono::NaiveDateTime;
 use serde::{
     Deserialize,
     Serialize,
 };
+use serde_json::Value;
+use crate::domain::entities::label::TaskLabel;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{INVALID_CURSOR, INVALID_EXPAND};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Task {
     pub id: i64,
+    pub project_id: i64,
+    // task แม่ (None = task ระดับบนสุด)
+    pub parent_task_id: Option<i64>,
     pub title: String,
     pub description: Option<String>,
     pub task_status_id: Option<i64>,
@@ -15,6 +24,75 @@ pub struct Task {
     pub created_at: NaiveDateTime,
     pub updated_at: Option<NaiveDateTime>,
     pub updated_by: Option<i64>,
+    pub assignees: Vec<i64>,
+    pub start_date: Option<NaiveDateTime>,
+    pub due_date: Option<NaiveDateTime>,
+    // เลยกำหนดส่งและยังไม่เสร็จ
+    pub overdue: bool,
+    // มีค่าเฉพาะ task ที่อยู่ในถังขยะ
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<i64>,
+    // เพิ่มขึ้นทุกครั้งที่แก้ไข ส่งกลับเป็น ETag
+    pub version: i32,
+    // จำนวน subtask ชั้นถัดไปที่ยังไม่ถูกลบ และจำนวนที่สถานะเป็น COMPLETED
+    pub subtask_count: i64,
+    pub completed_subtask_count: i64,
+    // task ที่ต้องเสร็จก่อน task นี้ และ task ที่รอ task นี้อยู่ (ไม่รวมที่อยู่ในถังขยะ)
+    pub blocked_by: Vec<i64>,
+    pub blocks: Vec<i64>,
+    // เรียงตามชื่อ label
+    pub labels: Vec<TaskLabel>,
+    // มีค่าเฉพาะเมื่อขอผ่าน ?expand=status,priority,createdBy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_status: Option<TaskMasterData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_levels: Option<TaskMasterData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by_user: Option<TaskUser>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskMasterData {
+    pub id: i64,
+    pub code: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskUser {
+    pub id: i64,
+    pub username: String,
+}
+
+// ข้อมูลที่ join เพิ่มใน task (ค่าเริ่มต้นไม่ join ตอบแบบ flat เหมือนเดิม)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TaskExpand {
+    pub status: bool,
+    pub priority: bool,
+    pub created_by: bool,
+}
+
+impl TaskExpand {
+    // รูปแบบ status,priority,createdBy (ไม่สนลำดับและค่าซ้ำ)
+    pub fn parse(value: &str) -> Result<Self, CustomError> {
+        let mut expand = TaskExpand::default();
+        for item in value.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            match item {
+                "status" => expand.status = true,
+                "priority" => expand.priority = true,
+                "createdBy" => expand.created_by = true,
+                _ => return Err(CustomError::ValidationError(INVALID_EXPAND.to_string())),
+            }
+        }
+
+        Ok(expand)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !(self.status || self.priority || self.created_by)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -22,31 +100,447 @@ pub struct TaskID {
     pub id: i64,
 }
 
-pub struct TaskCreateEntity {
+// R คือชนิดของการอ้างอิง master data: MasterDataRef ตอนรับจาก client และ i64 หลังตรวจสอบแล้ว
+pub struct TaskCreateEntity<R = i64> {
+    pub project_id: i64,
+    pub parent_task_id: Option<i64>,
     pub title: String,
     pub description: Option<String>,
-    pub task_status_id: i64,
-    pub priority_levels_id: i64,
+    pub task_status_id: R,
+    pub priority_levels_id: R,
+    pub start_date: Option<NaiveDateTime>,
+    pub due_date: Option<NaiveDateTime>,
     pub created_by: i64,
 }
 
-pub struct UpdateTask {
+pub struct UpdateTask<R = i64> {
     pub id: i64,
+    // ย้าย task ไป project อื่นได้
+    pub project_id: i64,
+    pub parent_task_id: Option<i64>,
     pub title: String,
     pub description: Option<String>,
-    pub task_status_id: i64,
-    pub priority_levels_id: i64,
+    pub task_status_id: R,
+    pub priority_levels_id: R,
+    pub start_date: Option<NaiveDateTime>,
+    pub due_date: Option<NaiveDateTime>,
     pub updated_by: i64,
+    // version จาก If-Match (None = `*` ไม่ตรวจสอบ version)
+    pub version: Option<i32>,
 }
 
-pub struct UpdateTaskStatus {
+// แก้ไขบางส่วน: None = ไม่แก้ไข, Some(None) = ล้างค่า
+pub struct PatchTask<R = i64> {
     pub id: i64,
-    pub task_status_id: i64,
+    pub project_id: Option<i64>,
+    pub parent_task_id: Option<Option<i64>>,
+    pub title: Option<String>,
+    pub description: Option<Option<String>>,
+    pub task_status_id: Option<R>,
+    pub priority_levels_id: Option<R>,
+    pub start_date: Option<Option<NaiveDateTime>>,
+    pub due_date: Option<Option<NaiveDateTime>>,
     pub updated_by: i64,
+    pub version: Option<i32>,
 }
 
-pub struct UpdateTaskPriorityLevels {
+impl<R> PatchTask<R> {
+    pub fn is_empty(&self) -> bool {
+        self.project_id.is_none()
+            && self.parent_task_id.is_none()
+            && self.title.is_none()
+            && self.description.is_none()
+            && self.task_status_id.is_none()
+            && self.priority_levels_id.is_none()
+            && self.start_date.is_none()
+            && self.due_date.is_none()
+    }
+}
+
+pub struct UpdateTaskStatus<R = i64> {
     pub id: i64,
-    pub priority_levels_id: i64,
+    pub task_status_id: R,
     pub updated_by: i64,
-}
\ No newline at end of file
+    pub version: Option<i32>,
+}
+
+pub struct UpdateTaskPriorityLevels<R = i64> {
+    pub id: i64,
+    pub priority_levels_id: R,
+    pub updated_by: i64,
+    pub version: Option<i32>,
+}
+
+// task_id ถูก block โดย blocked_by_task_id
+pub struct TaskDependency {
+    pub task_id: i64,
+    pub blocked_by_task_id: i64,
+    pub created_by: i64,
+}
+
+pub struct TaskAssign {
+    pub task_id: i64,
+    pub user_ids: Vec<i64>,
+    pub assigned_by: i64,
+}
+
+pub struct TaskLabelAdd {
+    pub task_id: i64,
+    pub label_ids: Vec<i64>,
+    pub labeled_by: i64,
+}
+
+// atomic = สำเร็จทุกรายการหรือไม่เปลี่ยนอะไรเลย, bestEffort = ทำรายการที่ผ่านและคืนผลแยกรายการ
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TaskBulkMode {
+    #[default]
+    #[serde(rename = "atomic")]
+    Atomic,
+    #[serde(rename = "bestEffort")]
+    BestEffort,
+}
+
+// การแก้ไขที่ทำแบบกลุ่มได้ (R = id หรือ code ของ master data)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskBulkAction<R = i64> {
+    UpdateStatus(R),
+    UpdatePriority(R),
+    Assign(Vec<i64>),
+    Delete,
+}
+
+impl<R> TaskBulkAction<R> {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaskBulkAction::UpdateStatus(..) => "updateStatus",
+            TaskBulkAction::UpdatePriority(..) => "updatePriority",
+            TaskBulkAction::Assign(..) => "assign",
+            TaskBulkAction::Delete => "delete",
+        }
+    }
+}
+
+pub struct TaskBulkOperation<R = i64> {
+    pub action: TaskBulkAction<R>,
+    pub task_ids: Vec<i64>,
+}
+
+pub struct TaskBulk<R = i64> {
+    pub mode: TaskBulkMode,
+    pub operations: Vec<TaskBulkOperation<R>>,
+}
+
+// รายการที่ตรวจสิทธิ์และเงื่อนไขแล้ว (operation_index = ลำดับ operation ใน request)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskBulkItem {
+    pub operation_index: usize,
+    pub task_id: i64,
+    pub action: TaskBulkAction,
+}
+
+impl TaskBulkItem {
+    // ระบุรายการที่ทำให้ bulk แบบ atomic ล้มในข้อความ error
+    pub fn context(&self) -> String {
+        format!("operations[{}] task {}", self.operation_index, self.task_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskBulkItemResult {
+    pub operation_index: usize,
+    pub operation: String,
+    pub task_id: i64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TaskBulkItemResult {
+    pub fn new(operation_index: usize, operation: &str, task_id: i64, error: Option<String>) -> Self {
+        Self {
+            operation_index,
+            operation: operation.to_string(),
+            task_id,
+            success: error.is_none(),
+            error,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskBulkResult {
+    pub mode: TaskBulkMode,
+    pub succeeded: usize,
+    pub failed: usize,
+    // เรียงตามลำดับใน request
+    pub items: Vec<TaskBulkItemResult>,
+}
+
+// แถวจากไฟล์ import (row นับจาก 1 ไม่รวม header) task เป็น Err เมื่ออ่านแถวนั้นไม่ได้
+pub struct TaskImportRow<R = i64> {
+    pub row: usize,
+    pub task: Result<TaskCreateEntity<R>, String>,
+}
+
+pub struct TaskImport<R = i64> {
+    // true = ตรวจอย่างเดียวไม่บันทึก
+    pub dry_run: bool,
+    pub rows: Vec<TaskImportRow<R>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskImportError {
+    pub row: usize,
+    pub error: String,
+}
+
+// บันทึกเมื่อทุกแถวผ่านเท่านั้น (imported = 0 เมื่อมี error หรือเป็น dry run)
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskImportResult {
+    pub dry_run: bool,
+    pub total: usize,
+    pub valid: usize,
+    pub imported: usize,
+    pub task_ids: Vec<i64>,
+    pub errors: Vec<TaskImportError>,
+}
+
+// any = มี label ใดก็ได้ในรายการ, all = ต้องมีครบทุก label
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum LabelMatch {
+    #[default]
+    #[serde(rename = "any")]
+    Any,
+    #[serde(rename = "all")]
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TaskSortField {
+    #[default]
+    #[serde(rename = "id")]
+    Id,
+    #[serde(rename = "createdAt")]
+    CreatedAt,
+    #[serde(rename = "updatedAt")]
+    UpdatedAt,
+    #[serde(rename = "title")]
+    Title,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum SortDirection {
+    #[serde(rename = "asc")]
+    Asc,
+    #[default]
+    #[serde(rename = "desc")]
+    Desc,
+}
+
+// ตำแหน่งของแถวสุดท้ายในหน้าก่อนหน้า (keyset pagination) ใช้ snowflake id เป็นตัวตัดสินเมื่อค่า sort ซ้ำกัน
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskCursor {
+    pub id: i64,
+    pub value: Option<String>,
+}
+
+impl TaskCursor {
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("Failed to serialize cursor");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, CustomError> {
+        URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()
+            .and_then(|json| serde_json::from_slice(&json).ok())
+            .ok_or(CustomError::ValidationError(INVALID_CURSOR.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskFilter {
+    pub project_id: Option<i64>,
+    // เฉพาะ subtask ชั้นถัดไปของ task นี้
+    pub parent_task_id: Option<i64>,
+    pub task_status_id: Option<i64>,
+    pub priority_levels_id: Option<i64>,
+    pub created_by: Option<i64>,
+    pub assignee: Option<i64>,
+    // จำกัดเฉพาะ task ใน project ที่ user เป็นสมาชิก ตามสิทธิ์ของ role ใน project นั้น
+    pub member_id: Option<i64>,
+    pub created_from: Option<NaiveDateTime>,
+    pub created_to: Option<NaiveDateTime>,
+    pub updated_from: Option<NaiveDateTime>,
+    pub updated_to: Option<NaiveDateTime>,
+    pub due_before: Option<NaiveDateTime>,
+    pub due_this_week: bool,
+    pub overdue: Option<bool>,
+    // true = ดูเฉพาะ task ในถังขยะ
+    pub deleted: bool,
+    pub search: Option<String>,
+    // ชื่อ label ตัวพิมพ์เล็ก
+    pub labels: Vec<String>,
+    pub label_match: LabelMatch,
+    pub sort_by: TaskSortField,
+    pub sort_direction: SortDirection,
+    pub cursor: Option<TaskCursor>,
+    pub limit: i64,
+    pub expand: TaskExpand,
+}
+
+impl Default for TaskFilter {
+    fn default() -> Self {
+        Self {
+            project_id: None,
+            parent_task_id: None,
+            task_status_id: None,
+            priority_levels_id: None,
+            created_by: None,
+            assignee: None,
+            member_id: None,
+            created_from: None,
+            created_to: None,
+            updated_from: None,
+            updated_to: None,
+            due_before: None,
+            due_this_week: false,
+            overdue: None,
+            deleted: false,
+            search: None,
+            labels: vec![],
+            label_match: LabelMatch::default(),
+            sort_by: TaskSortField::default(),
+            sort_direction: SortDirection::default(),
+            cursor: None,
+            limit: DEFAULT_PAGE_SIZE,
+            expand: TaskExpand::default(),
+        }
+    }
+}
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+
+#[derive(Debug, PartialEq)]
+pub struct TaskPage {
+    pub items: Vec<Task>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+// ผลการค้นหา full-text เรียงตาม rank จากมากไปน้อย
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskSearchHit {
+    pub task: Task,
+    pub rank: f32,
+    pub highlight: TaskSearchHighlight,
+}
+
+// ข้อความที่ escape HTML แล้ว คำที่ตรงถูกครอบด้วย <mark></mark>
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskSearchHighlight {
+    pub title: String,
+    pub description: Option<String>,
+    // comment ที่ตรงที่สุดของ task นี้
+    pub comment_id: Option<i64>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TaskSearchPage {
+    pub items: Vec<TaskSearchHit>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+// ผลการลบถาวร task ในถังขยะ
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TaskPurgeResult {
+    pub purged: u64,
+    // key ของไฟล์แนบที่ต้องลบออกจาก storage หลัง commit
+    pub storage_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TaskHistoryOperation {
+    Create,
+    Update,
+    Delete,
+    Assign,
+    Unassign,
+    Restore,
+    Purge,
+    Link,
+    Unlink,
+    Label,
+    Unlabel,
+}
+
+impl TaskHistoryOperation {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaskHistoryOperation::Create => "CREATE",
+            TaskHistoryOperation::Update => "UPDATE",
+            TaskHistoryOperation::Delete => "DELETE",
+            TaskHistoryOperation::Assign => "ASSIGN",
+            TaskHistoryOperation::Unassign => "UNASSIGN",
+            TaskHistoryOperation::Restore => "RESTORE",
+            TaskHistoryOperation::Purge => "PURGE",
+            TaskHistoryOperation::Link => "LINK",
+            TaskHistoryOperation::Unlink => "UNLINK",
+            TaskHistoryOperation::Label => "LABEL",
+            TaskHistoryOperation::Unlabel => "UNLABEL",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "CREATE" => Some(TaskHistoryOperation::Create),
+            "UPDATE" => Some(TaskHistoryOperation::Update),
+            "DELETE" => Some(TaskHistoryOperation::Delete),
+            "ASSIGN" => Some(TaskHistoryOperation::Assign),
+            "UNASSIGN" => Some(TaskHistoryOperation::Unassign),
+            "RESTORE" => Some(TaskHistoryOperation::Restore),
+            "PURGE" => Some(TaskHistoryOperation::Purge),
+            "LINK" => Some(TaskHistoryOperation::Link),
+            "UNLINK" => Some(TaskHistoryOperation::Unlink),
+            "LABEL" => Some(TaskHistoryOperation::Label),
+            "UNLABEL" => Some(TaskHistoryOperation::Unlabel),
+            _ => None,
+        }
+    }
+}
+
+// ค่าก่อนและหลังของ field ที่ถูกเปลี่ยน (null คือไม่มีค่า)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskFieldChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskHistory {
+    pub id: i64,
+    pub task_id: i64,
+    pub operation: TaskHistoryOperation,
+    pub changes: Vec<TaskFieldChange>,
+    pub actor_id: i64,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskHistoryFilter {
+    pub task_id: i64,
+    pub cursor: Option<TaskCursor>,
+    pub limit: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TaskHistoryPage {
+    pub items: Vec<TaskHistory>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
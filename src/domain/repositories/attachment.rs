@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use mockall::automock;
+use crate::domain::entities::attachment::{Attachment, AttachmentCreateEntity};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[automock]
+#[async_trait]
+pub trait AttachmentRepositories: Send + Sync {
+    async fn list_attachment(&self, task_id: i64) -> Result<Vec<Attachment>, CustomError>;
+    async fn get_attachment(&self, id: i64) -> Result<Attachment, CustomError>;
+    async fn create_attachment(&self, attachment: AttachmentCreateEntity) -> Result<i64, CustomError>;
+    async fn delete_attachment(&self, id: i64) -> Result<(), CustomError>;
+}
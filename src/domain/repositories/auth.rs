@@ -1,8 +1,33 @@
 use async_trait::async_trait;
-use crate::domain::entities::auth::User;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use crate::domain::entities::auth::{AccessTokenStatus, RefreshToken, RefreshTokenCreate, Role, UpdateUser, User, UserCreateEntity, UserProfile};
 use crate::shared::exceptions::custom_error::CustomError;
 
+#[automock]
 #[async_trait]
 pub trait AuthRepositories: Send + Sync {
     async fn user_exists(&self, username: &str) -> Result<User, CustomError>;
+    async fn get_user(&self, id: i64) -> Result<User, CustomError>;
+    async fn create_refresh_token(&self, token: RefreshTokenCreate) -> Result<(), CustomError>;
+    async fn get_refresh_token(&self, token_hash: &str) -> Result<Option<RefreshToken>, CustomError>;
+    // ปิด token เดิมและสร้าง token ใหม่ใน transaction เดียว คืน false ถ้า token เดิมถูกใช้ไปแล้ว
+    async fn rotate_refresh_token(&self, token_hash: &str, new_token: RefreshTokenCreate) -> Result<bool, CustomError>;
+    async fn revoke_refresh_token_family(&self, family_id: &str) -> Result<(), CustomError>;
+    async fn revoke_access_token(&self, jti: &str, user_id: i64, expires_at: NaiveDateTime) -> Result<(), CustomError>;
+    async fn revoke_user_refresh_tokens(&self, user_id: i64) -> Result<(), CustomError>;
+    async fn list_users(&self) -> Result<Vec<UserProfile>, CustomError>;
+    async fn get_user_profile(&self, id: i64) -> Result<UserProfile, CustomError>;
+    async fn username_exists(&self, username: &str) -> Result<bool, CustomError>;
+    async fn create_user(&self, user: UserCreateEntity) -> Result<i64, CustomError>;
+    async fn update_user(&self, user: UpdateUser) -> Result<(), CustomError>;
+    async fn update_password(&self, id: i64, password: String, updated_by: i64) -> Result<(), CustomError>;
+}
+
+// ใช้ใน JwtMiddleware เพื่อตรวจว่า access token ถูกยกเลิกไปแล้วหรือยัง
+#[automock]
+#[async_trait]
+pub trait TokenDenylistRepositories: Send + Sync {
+    // ตรวจ jti ใน denylist พร้อมกับสถานะ active และ role ของผู้ใช้ใน query เดียว
+    async fn access_token_status(&self, jti: &str, user_id: i64, role: Role) -> Result<AccessTokenStatus, CustomError>;
 }
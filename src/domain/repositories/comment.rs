@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use mockall::automock;
+use crate::domain::entities::comment::{Comment, CommentCreateEntity, CommentFilter, CommentPage, UpdateComment};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[automock]
+#[async_trait]
+pub trait CommentRepositories: Send + Sync {
+    // เรียงตามเวลาที่เขียน เก่าสุดก่อน
+    async fn list_comment(&self, filter: CommentFilter) -> Result<CommentPage, CustomError>;
+    async fn get_comment(&self, id: i64) -> Result<Comment, CustomError>;
+    async fn create_comment(&self, comment: CommentCreateEntity) -> Result<i64, CustomError>;
+    async fn update_comment(&self, comment: UpdateComment) -> Result<(), CustomError>;
+    async fn delete_comment(&self, id: i64) -> Result<(), CustomError>;
+}
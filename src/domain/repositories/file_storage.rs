@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use mockall::automock;
+use crate::shared::exceptions::custom_error::CustomError;
+
+// ที่เก็บไฟล์แนบ เลือก implementation จาก ATTACHMENT_STORAGE (local หรือ s3)
+#[automock]
+#[async_trait]
+pub trait FileStorage: Send + Sync {
+    async fn put_object(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<(), CustomError>;
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>, CustomError>;
+    // ลบ key ที่ไม่มีอยู่แล้วถือว่าสำเร็จ
+    async fn delete_object(&self, key: &str) -> Result<(), CustomError>;
+}
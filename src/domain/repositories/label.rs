@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use mockall::automock;
+use crate::domain::entities::label::{Label, LabelCreateEntity, UpdateLabel};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[automock]
+#[async_trait]
+pub trait LabelRepositories: Send + Sync {
+    async fn list_label(&self) -> Result<Vec<Label>, CustomError>;
+    async fn get_label(&self, id: i64) -> Result<Label, CustomError>;
+    // ชื่อซ้ำแบบไม่สนตัวพิมพ์ใหญ่/เล็ก ยกเว้น label id ที่กำหนด (ใช้ตอนแก้ไข)
+    async fn label_name_exists(&self, name: String, exclude_id: Option<i64>) -> Result<bool, CustomError>;
+    async fn create_label(&self, label: LabelCreateEntity) -> Result<i64, CustomError>;
+    async fn update_label(&self, label: UpdateLabel) -> Result<(), CustomError>;
+    // ถอด label ออกจากทุก task ด้วย
+    async fn delete_label(&self, id: i64) -> Result<(), CustomError>;
+}
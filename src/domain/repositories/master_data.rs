@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use mockall::automock;
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::domain::entities::master_data::{MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus};
+use crate::domain::entities::master_data::{
+    MasterDataCreateEntity, MasterDataDeactivate, MasterDataItem, MasterDataKind, MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus,
+    MasterDataTaskStatusTransition, UpdateMasterData,
+};
 
 #[automock]
 #[async_trait]
@@ -9,4 +12,23 @@ pub trait MasterDataRepositories: Send + Sync {
     async fn list_task_status(&self) -> Result<Vec<MasterDataTaskStatus>, CustomError>;
     async fn list_role(&self) -> Result<Vec<MasterDataRole>, CustomError>;
     async fn list_priority_levels(&self) -> Result<Vec<MasterDataPriorityLevels>, CustomError>;
+    async fn list_task_status_transition(&self, from_status_id: i64) -> Result<Vec<MasterDataTaskStatusTransition>, CustomError>;
+}
+
+// การจัดการ master data ของ Admin (แยกจาก MasterDataRepositories ที่ use case อื่นใช้อ่านอย่างเดียว)
+#[automock]
+#[async_trait]
+pub trait MasterDataAdminRepositories: Send + Sync {
+    // รวมรายการที่ปิดใช้งาน เรียงตาม seq
+    async fn list_master_data(&self, kind: MasterDataKind) -> Result<Vec<MasterDataItem>, CustomError>;
+    async fn get_master_data(&self, kind: MasterDataKind, id: i64) -> Result<MasterDataItem, CustomError>;
+    async fn master_data_code_exists(&self, kind: MasterDataKind, code: String) -> Result<bool, CustomError>;
+    // ต่อท้ายลำดับสุดท้าย
+    async fn create_master_data(&self, kind: MasterDataKind, item: MasterDataCreateEntity) -> Result<i64, CustomError>;
+    async fn update_master_data(&self, kind: MasterDataKind, item: UpdateMasterData) -> Result<(), CustomError>;
+    async fn activate_master_data(&self, kind: MasterDataKind, id: i64, updated_by: i64) -> Result<(), CustomError>;
+    // ย้ายข้อมูลที่อ้างอิงไป replacement (ถ้ามี) แล้วปิดใช้งาน ถ้ายังมีข้อมูลอ้างอิงอยู่คืน DataConflict
+    async fn deactivate_master_data(&self, kind: MasterDataKind, deactivate: MasterDataDeactivate) -> Result<(), CustomError>;
+    // ids คือทุกรายการของตารางเรียงตามลำดับใหม่
+    async fn reorder_master_data(&self, kind: MasterDataKind, ids: Vec<i64>, updated_by: i64) -> Result<(), CustomError>;
 }
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use mockall::automock;
+use crate::domain::entities::auth::Role;
+use crate::domain::entities::project::{Project, ProjectCreateEntity, ProjectFilter, ProjectMember, ProjectMemberSet, UpdateProject};
+use crate::shared::exceptions::custom_error::CustomError;
+
+#[automock]
+#[async_trait]
+pub trait ProjectRepositories: Send + Sync {
+    async fn list_project(&self, filter: ProjectFilter) -> Result<Vec<Project>, CustomError>;
+    async fn get_project(&self, id: i64) -> Result<Project, CustomError>;
+    async fn create_project(&self, project: ProjectCreateEntity) -> Result<i64, CustomError>;
+    async fn update_project(&self, project: UpdateProject) -> Result<(), CustomError>;
+    // archived_by = None คือนำออกจากคลัง
+    async fn set_project_archived(&self, id: i64, archived_by: Option<i64>) -> Result<(), CustomError>;
+    async fn delete_project(&self, id: i64) -> Result<(), CustomError>;
+    // นับรวม task ที่อยู่ในถังขยะด้วย
+    async fn count_task(&self, project_id: i64) -> Result<i64, CustomError>;
+    // role ของผู้ใช้งานใน project (None = ไม่ได้เป็นสมาชิก)
+    async fn member_role(&self, project_id: i64, user_id: i64) -> Result<Option<Role>, CustomError>;
+    async fn list_member(&self, project_id: i64) -> Result<Vec<ProjectMember>, CustomError>;
+    async fn set_member(&self, member: ProjectMemberSet) -> Result<(), CustomError>;
+    // คืนค่า false เมื่อ user ไม่ได้เป็นสมาชิกอยู่แล้ว
+    async fn remove_member(&self, project_id: i64, user_id: i64) -> Result<bool, CustomError>;
+    async fn is_active_user(&self, user_id: i64) -> Result<bool, CustomError>;
+}
@@ -1,19 +1,56 @@
 
 use async_trait::async_trait;
 
+use chrono::NaiveDateTime;
 use mockall::automock;
-use crate::domain::entities::task::{Task, TaskCreateEntity, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
+use crate::domain::entities::task::{PatchTask, Task, TaskAssign, TaskBulkItem, TaskBulkItemResult, TaskBulkMode, TaskDependency, TaskHistoryFilter, TaskHistoryPage, TaskCreateEntity, TaskExpand, TaskFilter, TaskLabelAdd, TaskPage, TaskPurgeResult, TaskSearchPage, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
 use crate::shared::exceptions::custom_error::CustomError;
 
 #[automock]
 #[async_trait]
 pub trait TaskRepositories: Send + Sync {
-    async fn list_task(&self) -> Result<Vec<Task>, CustomError>;
+    async fn list_task(&self, filter: TaskFilter) -> Result<TaskPage, CustomError>;
+    // full-text search ด้วย filter.search ตามเงื่อนไขอื่นเดียวกับ list_task (ไม่ใช้ sort_by)
+    async fn search_task(&self, filter: TaskFilter) -> Result<TaskSearchPage, CustomError>;
     async fn get_task(&self, id: i64) -> Result<Task, CustomError>;
+    // เหมือน get_task พร้อม join ข้อมูลตาม expand
+    async fn get_task_expanded(&self, id: i64, expand: TaskExpand) -> Result<Task, CustomError>;
     async fn create_task(&self, task: TaskCreateEntity) -> Result<i64, CustomError>;
+    // สร้างหลาย task ใน transaction เดียว (ใช้กับ import) คืน id ตามลำดับที่ส่งมา
+    async fn create_tasks(&self, tasks: Vec<TaskCreateEntity>) -> Result<Vec<i64>, CustomError>;
     async fn update_task(&self, task: UpdateTask) -> Result<(), CustomError>;
+    async fn patch_task(&self, task: PatchTask) -> Result<(), CustomError>;
     async fn update_task_status(&self, task: UpdateTaskStatus) -> Result<(), CustomError>;
     async fn update_task_priority_levels(&self, task: UpdateTaskPriorityLevels) -> Result<(), CustomError>;
-    async fn delete_task(&self, id: i64) -> Result<(), CustomError>;
-    async fn task_exists(&self, id: i64) -> Result<bool, CustomError>;
+    // subtask ทุกชั้นถูกย้ายไปถังขยะพร้อมกัน
+    async fn delete_task(&self, id: i64, deleted_by: i64, version: Option<i32>) -> Result<(), CustomError>;
+    async fn assign_task(&self, assign: TaskAssign) -> Result<(), CustomError>;
+    // ทำทุกรายการใน transaction เดียว atomic ล้มทั้งหมดเมื่อรายการใดผิดพลาด ส่วน bestEffort ใช้ savepoint ต่อรายการ
+    async fn bulk_task(&self, items: Vec<TaskBulkItem>, mode: TaskBulkMode, actor_id: i64) -> Result<Vec<TaskBulkItemResult>, CustomError>;
+    // คืนค่า false เมื่อ user ไม่ได้ถูก assign อยู่แล้ว
+    async fn unassign_task(&self, task_id: i64, user_id: i64, unassigned_by: i64) -> Result<bool, CustomError>;
+    async fn add_labels(&self, labels: TaskLabelAdd) -> Result<(), CustomError>;
+    // คืนค่า false เมื่อ task ไม่มี label นี้อยู่แล้ว
+    async fn remove_label(&self, task_id: i64, label_id: i64, removed_by: i64) -> Result<bool, CustomError>;
+    // label ที่มีอยู่ในระบบ
+    async fn existing_label_ids(&self, label_ids: Vec<i64>) -> Result<Vec<i64>, CustomError>;
+    async fn add_dependency(&self, dependency: TaskDependency) -> Result<(), CustomError>;
+    // คืนค่า false เมื่อไม่มี dependency นี้อยู่แล้ว
+    async fn remove_dependency(&self, task_id: i64, blocked_by_task_id: i64, removed_by: i64) -> Result<bool, CustomError>;
+    // task ที่ block task นี้ทั้งทางตรงและทางอ้อม
+    async fn transitive_blocker_ids(&self, id: i64) -> Result<Vec<i64>, CustomError>;
+    // task ที่ block task นี้และยังไม่เสร็จ (ไม่รวมที่อยู่ในถังขยะ)
+    async fn open_blocker_ids(&self, id: i64) -> Result<Vec<i64>, CustomError>;
+    // user ที่ยังเปิดใช้งานและเป็นสมาชิกของ project
+    async fn active_user_ids(&self, user_ids: Vec<i64>, project_id: i64) -> Result<Vec<i64>, CustomError>;
+    // id ของ task นี้และ task แม่ทุกชั้นขึ้นไปจนถึงระดับบนสุด
+    async fn task_ancestor_ids(&self, id: i64) -> Result<Vec<i64>, CustomError>;
+    // จำนวนชั้นของ subtask ที่อยู่ใต้ task นี้ (0 = ไม่มี subtask)
+    async fn subtask_depth(&self, id: i64) -> Result<i32, CustomError>;
+    async fn get_deleted_task(&self, id: i64) -> Result<Task, CustomError>;
+    // กู้คืน subtask ที่ถูกลบไปพร้อมกับ task นี้ด้วย
+    async fn restore_task(&self, id: i64, restored_by: i64) -> Result<(), CustomError>;
+    // ลบถาวร task ที่อยู่ในถังขยะก่อนเวลาที่กำหนด คืนค่าจำนวนที่ลบและ key ของไฟล์แนบที่ต้องลบต่อ
+    async fn purge_deleted_task(&self, deleted_before: NaiveDateTime) -> Result<TaskPurgeResult, CustomError>;
+    async fn list_task_history(&self, filter: TaskHistoryFilter) -> Result<TaskHistoryPage, CustomError>;
 }
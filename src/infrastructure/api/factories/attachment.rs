@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use actix_web::web;
+use deadpool_postgres::Pool;
+use crate::application::use_cases::attachment::AttachmentUseCaseImpl;
+use crate::domain::repositories::file_storage::FileStorage;
+use crate::infrastructure::api::handlers::attachment::AttachmentHandler;
+use crate::infrastructure::config::ServerConfig;
+use crate::infrastructure::database::attachment::AttachmentRepositoriesImpl;
+use crate::infrastructure::database::project::ProjectRepositoriesImpl;
+use crate::infrastructure::database::task::TaskRepositoriesImpl;
+use crate::infrastructure::storage::local::LocalFileStorage;
+use crate::infrastructure::storage::s3::S3FileStorage;
+use crate::shared::utils::snowflake::SnowflakeImpl;
+
+pub type AttachmentUseCaseType =
+    AttachmentUseCaseImpl<AttachmentRepositoriesImpl<SnowflakeImpl>, TaskRepositoriesImpl<SnowflakeImpl>, ProjectRepositoriesImpl<SnowflakeImpl>>;
+
+// ฟังก์ชันสำหรับสร้าง Attachment Handler
+pub fn create_attachment_handler_data(
+    pool: Arc<Pool>,
+    snowflake_node: SnowflakeImpl,
+    task_snowflake_node: SnowflakeImpl,
+    project_snowflake_node: SnowflakeImpl,
+    config: &ServerConfig,
+) -> Result<web::Data<AttachmentHandler<AttachmentUseCaseType>>, std::io::Error> {
+    let attachment_repository = AttachmentRepositoriesImpl::new(Arc::clone(&pool), snowflake_node);
+    let task_repository = TaskRepositoriesImpl::new(Arc::clone(&pool), task_snowflake_node);
+    let project_repository = ProjectRepositoriesImpl::new(pool, project_snowflake_node);
+    let attachment_use_case = AttachmentUseCaseImpl::new(
+        attachment_repository,
+        task_repository,
+        project_repository,
+        create_file_storage(config)?,
+        config.attachment_max_size_bytes,
+        config.attachment_allowed_content_types.clone(),
+    );
+    let attachment_handler = AttachmentHandler::new(attachment_use_case);
+    Ok(web::Data::new(attachment_handler))
+}
+
+// เลือกที่เก็บไฟล์ตาม ATTACHMENT_STORAGE
+pub fn create_file_storage(config: &ServerConfig) -> Result<Arc<dyn FileStorage>, std::io::Error> {
+    match config.attachment_storage.as_str() {
+        "local" => Ok(Arc::new(LocalFileStorage::new(config.attachment_local_dir.clone()))),
+        "s3" => {
+            let required = |value: &Option<String>, name: &str| {
+                value.clone().ok_or_else(|| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{} must be set when ATTACHMENT_STORAGE=s3", name))
+                })
+            };
+
+            Ok(Arc::new(S3FileStorage::new(
+                config.s3_endpoint.clone(),
+                config.s3_region.clone(),
+                required(&config.s3_bucket, "S3_BUCKET")?,
+                required(&config.s3_access_key, "S3_ACCESS_KEY")?,
+                required(&config.s3_secret_key, "S3_SECRET_KEY")?,
+            )))
+        }
+        storage => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Invalid ATTACHMENT_STORAGE: {} (expected local or s3)", storage),
+        )),
+    }
+}
@@ -4,15 +4,34 @@ use deadpool_postgres::Pool;
 use crate::application::use_cases::auth::AuthUseCaseImpl;
 use crate::infrastructure::api::handlers::auth::AuthHandler;
 use crate::infrastructure::config::ServerConfig;
-use crate::infrastructure::database::auth::AuthRepositoriesImpl;
+use crate::infrastructure::database::auth::{AuthRepositoriesImpl, TokenDenylistRepositoriesImpl};
+use crate::infrastructure::database::master_data::MasterDataRepositoriesImpl;
+use crate::infrastructure::database::master_data_cache::{CachedMasterDataRepositories, MasterDataCache};
+use crate::shared::middleware::auth::JwtMiddleware;
+use crate::shared::utils::snowflake::SnowflakeImpl;
 
 // ฟังก์ชันสำหรับสร้าง Auth Handler
 pub fn create_user_handler_data(
     pool: Arc<Pool>,
+    snowflake_node: SnowflakeImpl,
+    master_data_cache: Arc<MasterDataCache>,
     config: &ServerConfig,
-) -> web::Data<AuthHandler<AuthUseCaseImpl<AuthRepositoriesImpl>>> {
-    let user_repository = AuthRepositoriesImpl::new(pool);
-    let user_use_case = AuthUseCaseImpl::new(user_repository, config.jwt_secret.clone()); // UseCase logic
+) -> web::Data<AuthHandler<AuthUseCaseImpl<AuthRepositoriesImpl<SnowflakeImpl>, CachedMasterDataRepositories<MasterDataRepositoriesImpl>>>> {
+    let user_repository = AuthRepositoriesImpl::new(Arc::clone(&pool), snowflake_node);
+    let master_data_repository = CachedMasterDataRepositories::new(MasterDataRepositoriesImpl::new(pool), master_data_cache);
+    let user_use_case = AuthUseCaseImpl::new(
+        user_repository,
+        master_data_repository,
+        config.jwt_secret.clone(),
+        config.jwt_refresh_expire_seconds,
+        config.allow_registration,
+    ); // UseCase logic
     let user_handler = AuthHandler::new(user_use_case);
     web::Data::new(user_handler)
 }
+
+// ฟังก์ชันสำหรับสร้าง JWT Middleware ที่ตรวจ denylist จาก database
+pub fn create_jwt_middleware(pool: Arc<Pool>, config: &ServerConfig) -> JwtMiddleware {
+    let denylist = Arc::new(TokenDenylistRepositoriesImpl::new(pool));
+    JwtMiddleware::new(config.jwt_secret.clone(), denylist)
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use actix_web::web;
+use deadpool_postgres::Pool;
+use crate::application::use_cases::comment::CommentUseCaseImpl;
+use crate::infrastructure::api::handlers::comment::CommentHandler;
+use crate::infrastructure::database::comment::CommentRepositoriesImpl;
+use crate::infrastructure::database::project::ProjectRepositoriesImpl;
+use crate::infrastructure::database::task::TaskRepositoriesImpl;
+use crate::shared::utils::snowflake::SnowflakeImpl;
+
+pub type CommentUseCaseType =
+    CommentUseCaseImpl<CommentRepositoriesImpl<SnowflakeImpl>, TaskRepositoriesImpl<SnowflakeImpl>, ProjectRepositoriesImpl<SnowflakeImpl>>;
+
+// ฟังก์ชันสำหรับสร้าง Comment Handler
+pub fn create_comment_handler_data(
+    pool: Arc<Pool>,
+    snowflake_node: SnowflakeImpl,
+    task_snowflake_node: SnowflakeImpl,
+    project_snowflake_node: SnowflakeImpl,
+) -> web::Data<CommentHandler<CommentUseCaseType>> {
+    let comment_repository = CommentRepositoriesImpl::new(Arc::clone(&pool), snowflake_node);
+    let task_repository = TaskRepositoriesImpl::new(Arc::clone(&pool), task_snowflake_node);
+    let project_repository = ProjectRepositoriesImpl::new(pool, project_snowflake_node);
+    let comment_use_case = CommentUseCaseImpl::new(comment_repository, task_repository, project_repository);
+    let comment_handler = CommentHandler::new(comment_use_case);
+    web::Data::new(comment_handler)
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+use actix_web::web;
+use deadpool_postgres::Pool;
+use crate::application::use_cases::label::LabelUseCaseImpl;
+use crate::infrastructure::api::handlers::label::LabelHandler;
+use crate::infrastructure::database::label::LabelRepositoriesImpl;
+use crate::shared::utils::snowflake::SnowflakeImpl;
+
+pub type LabelUseCaseType = LabelUseCaseImpl<LabelRepositoriesImpl<SnowflakeImpl>>;
+
+// ฟังก์ชันสำหรับสร้าง Label Handler
+pub fn create_label_handler_data(pool: Arc<Pool>, snowflake_node: SnowflakeImpl) -> web::Data<LabelHandler<LabelUseCaseType>> {
+    let label_repository = LabelRepositoriesImpl::new(pool, snowflake_node);
+    let label_use_case = LabelUseCaseImpl::new(label_repository);
+    let label_handler = LabelHandler::new(label_use_case);
+    web::Data::new(label_handler)
+}
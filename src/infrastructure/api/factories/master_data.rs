@@ -3,13 +3,22 @@ use actix_web::web;
 use deadpool_postgres::Pool;
 use crate::application::use_cases::master_data::MasterDataUseCaseImpl;
 use crate::infrastructure::api::handlers::master_data_handler::MasterDataHandler;
-use crate::infrastructure::database::master_data::MasterDataRepositoriesImpl;
+use crate::infrastructure::database::master_data::{MasterDataAdminRepositoriesImpl, MasterDataRepositoriesImpl};
+use crate::infrastructure::database::master_data_cache::{CachedMasterDataRepositories, MasterDataCache};
+use crate::shared::utils::snowflake::SnowflakeImpl;
+
+pub type MasterDataUseCaseType =
+    MasterDataUseCaseImpl<CachedMasterDataRepositories<MasterDataRepositoriesImpl>, MasterDataAdminRepositoriesImpl<SnowflakeImpl>>;
 
 pub fn create_master_data_handler_data(
     pool: Arc<Pool>,
-) -> web::Data<MasterDataHandler<MasterDataUseCaseImpl<MasterDataRepositoriesImpl>>> {
-    let master_data_repository = MasterDataRepositoriesImpl::new(pool);
-    let master_data_use_case = MasterDataUseCaseImpl::new(master_data_repository);
-    let master_data_handler = MasterDataHandler::new(master_data_use_case);
+    snowflake_node: SnowflakeImpl,
+    master_data_cache: Arc<MasterDataCache>,
+    cache_max_age_seconds: u64,
+) -> web::Data<MasterDataHandler<MasterDataUseCaseType>> {
+    let master_data_repository = CachedMasterDataRepositories::new(MasterDataRepositoriesImpl::new(Arc::clone(&pool)), Arc::clone(&master_data_cache));
+    let master_data_admin_repository = MasterDataAdminRepositoriesImpl::new(pool, snowflake_node, master_data_cache);
+    let master_data_use_case = MasterDataUseCaseImpl::new(master_data_repository, master_data_admin_repository);
+    let master_data_handler = MasterDataHandler::new(master_data_use_case, cache_max_age_seconds);
     web::Data::new(master_data_handler)
 }
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+use actix_web::web;
+use deadpool_postgres::Pool;
+use crate::application::use_cases::project::ProjectUseCaseImpl;
+use crate::infrastructure::api::handlers::project::ProjectHandler;
+use crate::infrastructure::database::master_data::MasterDataRepositoriesImpl;
+use crate::infrastructure::database::master_data_cache::{CachedMasterDataRepositories, MasterDataCache};
+use crate::infrastructure::database::project::ProjectRepositoriesImpl;
+use crate::shared::utils::snowflake::SnowflakeImpl;
+
+// ฟังก์ชันสำหรับสร้าง Project Handler
+pub fn create_project_handler_data(
+    pool: Arc<Pool>,
+    snowflake_node: SnowflakeImpl,
+    master_data_cache: Arc<MasterDataCache>,
+) -> web::Data<ProjectHandler<ProjectUseCaseImpl<ProjectRepositoriesImpl<SnowflakeImpl>, CachedMasterDataRepositories<MasterDataRepositoriesImpl>>>> {
+    let project_repository = ProjectRepositoriesImpl::new(Arc::clone(&pool), snowflake_node);
+    let master_data_repository = CachedMasterDataRepositories::new(MasterDataRepositoriesImpl::new(pool), master_data_cache);
+    let project_use_case = ProjectUseCaseImpl::new(project_repository, master_data_repository);
+    let project_handler = ProjectHandler::new(project_use_case);
+    web::Data::new(project_handler)
+}
@@ -3,16 +3,26 @@ use actix_web::web;
 use deadpool_postgres::Pool;
 use crate::application::use_cases::task::TaskUseCaseImpl;
 use crate::infrastructure::api::handlers::task::TaskHandler;
+use crate::infrastructure::database::master_data::MasterDataRepositoriesImpl;
+use crate::infrastructure::database::master_data_cache::{CachedMasterDataRepositories, MasterDataCache};
+use crate::infrastructure::database::project::ProjectRepositoriesImpl;
 use crate::infrastructure::database::task::TaskRepositoriesImpl;
 use crate::shared::utils::snowflake::SnowflakeImpl;
 
+pub type TaskUseCaseType =
+    TaskUseCaseImpl<TaskRepositoriesImpl<SnowflakeImpl>, CachedMasterDataRepositories<MasterDataRepositoriesImpl>, ProjectRepositoriesImpl<SnowflakeImpl>>;
+
 // ฟังก์ชันสำหรับสร้าง Task Handler
 pub fn create_task_handler_data(
     pool: Arc<Pool>,
     snowflake_node: SnowflakeImpl,
-) -> web::Data<TaskHandler<TaskUseCaseImpl<TaskRepositoriesImpl<SnowflakeImpl>>>> {
-    let task_repository = TaskRepositoriesImpl::new(pool, snowflake_node);
-    let task_use_case = TaskUseCaseImpl::new(task_repository);
+    project_snowflake_node: SnowflakeImpl,
+    master_data_cache: Arc<MasterDataCache>,
+) -> web::Data<TaskHandler<TaskUseCaseType>> {
+    let task_repository = TaskRepositoriesImpl::new(Arc::clone(&pool), snowflake_node);
+    let master_data_repository = CachedMasterDataRepositories::new(MasterDataRepositoriesImpl::new(Arc::clone(&pool)), master_data_cache);
+    let project_repository = ProjectRepositoriesImpl::new(pool, project_snowflake_node);
+    let task_use_case = TaskUseCaseImpl::new(task_repository, master_data_repository, project_repository);
     let task_handler = TaskHandler::new(task_use_case);
     web::Data::new(task_handler)
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+use actix_multipart::{Multipart, MultipartError};
+use actix_web::{http::header::ContentDisposition, web, HttpRequest, HttpResponse, Responder};
+use futures_util::TryStreamExt;
+use crate::application::interfaces::attachment::AttachmentUseCase;
+use crate::domain::entities::attachment::AttachmentUpload;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{ATTACHMENT_FILE_REQUIRED, ATTACHMENT_TOO_LARGE};
+use crate::shared::middleware::jwt::extract_auth_user;
+use crate::shared::middleware::response::response_success;
+
+// ชื่อ field ของไฟล์ใน multipart/form-data
+const FILE_FIELD: &str = "file";
+
+pub struct AttachmentHandler<T: AttachmentUseCase + Send + Sync> {
+    use_case: T,
+}
+
+impl<T: AttachmentUseCase + Send + Sync> AttachmentHandler<T> {
+    pub fn new(use_case: T) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn list_attachment(
+        handler: web::Data<AttachmentHandler<T>>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.list_attachment(path.into_inner(), &auth_user).await {
+            Ok(attachments) => Ok(HttpResponse::Ok().json(response_success("get attachments successfully", attachments))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn upload_attachment(
+        handler: web::Data<AttachmentHandler<T>>,
+        payload: Multipart,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let task_id = path.into_inner();
+        handler.use_case.authorize_upload(task_id, &auth_user).await?;
+
+        let (file_name, content_type, data) = read_file_field(payload, handler.use_case.max_size_bytes())
+            .await?
+            .ok_or(CustomError::ValidationError(ATTACHMENT_FILE_REQUIRED.to_string()))?;
+        let upload = AttachmentUpload {
+            task_id,
+            file_name,
+            content_type,
+            data,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.upload_attachment(upload, &auth_user).await {
+            Ok(attachment_id) => Ok(HttpResponse::Created().json(response_success("Attachment uploaded successfully", attachment_id))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn download_attachment(
+        handler: web::Data<AttachmentHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (task_id, attachment_id) = path.into_inner();
+
+        match handler.use_case.download_attachment(task_id, attachment_id, &auth_user).await {
+            Ok(file) => Ok(HttpResponse::Ok()
+                .content_type(file.attachment.content_type)
+                .insert_header(ContentDisposition::attachment(file.attachment.file_name))
+                .body(file.data)),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn delete_attachment(
+        handler: web::Data<AttachmentHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (task_id, attachment_id) = path.into_inner();
+
+        match handler.use_case.delete_attachment(task_id, attachment_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Attachment deleted successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+// อ่าน field "file" (ชื่อไฟล์, content type, ข้อมูล) หยุดทันทีเมื่อขนาดเกิน max_size_bytes field อื่นอ่านทิ้ง
+async fn read_file_field(mut payload: Multipart, max_size_bytes: usize) -> Result<Option<(String, String, Vec<u8>)>, CustomError> {
+    let mut file = None;
+
+    while let Some(mut field) = payload.try_next().await.map_err(multipart_error)? {
+        if file.is_some() || field.name() != Some(FILE_FIELD) {
+            while field.try_next().await.map_err(multipart_error)?.is_some() {}
+            continue;
+        }
+
+        let file_name = field
+            .content_disposition()
+            .and_then(|content_disposition| content_disposition.get_filename())
+            .unwrap_or_default()
+            .to_string();
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.essence_str().to_string())
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        let mut data = Vec::new();
+        while let Some(chunk) = field.try_next().await.map_err(multipart_error)? {
+            if data.len() + chunk.len() > max_size_bytes {
+                return Err(CustomError::PayloadTooLarge(format!("{}: {} bytes", ATTACHMENT_TOO_LARGE, max_size_bytes)));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        file = Some((file_name, content_type, data));
+    }
+
+    Ok(file)
+}
+
+fn multipart_error(e: MultipartError) -> CustomError {
+    CustomError::ValidationError(e.to_string())
+}
@@ -1,9 +1,14 @@
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use chrono::DateTime;
 use validator::Validate;
 use crate::application::interfaces::auth::AuthUseCase;
-use crate::domain::entities::auth::Login;
-use crate::infrastructure::api::requests::auth::LoginRequest;
+use crate::domain::entities::auth::{ChangePassword, Login, Logout, Register, ResetPassword, UpdateUser, UserCreateEntity};
+use crate::infrastructure::api::requests::auth::{
+    ChangePasswordRequest, CreateUserRequest, LoginRequest, LogoutRequest, RefreshTokenRequest, RegisterRequest,
+    ResetPasswordRequest, UpdateUserRequest,
+};
 use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::middleware::jwt::{extract_auth_user, extract_claims};
 use crate::shared::middleware::response::response_success;
 
 pub struct AuthHandler<T: AuthUseCase + Send + Sync> {
@@ -31,4 +36,198 @@ impl<T: AuthUseCase + Send + Sync> AuthHandler<T> {
             Err(e) => Err(e)
         }
     }
+
+    pub(crate) async fn refresh(
+        handler: web::Data<AuthHandler<T>>,
+        body: web::Json<RefreshTokenRequest>,
+    ) -> Result<impl Responder, CustomError> {
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        match handler.use_case.refresh(body.into_inner().refresh_token).await {
+            Ok(token) => Ok(HttpResponse::Ok().json(response_success("refresh token successfully", token))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn logout(
+        handler: web::Data<AuthHandler<T>>,
+        body: Option<web::Json<LogoutRequest>>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        // claims ของ access token ที่ใช้เรียก endpoint นี้ (ถอดมาจาก middleware)
+        let claims = extract_claims(&req).await?;
+        let expires_at = DateTime::from_timestamp(claims.exp as i64, 0)
+            .ok_or(CustomError::Unauthorized("Invalid JWT Token".to_string()))?
+            .naive_utc();
+
+        let payload = Logout {
+            user_id: claims.sub,
+            jti: claims.jti,
+            expires_at,
+            refresh_token: body.and_then(|body| body.into_inner().refresh_token),
+        };
+
+        match handler.use_case.logout(payload).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("logout successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn register(
+        handler: web::Data<AuthHandler<T>>,
+        body: web::Json<RegisterRequest>,
+    ) -> Result<impl Responder, CustomError> {
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let payload = Register {
+            username: body.username,
+            password: body.password,
+        };
+
+        match handler.use_case.register(payload).await {
+            Ok(user_id) => Ok(HttpResponse::Created().json(response_success("register successfully", user_id))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn me(
+        handler: web::Data<AuthHandler<T>>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.me(&auth_user).await {
+            Ok(user) => Ok(HttpResponse::Ok().json(response_success("get profile successfully", user))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn change_password(
+        handler: web::Data<AuthHandler<T>>,
+        body: web::Json<ChangePasswordRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let payload = ChangePassword {
+            user_id: auth_user.id,
+            current_password: body.current_password,
+            new_password: body.new_password,
+        };
+
+        match handler.use_case.change_password(payload).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("password changed successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn list_users(
+        handler: web::Data<AuthHandler<T>>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.list_users(&auth_user).await {
+            Ok(users) => Ok(HttpResponse::Ok().json(response_success("get users successfully", users))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn get_user(
+        handler: web::Data<AuthHandler<T>>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.get_user(path.into_inner(), &auth_user).await {
+            Ok(user) => Ok(HttpResponse::Ok().json(response_success("get user successfully", user))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn create_user(
+        handler: web::Data<AuthHandler<T>>,
+        body: web::Json<CreateUserRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let payload = UserCreateEntity {
+            username: body.username,
+            password: body.password,
+            role_id: body.role_id,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.create_user(payload, &auth_user).await {
+            Ok(user_id) => Ok(HttpResponse::Created().json(response_success("User created successfully", user_id))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn update_user(
+        handler: web::Data<AuthHandler<T>>,
+        body: web::Json<UpdateUserRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let payload = UpdateUser {
+            id: path.into_inner(),
+            role_id: body.role_id,
+            active: body.active,
+            updated_by: auth_user.id,
+        };
+
+        match handler.use_case.update_user(payload, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("User updated successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn deactivate_user(
+        handler: web::Data<AuthHandler<T>>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.deactivate_user(path.into_inner(), &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("User deactivated successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn reset_password(
+        handler: web::Data<AuthHandler<T>>,
+        body: web::Json<ResetPasswordRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let payload = ResetPassword {
+            id: path.into_inner(),
+            new_password: body.into_inner().new_password,
+            updated_by: auth_user.id,
+        };
+
+        match handler.use_case.reset_password(payload, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("password reset successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
 }
@@ -0,0 +1,105 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+use crate::application::interfaces::comment::CommentUseCase;
+use crate::domain::entities::comment::{CommentCreateEntity, CommentFilter, UpdateComment};
+use crate::domain::entities::task::{TaskCursor, DEFAULT_PAGE_SIZE};
+use crate::infrastructure::api::requests::comment::{CommentRequest, ListCommentQuery};
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::middleware::jwt::extract_auth_user;
+use crate::shared::middleware::response::{response_success, response_success_with_meta, PageMeta};
+
+pub struct CommentHandler<T: CommentUseCase + Send + Sync> {
+    use_case: T,
+}
+
+impl<T: CommentUseCase + Send + Sync> CommentHandler<T> {
+    pub fn new(use_case: T) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn list_comment(
+        handler: web::Data<CommentHandler<T>>,
+        query: web::Query<ListCommentQuery>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let filter = CommentFilter {
+            task_id: path.into_inner(),
+            cursor: query.cursor.as_deref().map(TaskCursor::decode).transpose()?,
+            limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        };
+
+        match handler.use_case.list_comment(filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("get comments successfully", page.items, meta)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn create_comment(
+        handler: web::Data<CommentHandler<T>>,
+        body: web::Json<CommentRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let comment = CommentCreateEntity {
+            task_id: path.into_inner(),
+            body: body.into_inner().body,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.create_comment(comment, &auth_user).await {
+            Ok(comment_id) => Ok(HttpResponse::Created().json(response_success("Comment created successfully", comment_id))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn update_comment(
+        handler: web::Data<CommentHandler<T>>,
+        body: web::Json<CommentRequest>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let (task_id, comment_id) = path.into_inner();
+        let comment = UpdateComment {
+            id: comment_id,
+            body: body.into_inner().body,
+        };
+
+        match handler.use_case.update_comment(task_id, comment, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Comment updated successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn delete_comment(
+        handler: web::Data<CommentHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (task_id, comment_id) = path.into_inner();
+
+        match handler.use_case.delete_comment(task_id, comment_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Comment deleted successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+use crate::application::interfaces::label::LabelUseCase;
+use crate::domain::entities::label::{LabelCreateEntity, UpdateLabel};
+use crate::infrastructure::api::requests::label::LabelRequest;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::middleware::jwt::extract_auth_user;
+use crate::shared::middleware::response::response_success;
+
+pub struct LabelHandler<T: LabelUseCase + Send + Sync> {
+    use_case: T,
+}
+
+impl<T: LabelUseCase + Send + Sync> LabelHandler<T> {
+    pub fn new(use_case: T) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn list_label(handler: web::Data<LabelHandler<T>>) -> Result<impl Responder, CustomError> {
+        match handler.use_case.list_label().await {
+            Ok(labels) => Ok(HttpResponse::Ok().json(response_success("get labels successfully", labels))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_label(handler: web::Data<LabelHandler<T>>, path: web::Path<i64>) -> Result<impl Responder, CustomError> {
+        match handler.use_case.get_label(path.into_inner()).await {
+            Ok(label) => Ok(HttpResponse::Ok().json(response_success("get label successfully", label))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn create_label(
+        handler: web::Data<LabelHandler<T>>,
+        body: web::Json<LabelRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let label = LabelCreateEntity {
+            name: body.name,
+            color: body.color,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.create_label(label, &auth_user).await {
+            Ok(label_id) => Ok(HttpResponse::Created().json(response_success("Label created successfully", label_id))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn update_label(
+        handler: web::Data<LabelHandler<T>>,
+        body: web::Json<LabelRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let label = UpdateLabel {
+            id: path.into_inner(),
+            name: body.name,
+            color: body.color,
+            updated_by: auth_user.id,
+        };
+
+        match handler.use_case.update_label(label, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Label updated successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn delete_label(handler: web::Data<LabelHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.delete_label(path.into_inner(), &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Label deleted successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+}
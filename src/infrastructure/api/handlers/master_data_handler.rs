@@ -1,43 +1,208 @@
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::http::header::{self, ContentType};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use validator::Validate;
 use crate::application::interfaces::master_data::MasterDataUseCase;
+use crate::domain::entities::master_data::{MasterDataCreateEntity, MasterDataDeactivate, MasterDataKind, UpdateMasterData};
+use crate::infrastructure::api::requests::master_data::{
+    DeactivateMasterDataRequest, MasterDataRequest, ReorderMasterDataRequest, UpdateMasterDataRequest,
+};
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::shared::middleware::response::response_success;
+use crate::shared::middleware::jwt::extract_auth_user;
+use crate::shared::middleware::response::{response_success, ApiResponse};
 
 pub struct MasterDataHandler<T: MasterDataUseCase + Send + Sync> {
     use_case: T,
+    // max-age ของ Cache-Control สำหรับรายการที่ไม่ต้อง login
+    cache_max_age_seconds: u64,
 }
 
 impl<T: MasterDataUseCase + Send + Sync> MasterDataHandler<T> {
-    pub fn new(use_case: T) -> Self {
-        Self { use_case }
+    pub fn new(use_case: T, cache_max_age_seconds: u64) -> Self {
+        Self { use_case, cache_max_age_seconds }
+    }
+
+    fn public_cache_control(&self) -> String {
+        format!("public, max-age={}", self.cache_max_age_seconds)
     }
 
     pub(crate) async fn list_task_status(
         handler: web::Data<MasterDataHandler<T>>,
+        req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
         // เรีนยก use case และ return
         match handler.use_case.list_task_status().await {
-            Ok(items) => Ok(HttpResponse::Ok().json(response_success("get list task status successfully", items))),
+            Ok(items) => cached_json(&req, handler.public_cache_control(), response_success("get list task status successfully", items)),
             Err(e) => Err(e)
         }
     }
 
     pub(crate) async fn list_role(
         handler: web::Data<MasterDataHandler<T>>,
+        req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
         match handler.use_case.list_role().await {
-            Ok(items) => Ok(HttpResponse::Ok().json(response_success("get list role successfully", items))),
+            Ok(items) => cached_json(&req, handler.public_cache_control(), response_success("get list role successfully", items)),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn list_task_status_transition(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        match handler.use_case.list_task_status_transition(path.into_inner()).await {
+            Ok(items) => cached_json(&req, handler.public_cache_control(), response_success("get list task status transition successfully", items)),
             Err(e) => Err(e)
         }
     }
 
     pub(crate) async fn list_priority_levels(
         handler: web::Data<MasterDataHandler<T>>,
+        req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
         match handler.use_case.list_priority_levels().await {
-            Ok(items) => Ok(HttpResponse::Ok().json(response_success("get list priority levels successfully", items))),
+            Ok(items) => cached_json(&req, handler.public_cache_control(), response_success("get list priority levels successfully", items)),
             Err(e) => Err(e)
         }
     }
+    pub(crate) async fn list_master_data(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<MasterDataKind>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.list_master_data(path.into_inner(), auth_user).await {
+            // มีรายการที่ปิดใช้งานและต้อง login จึงให้ client ตรวจกับ server ทุกครั้ง
+            Ok(items) => cached_json(&req, "private, no-cache".to_string(), response_success("get list master data successfully", items)),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn create_master_data(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<MasterDataKind>,
+        body: web::Json<MasterDataRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let item = MasterDataCreateEntity {
+            title: body.title,
+            code: body.code,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.create_master_data(path.into_inner(), item, auth_user).await {
+            Ok(id) => Ok(HttpResponse::Created().json(response_success("Master data created successfully", id))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn update_master_data(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<(MasterDataKind, i64)>,
+        body: web::Json<UpdateMasterDataRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let (kind, id) = path.into_inner();
+        let item = UpdateMasterData {
+            id,
+            title: body.into_inner().title,
+            updated_by: auth_user.id,
+        };
+
+        match handler.use_case.update_master_data(kind, item, auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Master data updated successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn activate_master_data(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<(MasterDataKind, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        let (kind, id) = path.into_inner();
+        match handler.use_case.activate_master_data(kind, id, auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Master data activated successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn deactivate_master_data(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<(MasterDataKind, i64)>,
+        body: Option<web::Json<DeactivateMasterDataRequest>>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        // body ไม่บังคับ ถ้าไม่ส่งมาจะปิดใช้งานได้เฉพาะค่าที่ไม่มีข้อมูลอ้างอิง
+        let (kind, id) = path.into_inner();
+        let deactivate = MasterDataDeactivate {
+            id,
+            replacement_id: body.and_then(|body| body.into_inner().replacement_id),
+            updated_by: auth_user.id,
+        };
+
+        match handler.use_case.deactivate_master_data(kind, deactivate, auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Master data deactivated successfully", ()))),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub(crate) async fn reorder_master_data(
+        handler: web::Data<MasterDataHandler<T>>,
+        path: web::Path<MasterDataKind>,
+        body: web::Json<ReorderMasterDataRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        match handler.use_case.reorder_master_data(path.into_inner(), body.into_inner().ids, auth_user).await {
+            Ok(items) => Ok(HttpResponse::Ok().json(response_success("Master data reordered successfully", items))),
+            Err(e) => Err(e)
+        }
+    }
+}
+
+// ตอบ JSON พร้อม ETag (hash ของ body) ถ้า If-None-Match ตรงกับ ETag ปัจจุบันตอบ 304 โดยไม่ส่ง body
+fn cached_json<D: Serialize>(req: &HttpRequest, cache_control: String, response: ApiResponse<D>) -> Result<HttpResponse, CustomError> {
+    let body = serde_json::to_vec(&response).map_err(|e| CustomError::InternalError(format!("Failed to serialize response: {}", e)))?;
+    let etag = format!("\"{}\"", Sha256::digest(&body).iter().take(16).map(|byte| format!("{:02x}", byte)).collect::<String>());
+
+    let not_modified = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').map(str::trim).any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag));
+
+    if not_modified {
+        return Ok(HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .insert_header((header::CACHE_CONTROL, cache_control))
+            .finish());
+    }
+
+    Ok(HttpResponse::Ok()
+        .insert_header((header::ETAG, etag))
+        .insert_header((header::CACHE_CONTROL, cache_control))
+        .content_type(ContentType::json())
+        .body(body))
 }
\ No newline at end of file
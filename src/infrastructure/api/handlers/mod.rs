@@ -1,4 +1,8 @@
 pub mod master_data_handler;
 pub mod task;
 pub mod auth;
-pub mod health_check;
\ No newline at end of file
+pub mod health_check;
+pub mod project;
+pub mod comment;
+pub mod attachment;
+pub mod label;
\ No newline at end of file
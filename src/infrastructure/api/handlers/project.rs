@@ -0,0 +1,165 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+use crate::application::interfaces::project::ProjectUseCase;
+use crate::domain::entities::project::{ProjectCreateEntity, ProjectFilter, ProjectMemberSet, UpdateProject};
+use crate::infrastructure::api::requests::project::{ListProjectQuery, ProjectRequest, SetProjectMemberRequest};
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::middleware::jwt::extract_auth_user;
+use crate::shared::middleware::response::response_success;
+
+pub struct ProjectHandler<T: ProjectUseCase + Send + Sync> {
+    use_case: T,
+}
+
+impl<T: ProjectUseCase + Send + Sync> ProjectHandler<T> {
+    pub fn new(use_case: T) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn list_project(
+        handler: web::Data<ProjectHandler<T>>,
+        query: web::Query<ListProjectQuery>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        let filter = ProjectFilter {
+            member_id: None,
+            archived: query.archived.unwrap_or(false),
+        };
+
+        match handler.use_case.list_project(filter, &auth_user).await {
+            Ok(projects) => Ok(HttpResponse::Ok().json(response_success("get projects successfully", projects))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_project(handler: web::Data<ProjectHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.get_project(path.into_inner(), &auth_user).await {
+            Ok(project) => Ok(HttpResponse::Ok().json(response_success("get project successfully", project))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn create_project(
+        handler: web::Data<ProjectHandler<T>>,
+        body: web::Json<ProjectRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let project = ProjectCreateEntity {
+            name: body.name,
+            description: body.description,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.create_project(project, &auth_user).await {
+            Ok(project_id) => Ok(HttpResponse::Created().json(response_success("Project created successfully", project_id))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn update_project(
+        handler: web::Data<ProjectHandler<T>>,
+        body: web::Json<ProjectRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let project = UpdateProject {
+            id: path.into_inner(),
+            name: body.name,
+            description: body.description,
+            updated_by: auth_user.id,
+        };
+
+        match handler.use_case.update_project(project, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Project updated successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn archive_project(handler: web::Data<ProjectHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.archive_project(path.into_inner(), &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Project archived successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn unarchive_project(handler: web::Data<ProjectHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.unarchive_project(path.into_inner(), &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Project unarchived successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn delete_project(handler: web::Data<ProjectHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.delete_project(path.into_inner(), &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Project deleted successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn list_member(handler: web::Data<ProjectHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        match handler.use_case.list_member(path.into_inner(), &auth_user).await {
+            Ok(members) => Ok(HttpResponse::Ok().json(response_success("get project members successfully", members))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn set_member(
+        handler: web::Data<ProjectHandler<T>>,
+        body: web::Json<SetProjectMemberRequest>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (project_id, user_id) = path.into_inner();
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let member = ProjectMemberSet {
+            project_id,
+            user_id,
+            role_id: body.role_id,
+            added_by: auth_user.id,
+        };
+
+        match handler.use_case.set_member(member, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Project member saved successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn remove_member(
+        handler: web::Data<ProjectHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (project_id, user_id) = path.into_inner();
+
+        match handler.use_case.remove_member(project_id, user_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Project member removed successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -1,17 +1,48 @@
 
-use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use actix_web::{http::header, http::header::ContentDisposition, web, HttpRequest, HttpResponse, Responder};
+use futures_util::{stream, StreamExt};
+use std::collections::HashMap;
+use std::str::FromStr;
 use validator::Validate;
 use crate::application::interfaces::task::TaskUseCase;
+use crate::domain::entities::auth::AuthUser;
+use crate::domain::entities::master_data::MasterDataRef;
 use crate::domain::entities::task::{
+    Task,
+    TaskAssign,
+    TaskBulk,
+    TaskBulkAction,
+    TaskBulkOperation,
     TaskCreateEntity,
+    TaskDependency,
+    TaskCursor,
+    TaskExpand,
+    TaskFilter,
+    TaskHistoryFilter,
+    TaskImport,
+    TaskImportRow,
+    TaskLabelAdd,
+    DEFAULT_PAGE_SIZE,
+    PatchTask as PatchTaskEntity,
     UpdateTask as UpdateTaskEntity,
     UpdateTaskStatus as UpdateTaskStatusEntity,
     UpdateTaskPriorityLevels as UpdateTaskPriorityLevelsEntity
 };
-use crate::infrastructure::api::requests::task::{TaskRequest, UpdateTaskPriorityLevelsRequest, UpdateTaskStatusRequest};
+use crate::infrastructure::api::requests::task::{AssignTaskRequest, BulkTaskOperationRequest, BulkTaskOperationType, BulkTaskRequest, ExportTaskQuery, GetTaskQuery, ImportTaskQuery, ImportTaskRow, ListTaskHistoryQuery, ListTaskQuery, PatchTaskRequest, TaskDependencyRequest, TaskFileFormat, TaskLabelRequest, TaskRequest, UpdateTaskPriorityLevelsRequest, UpdateTaskStatusRequest};
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::shared::middleware::jwt::extract_user_id;
-use crate::shared::middleware::response::response_success;
+use crate::shared::exceptions::error_message::{IF_MATCH_REQUIRED, INVALID_ASSIGNEE_FILTER, INVALID_IF_MATCH, INVALID_TASK_BULK_OPERATION, INVALID_TASK_IMPORT_FILE, TASK_IMPORT_MISSING_COLUMN};
+use crate::shared::middleware::jwt::extract_auth_user;
+use crate::shared::middleware::response::{response_success, response_success_with_meta, PageMeta};
+use crate::shared::utils::csv;
+
+// จำนวน task ต่อหน้าที่ดึงจาก database ระหว่าง export
+const EXPORT_PAGE_SIZE: i64 = 100;
+
+// column ของไฟล์ CSV (import อ่านเฉพาะ column ของ ImportTaskRow ที่เหลือข้าม)
+const TASK_CSV_COLUMNS: [&str; 18] = [
+    "id", "project_id", "parent_task_id", "title", "description", "task_status_id", "task_status", "priority_levels_id", "priority_levels",
+    "assignees", "labels", "start_date", "due_date", "overdue", "created_by", "created_at", "updated_at", "version",
+];
 
 pub struct TaskHandler<T: TaskUseCase + Send + Sync> {
     use_case: T,
@@ -22,17 +53,152 @@ impl<T: TaskUseCase + Send + Sync> TaskHandler<T> {
         Self { use_case }
     }
 
-    pub async fn list_task(handler: web::Data<TaskHandler<T>>) -> Result<impl Responder, CustomError> {
-        match handler.use_case.list_task().await {
-            Ok(tasks) => Ok(HttpResponse::Ok().json(response_success("get task successfully", tasks))),
+    pub async fn list_task(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskQuery>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let filter = task_filter(query.into_inner(), &auth_user)?;
+
+        match handler.use_case.list_task(filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("get task successfully", page.items, meta)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // GET /task/search?q= เรียงตามความเกี่ยวข้อง (sortBy/sortDirection ไม่มีผล)
+    pub async fn search_task(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskQuery>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let filter = task_filter(query.into_inner(), &auth_user)?;
+
+        match handler.use_case.search_task(filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("search task successfully", page.items, meta)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // GET /projects/{project_id}/tasks
+    pub async fn list_project_task(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskQuery>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let mut filter = task_filter(query.into_inner(), &auth_user)?;
+        filter.project_id = Some(path.into_inner());
+
+        match handler.use_case.list_task(filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("get task successfully", page.items, meta)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // GET /task/{task_id}/subtasks
+    pub async fn list_subtask(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskQuery>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let filter = task_filter(query.into_inner(), &auth_user)?;
+
+        match handler.use_case.list_subtask(path.into_inner(), filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("get subtask successfully", page.items, meta)))
+            }
             Err(e) => Err(e),
         }
     }
 
-    pub async fn get_task(handler: web::Data<TaskHandler<T>>, path: web::Path<i64>) -> Result<impl Responder, CustomError> {
+    pub async fn list_trash(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskQuery>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let filter = task_filter(query.into_inner(), &auth_user)?;
+
+        match handler.use_case.list_trash(filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("get trash successfully", page.items, meta)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn restore_task(handler: web::Data<TaskHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
         let task_id = path.into_inner();
-        match handler.use_case.get_task(task_id).await {
-            Ok(task) => Ok(HttpResponse::Ok().json(response_success("get task successfully", task))),
+        match handler.use_case.restore_task(task_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task restored successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_task(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<GetTaskQuery>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let expand = query.expand.as_deref().map(TaskExpand::parse).transpose()?.unwrap_or_default();
+        let task_id = path.into_inner();
+        match handler.use_case.get_task(task_id, expand, &auth_user).await {
+            Ok(task) => Ok(HttpResponse::Ok()
+                .insert_header((header::ETAG, task_etag(task.version)))
+                .json(response_success("get task successfully", task))),
             Err(e) => Err(e),
         }
     }
@@ -42,23 +208,27 @@ impl<T: TaskUseCase + Send + Sync> TaskHandler<T> {
         body: web::Json<TaskRequest>,
         req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
-        // user_id จาก JWT ที่ถอดมาจาก middleware
-        let user_id = extract_user_id(&req).await?;
+        // user จาก JWT ที่ถอดมาจาก middleware (id และ role)
+        let auth_user = extract_auth_user(&req).await?;
 
         // validate body request
         body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
 
         // เตรียมข้อมูลส่งให้ layer use case
         let new_task_entity = TaskCreateEntity {
+            project_id: body.project_id,
+            parent_task_id: body.parent_task_id,
             title: body.title.clone(),
             description: body.description.clone(),
-            task_status_id: body.task_status_id,
-            priority_levels_id: body.priority_levels_id,
-            created_by: user_id,
+            task_status_id: body.task_status_id.clone(),
+            priority_levels_id: body.priority_levels_id.clone(),
+            start_date: body.start_date,
+            due_date: body.due_date,
+            created_by: auth_user.id,
         };
 
         // เรียก use case และ return
-        match handler.use_case.create_task(new_task_entity).await {
+        match handler.use_case.create_task(new_task_entity, &auth_user).await {
             Ok(task_id) => Ok(HttpResponse::Created().json(response_success("Task created successfully", task_id))),
             Err(e) => Err(e),
         }
@@ -70,21 +240,60 @@ impl<T: TaskUseCase + Send + Sync> TaskHandler<T> {
         path: web::Path<i64>,
         req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
-        let user_id = extract_user_id(&req).await?;
+        let auth_user = extract_auth_user(&req).await?;
         let task_id = path.into_inner();
+        let version = if_match_version(&req)?;
 
         body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
 
         let update_task_entity = UpdateTaskEntity {
             id: task_id,
+            project_id: body.project_id,
+            parent_task_id: body.parent_task_id,
             title: body.title.clone(),
             description: body.description.clone(),
+            task_status_id: body.task_status_id.clone(),
+            priority_levels_id: body.priority_levels_id.clone(),
+            start_date: body.start_date,
+            due_date: body.due_date,
+            updated_by: auth_user.id,
+            version,
+        };
+
+        match handler.use_case.update_task(update_task_entity, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task updated successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn patch_task(
+        handler: web::Data<TaskHandler<T>>,
+        body: web::Json<PatchTaskRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let task_id = path.into_inner();
+        let version = if_match_version(&req)?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let patch_task_entity = PatchTaskEntity {
+            id: task_id,
+            project_id: body.project_id,
+            parent_task_id: body.parent_task_id,
+            title: body.title,
+            description: body.description,
             task_status_id: body.task_status_id,
             priority_levels_id: body.priority_levels_id,
-            updated_by: user_id,
+            start_date: body.start_date,
+            due_date: body.due_date,
+            updated_by: auth_user.id,
+            version,
         };
 
-        match handler.use_case.update_task(update_task_entity).await {
+        match handler.use_case.patch_task(patch_task_entity, &auth_user).await {
             Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task updated successfully", ()))),
             Err(e) => Err(e),
         }
@@ -96,18 +305,20 @@ impl<T: TaskUseCase + Send + Sync> TaskHandler<T> {
         path: web::Path<i64>,
         req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
-        let user_id = extract_user_id(&req).await?;
+        let auth_user = extract_auth_user(&req).await?;
         let task_id = path.into_inner();
+        let version = if_match_version(&req)?;
 
         body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
 
         let update_task_entity = UpdateTaskStatusEntity {
             id: task_id,
-            task_status_id: body.task_status_id,
-            updated_by: user_id,
+            task_status_id: body.task_status_id.clone(),
+            updated_by: auth_user.id,
+            version,
         };
 
-        match handler.use_case.update_task_status(update_task_entity).await {
+        match handler.use_case.update_task_status(update_task_entity, &auth_user).await {
             Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task status updated successfully", ()))),
             Err(e) => Err(e),
         }
@@ -119,28 +330,536 @@ impl<T: TaskUseCase + Send + Sync> TaskHandler<T> {
         path: web::Path<i64>,
         req: HttpRequest,
     ) -> Result<impl Responder, CustomError> {
-        let user_id = extract_user_id(&req).await?;
+        let auth_user = extract_auth_user(&req).await?;
         let task_id = path.into_inner();
+        let version = if_match_version(&req)?;
 
         body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
 
         let update_task_entity = UpdateTaskPriorityLevelsEntity {
             id: task_id,
-            priority_levels_id: body.priority_levels_id,
-            updated_by: user_id,
+            priority_levels_id: body.priority_levels_id.clone(),
+            updated_by: auth_user.id,
+            version,
         };
 
-        match handler.use_case.update_task_priority_levels(update_task_entity).await {
+        match handler.use_case.update_task_priority_levels(update_task_entity, &auth_user).await {
             Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task priority levels updated successfully", ()))),
             Err(e) => Err(e),
         }
     }
 
-    pub async fn delete_task(handler: web::Data<TaskHandler<T>>, path: web::Path<i64>) -> Result<impl Responder, CustomError> {
+    pub async fn delete_task(handler: web::Data<TaskHandler<T>>, path: web::Path<i64>, req: HttpRequest) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
         let task_id = path.into_inner();
-        match handler.use_case.delete_task(task_id).await {
+        let version = if_match_version(&req)?;
+        match handler.use_case.delete_task(task_id, version, &auth_user).await {
             Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task deleted successfully", ()))),
             Err(e) => Err(e),
         }
     }
+
+    pub async fn list_task_history(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskHistoryQuery>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let filter = TaskHistoryFilter {
+            task_id: path.into_inner(),
+            cursor: query.cursor.as_deref().map(TaskCursor::decode).transpose()?,
+            limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        };
+
+        match handler.use_case.list_task_history(filter, &auth_user).await {
+            Ok(page) => {
+                let meta = PageMeta {
+                    next_cursor: page.next_cursor,
+                    has_more: page.has_more,
+                };
+                Ok(HttpResponse::Ok().json(response_success_with_meta("get task history successfully", page.items, meta)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn assign_task(
+        handler: web::Data<TaskHandler<T>>,
+        body: web::Json<AssignTaskRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let task_id = path.into_inner();
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let assign = TaskAssign {
+            task_id,
+            user_ids: body.into_inner().user_ids,
+            assigned_by: auth_user.id,
+        };
+
+        match handler.use_case.assign_task(assign, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task assigned successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    // ส่งทีละหน้าตาม cursor จึงไม่ต้องโหลด task ทั้งหมดไว้ใน memory
+    pub async fn export_task(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ListTaskQuery>,
+        export: web::Query<ExportTaskQuery>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError>
+    where
+        T: 'static,
+    {
+        let auth_user = extract_auth_user(&req).await?;
+
+        query.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let format = export.format.unwrap_or_default();
+        let mut filter = task_filter(query.into_inner(), &auth_user)?;
+        filter.cursor = None;
+        filter.limit = EXPORT_PAGE_SIZE;
+        // CSV ใส่ code ของสถานะและความสำคัญไว้ให้อ่านง่าย
+        if format == TaskFileFormat::Csv {
+            filter.expand.status = true;
+            filter.expand.priority = true;
+        }
+
+        // ดึงหน้าแรกก่อนเริ่มส่ง เพื่อให้ error เรื่องสิทธิ์หรือ filter ตอบเป็น status code ปกติ
+        let page = handler.use_case.list_task(filter.clone(), &auth_user).await?;
+
+        let pages = stream::try_unfold(Some((page, filter, 0)), move |state| {
+            let handler = handler.clone();
+            async move {
+                let Some((page, mut filter, written)) = state else {
+                    return Ok(None);
+                };
+
+                let mut chunk = String::new();
+                for (index, task) in page.items.iter().enumerate() {
+                    chunk.push_str(&export_record(format, task, written + index)?);
+                }
+                let written = written + page.items.len();
+
+                let next = match page.next_cursor.filter(|_| page.has_more) {
+                    Some(cursor) => {
+                        filter.cursor = Some(TaskCursor::decode(&cursor)?);
+                        let page = handler.use_case.list_task(filter.clone(), &auth_user).await?;
+                        Some((page, filter, written))
+                    }
+                    None => None,
+                };
+
+                Ok::<_, CustomError>(Some((web::Bytes::from(chunk), next)))
+            }
+        });
+        let (prefix, suffix) = match format {
+            TaskFileFormat::Csv => (csv::write_record(&TASK_CSV_COLUMNS.map(String::from)), String::new()),
+            TaskFileFormat::Json => ("[".to_string(), "]".to_string()),
+            TaskFileFormat::Ndjson => (String::new(), String::new()),
+        };
+        let body = stream::once(async move { Ok(web::Bytes::from(prefix)) })
+            .chain(pages)
+            .chain(stream::once(async move { Ok(web::Bytes::from(suffix)) }));
+
+        let (content_type, file_name) = match format {
+            TaskFileFormat::Csv => ("text/csv; charset=utf-8", "tasks.csv"),
+            TaskFileFormat::Json => ("application/json", "tasks.json"),
+            TaskFileFormat::Ndjson => ("application/x-ndjson", "tasks.ndjson"),
+        };
+
+        Ok(HttpResponse::Ok()
+            .content_type(content_type)
+            .insert_header(ContentDisposition::attachment(file_name))
+            .streaming(body))
+    }
+
+    // ตรวจทุกแถวและรายงาน error รายแถว บันทึกเมื่อทุกแถวผ่านและไม่ใช่ dryRun
+    pub async fn import_task(
+        handler: web::Data<TaskHandler<T>>,
+        query: web::Query<ImportTaskQuery>,
+        body: web::Bytes,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        let format = query.format.unwrap_or_else(|| import_format(&req));
+        let text = std::str::from_utf8(&body).map_err(|_| CustomError::ValidationError(INVALID_TASK_IMPORT_FILE.to_string()))?;
+        let rows = match format {
+            TaskFileFormat::Csv => csv_import_rows(text)?,
+            TaskFileFormat::Json => json_import_rows(text)?,
+            TaskFileFormat::Ndjson => ndjson_import_rows(text),
+        };
+
+        let import = TaskImport {
+            dry_run: query.dry_run.unwrap_or(false),
+            rows: rows
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| TaskImportRow {
+                    row: index + 1,
+                    task: row.and_then(|row| import_task_entity(row, auth_user.id)),
+                })
+                .collect(),
+        };
+
+        match handler.use_case.import_task(import, &auth_user).await {
+            Ok(result) => Ok(HttpResponse::Ok().json(response_success("Task import processed", result))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn bulk_task(
+        handler: web::Data<TaskHandler<T>>,
+        body: web::Json<BulkTaskRequest>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let body = body.into_inner();
+        let bulk = TaskBulk {
+            mode: body.mode,
+            operations: body.operations.into_iter().map(bulk_operation).collect::<Result<_, _>>()?,
+        };
+
+        match handler.use_case.bulk_task(bulk, &auth_user).await {
+            Ok(result) => Ok(HttpResponse::Ok().json(response_success("Bulk task operations processed", result))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn unassign_task(
+        handler: web::Data<TaskHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (task_id, user_id) = path.into_inner();
+
+        match handler.use_case.unassign_task(task_id, user_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task unassigned successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn add_labels(
+        handler: web::Data<TaskHandler<T>>,
+        body: web::Json<TaskLabelRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        body.validate().map_err(|e| CustomError::ValidationError(e.to_string()))?;
+
+        let labels = TaskLabelAdd {
+            task_id: path.into_inner(),
+            label_ids: body.into_inner().label_ids,
+            labeled_by: auth_user.id,
+        };
+
+        match handler.use_case.add_labels(labels, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task labels added successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn remove_label(
+        handler: web::Data<TaskHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (task_id, label_id) = path.into_inner();
+
+        match handler.use_case.remove_label(task_id, label_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task label removed successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn add_dependency(
+        handler: web::Data<TaskHandler<T>>,
+        body: web::Json<TaskDependencyRequest>,
+        path: web::Path<i64>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+
+        let dependency = TaskDependency {
+            task_id: path.into_inner(),
+            blocked_by_task_id: body.blocked_by_task_id,
+            created_by: auth_user.id,
+        };
+
+        match handler.use_case.add_dependency(dependency, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task dependency added successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn remove_dependency(
+        handler: web::Data<TaskHandler<T>>,
+        path: web::Path<(i64, i64)>,
+        req: HttpRequest,
+    ) -> Result<impl Responder, CustomError> {
+        let auth_user = extract_auth_user(&req).await?;
+        let (task_id, blocked_by_task_id) = path.into_inner();
+
+        match handler.use_case.remove_dependency(task_id, blocked_by_task_id, &auth_user).await {
+            Ok(..) => Ok(HttpResponse::Ok().json(response_success("Task dependency removed successfully", ()))),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn task_filter(query: ListTaskQuery, auth_user: &AuthUser) -> Result<TaskFilter, CustomError> {
+    Ok(TaskFilter {
+        project_id: query.project_id,
+        parent_task_id: None,
+        task_status_id: query.task_status_id,
+        priority_levels_id: query.priority_levels_id,
+        created_by: query.created_by,
+        assignee: query.assignee.as_deref().map(|assignee| parse_assignee(assignee, auth_user)).transpose()?,
+        member_id: None,
+        created_from: query.created_from,
+        created_to: query.created_to,
+        updated_from: query.updated_from,
+        updated_to: query.updated_to,
+        due_before: query.due_before,
+        due_this_week: query.due_this_week.unwrap_or(false),
+        overdue: query.overdue,
+        deleted: false,
+        search: query.q,
+        labels: query.label.as_deref().map(parse_labels).unwrap_or_default(),
+        label_match: query.label_match.unwrap_or_default(),
+        sort_by: query.sort_by.unwrap_or_default(),
+        sort_direction: query.sort_direction.unwrap_or_default(),
+        cursor: query.cursor.as_deref().map(TaskCursor::decode).transpose()?,
+        limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        expand: query.expand.as_deref().map(TaskExpand::parse).transpose()?.unwrap_or_default(),
+    })
+}
+
+fn export_record(format: TaskFileFormat, task: &Task, index: usize) -> Result<String, CustomError> {
+    let json = || serde_json::to_string(task).map_err(|e| CustomError::InternalError(format!("Failed to serialize task: {}", e)));
+
+    Ok(match format {
+        TaskFileFormat::Csv => csv::write_record(&task_csv_record(task)),
+        TaskFileFormat::Json if index == 0 => json()?,
+        TaskFileFormat::Json => format!(",{}", json()?),
+        TaskFileFormat::Ndjson => format!("{}\n", json()?),
+    })
+}
+
+// เรียงตาม TASK_CSV_COLUMNS, ค่าหลายค่าคั่นด้วย ;
+fn task_csv_record(task: &Task) -> Vec<String> {
+    fn optional<V: ToString>(value: Option<V>) -> String {
+        value.map(|value| value.to_string()).unwrap_or_default()
+    }
+    fn datetime(value: Option<chrono::NaiveDateTime>) -> String {
+        optional(value.map(|value| value.format("%Y-%m-%dT%H:%M:%S%.f")))
+    }
+
+    vec![
+        task.id.to_string(),
+        task.project_id.to_string(),
+        optional(task.parent_task_id),
+        task.title.clone(),
+        task.description.clone().unwrap_or_default(),
+        optional(task.task_status_id),
+        optional(task.task_status.as_ref().map(|status| &status.code)),
+        optional(task.priority_levels_id),
+        optional(task.priority_levels.as_ref().map(|priority| &priority.code)),
+        task.assignees.iter().map(|id| id.to_string()).collect::<Vec<String>>().join(";"),
+        task.labels.iter().map(|label| label.name.as_str()).collect::<Vec<&str>>().join(";"),
+        datetime(task.start_date),
+        datetime(task.due_date),
+        task.overdue.to_string(),
+        task.created_by.to_string(),
+        datetime(Some(task.created_at)),
+        datetime(task.updated_at),
+        task.version.to_string(),
+    ]
+}
+
+fn import_format(req: &HttpRequest) -> TaskFileFormat {
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    if content_type.starts_with("text/csv") {
+        TaskFileFormat::Csv
+    } else if content_type.starts_with("application/x-ndjson") {
+        TaskFileFormat::Ndjson
+    } else {
+        TaskFileFormat::Json
+    }
+}
+
+fn json_import_rows(text: &str) -> Result<Vec<Result<ImportTaskRow, String>>, CustomError> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(text)
+        .map_err(|_| CustomError::ValidationError(INVALID_TASK_IMPORT_FILE.to_string()))?;
+
+    Ok(values
+        .into_iter()
+        .map(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+        .collect())
+}
+
+// บรรทัดว่างไม่นับเป็นแถว
+fn ndjson_import_rows(text: &str) -> Vec<Result<ImportTaskRow, String>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| e.to_string()))
+        .collect()
+}
+
+fn csv_import_rows(text: &str) -> Result<Vec<Result<ImportTaskRow, String>>, CustomError> {
+    let mut records = csv::parse(text)
+        .map_err(|_| CustomError::ValidationError(INVALID_TASK_IMPORT_FILE.to_string()))?
+        .into_iter();
+    let header: HashMap<String, usize> = records
+        .next()
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(index, column)| (column.trim().to_string(), index))
+        .collect();
+
+    // สถานะและความสำคัญใช้ column code (task_status, priority_levels) แทน id ได้
+    let required = [["project_id", "project_id"], ["title", "title"], ["task_status_id", "task_status"], ["priority_levels_id", "priority_levels"]];
+    if let Some([column, _]) = required.iter().find(|columns| columns.iter().all(|column| !header.contains_key(*column))) {
+        return Err(CustomError::ValidationError(format!("{}: {}", TASK_IMPORT_MISSING_COLUMN, column)));
+    }
+
+    Ok(records.map(|record| csv_import_row(&header, &record)).collect())
+}
+
+fn csv_import_row(header: &HashMap<String, usize>, record: &[String]) -> Result<ImportTaskRow, String> {
+    if record.len() != header.len() {
+        return Err(format!("expected {} columns but found {}", header.len(), record.len()));
+    }
+
+    let value = |column: &str| {
+        header
+            .get(column)
+            .map(|index| record[*index].as_str())
+            .filter(|value| !value.trim().is_empty())
+    };
+    let master_data = |id_column: &str, code_column: &str| {
+        value(id_column)
+            .or_else(|| value(code_column))
+            .map(|value| MasterDataRef::Code(value.trim().to_string()))
+            .ok_or(format!("{}: must not be empty", id_column))
+    };
+
+    Ok(ImportTaskRow {
+        project_id: parse_csv_value(value("project_id"), "project_id")?.ok_or("project_id: must not be empty".to_string())?,
+        parent_task_id: parse_csv_value(value("parent_task_id"), "parent_task_id")?,
+        title: value("title").unwrap_or_default().to_string(),
+        description: value("description").map(str::to_string),
+        task_status_id: master_data("task_status_id", "task_status")?,
+        priority_levels_id: master_data("priority_levels_id", "priority_levels")?,
+        start_date: parse_csv_value(value("start_date"), "start_date")?,
+        due_date: parse_csv_value(value("due_date"), "due_date")?,
+    })
+}
+
+fn parse_csv_value<V: FromStr>(value: Option<&str>, column: &str) -> Result<Option<V>, String> {
+    value
+        .map(|value| value.trim().parse::<V>().map_err(|_| format!("{}: invalid value {}", column, value)))
+        .transpose()
+}
+
+fn import_task_entity(row: ImportTaskRow, created_by: i64) -> Result<TaskCreateEntity<MasterDataRef>, String> {
+    row.validate().map_err(|e| e.to_string())?;
+
+    Ok(TaskCreateEntity {
+        project_id: row.project_id,
+        parent_task_id: row.parent_task_id,
+        title: row.title,
+        description: row.description,
+        task_status_id: row.task_status_id,
+        priority_levels_id: row.priority_levels_id,
+        start_date: row.start_date,
+        due_date: row.due_date,
+        created_by,
+    })
+}
+
+fn bulk_operation(operation: BulkTaskOperationRequest) -> Result<TaskBulkOperation<MasterDataRef>, CustomError> {
+    let action = match operation.op {
+        BulkTaskOperationType::UpdateStatus => operation.task_status_id.map(TaskBulkAction::UpdateStatus),
+        BulkTaskOperationType::UpdatePriority => operation.priority_levels_id.map(TaskBulkAction::UpdatePriority),
+        BulkTaskOperationType::Assign => operation.user_ids.map(TaskBulkAction::Assign),
+        BulkTaskOperationType::Delete => Some(TaskBulkAction::Delete),
+    };
+
+    Ok(TaskBulkOperation {
+        action: action.ok_or(CustomError::ValidationError(INVALID_TASK_BULK_OPERATION.to_string()))?,
+        task_ids: operation.task_ids,
+    })
+}
+
+fn task_etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+// การแก้ไข/ลบต้องส่ง If-Match ที่ได้จาก ETag ของ GET /task/{task_id} (หรือ * เพื่อข้ามการตรวจสอบ)
+fn if_match_version(req: &HttpRequest) -> Result<Option<i32>, CustomError> {
+    let if_match = req
+        .headers()
+        .get(header::IF_MATCH)
+        .ok_or(CustomError::PreconditionRequired(IF_MATCH_REQUIRED.to_string()))?
+        .to_str()
+        .map_err(|_| CustomError::ValidationError(INVALID_IF_MATCH.to_string()))?
+        .trim();
+
+    if if_match == "*" {
+        return Ok(None);
+    }
+
+    if_match
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .and_then(|value| value.parse::<i32>().ok())
+        .map(Some)
+        .ok_or(CustomError::ValidationError(INVALID_IF_MATCH.to_string()))
+}
+
+// assignee=me ใช้ id ของผู้ที่ login อยู่
+fn parse_assignee(assignee: &str, auth_user: &AuthUser) -> Result<i64, CustomError> {
+    if assignee.eq_ignore_ascii_case("me") {
+        return Ok(auth_user.id);
+    }
+
+    assignee
+        .parse::<i64>()
+        .map_err(|_| CustomError::ValidationError(INVALID_ASSIGNEE_FILTER.to_string()))
+}
+
+// เทียบชื่อ label แบบไม่สนตัวพิมพ์ ค่าว่างและชื่อซ้ำถูกตัดออก
+fn parse_labels(label: &str) -> Vec<String> {
+    let mut labels: Vec<String> = label
+        .split(',')
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect();
+    labels.sort_unstable();
+    labels.dedup();
+    labels
 }
\ No newline at end of file
@@ -9,3 +9,63 @@ pub struct LoginRequest {
     #[validate(length(min = 6))]
     pub password: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct RefreshTokenRequest {
+    #[serde(rename = "refreshToken")]
+    #[validate(length(min = 1))]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct LogoutRequest {
+    #[serde(rename = "refreshToken")]
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct RegisterRequest {
+    #[validate(length(min = 3, max = 255))]
+    pub username: String,
+
+    #[validate(length(min = 8))]
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateUserRequest {
+    #[validate(length(min = 3, max = 255))]
+    pub username: String,
+
+    #[validate(length(min = 8))]
+    pub password: String,
+
+    #[serde(rename = "roleId")]
+    pub role_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateUserRequest {
+    #[serde(rename = "roleId")]
+    pub role_id: i64,
+
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ChangePasswordRequest {
+    #[serde(rename = "currentPassword")]
+    #[validate(length(min = 1))]
+    pub current_password: String,
+
+    #[serde(rename = "newPassword")]
+    #[validate(length(min = 8))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ResetPasswordRequest {
+    #[serde(rename = "newPassword")]
+    #[validate(length(min = 8))]
+    pub new_password: String,
+}
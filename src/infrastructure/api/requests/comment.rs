@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CommentRequest {
+    #[validate(length(min = 1, max = 10000))]
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ListCommentQuery {
+    pub cursor: Option<String>,
+
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct LabelRequest {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+
+    // #rrggbb
+    pub color: String,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct MasterDataRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub title: String,
+
+    // เช่น IN_PROGRESS
+    #[validate(length(min = 1, max = 50))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateMasterDataRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub title: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeactivateMasterDataRequest {
+    // ค่าที่ใช้แทนสำหรับข้อมูลที่ยังอ้างอิงอยู่
+    #[serde(rename = "replacementId")]
+    pub replacement_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ReorderMasterDataRequest {
+    // id ทั้งหมดเรียงตามลำดับใหม่
+    #[validate(length(min = 1, max = 100))]
+    pub ids: Vec<i64>,
+}
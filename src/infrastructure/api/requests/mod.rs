@@ -1,2 +1,6 @@
 pub mod task;
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod project;
+pub mod comment;
+pub mod label;
+pub mod master_data;
\ No newline at end of file
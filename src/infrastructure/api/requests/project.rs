@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ProjectRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ListProjectQuery {
+    // true = ดูเฉพาะ project ที่เก็บเข้าคลังแล้ว
+    pub archived: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SetProjectMemberRequest {
+    #[serde(rename = "roleId")]
+    pub role_id: i64,
+}
@@ -1,28 +1,269 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
+use crate::domain::entities::master_data::MasterDataRef;
+use crate::domain::entities::task::{LabelMatch, SortDirection, TaskBulkMode, TaskSortField};
+use crate::shared::utils::merge_patch::deserialize_some;
 
 #[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct TaskRequest {
+    #[serde(rename = "projectId")]
+    pub project_id: i64,
+
+    // ไม่ส่งหรือ null = task ระดับบนสุด
+    #[serde(rename = "parentTaskId", default)]
+    pub parent_task_id: Option<i64>,
+
     #[validate(length(min = 1))]
     pub title: String,
 
     pub description: Option<String>,
 
+    // รับได้ทั้ง id และ code ของ master data
     #[serde(rename = "taskStatusId")]
-    pub task_status_id: i64,
+    pub task_status_id: MasterDataRef,
 
     #[serde(rename = "priorityLevelsId")]
-    pub priority_levels_id: i64,
+    pub priority_levels_id: MasterDataRef,
+
+    #[serde(rename = "startDate", default)]
+    pub start_date: Option<NaiveDateTime>,
+
+    #[serde(rename = "dueDate", default)]
+    pub due_date: Option<NaiveDateTime>,
+}
+
+// JSON Merge Patch: field ที่ไม่ส่งมาจะไม่ถูกแก้ไข และ null คือล้างค่า (เฉพาะ field ที่เป็น null ได้)
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PatchTaskRequest {
+    #[serde(rename = "projectId", default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<i64>,
+
+    #[serde(rename = "parentTaskId", default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub parent_task_id: Option<Option<i64>>,
+
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    pub title: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+
+    #[serde(rename = "taskStatusId", default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub task_status_id: Option<MasterDataRef>,
+
+    #[serde(rename = "priorityLevelsId", default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub priority_levels_id: Option<MasterDataRef>,
+
+    #[serde(rename = "startDate", default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Option<NaiveDateTime>>,
+
+    #[serde(rename = "dueDate", default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Option<NaiveDateTime>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct UpdateTaskStatusRequest {
     #[serde(rename = "taskStatusId")]
-    pub task_status_id: i64,
+    pub task_status_id: MasterDataRef,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct UpdateTaskPriorityLevelsRequest {
     #[serde(rename = "priorityLevelsId")]
-    pub priority_levels_id: i64,
-}
\ No newline at end of file
+    pub priority_levels_id: MasterDataRef,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct AssignTaskRequest {
+    #[serde(rename = "userIds")]
+    #[validate(length(min = 1, max = 50))]
+    pub user_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct BulkTaskRequest {
+    // ไม่ส่ง = atomic
+    #[serde(default)]
+    pub mode: TaskBulkMode,
+
+    #[validate(length(min = 1, max = 20), nested)]
+    pub operations: Vec<BulkTaskOperationRequest>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BulkTaskOperationType {
+    #[serde(rename = "updateStatus")]
+    UpdateStatus,
+    #[serde(rename = "updatePriority")]
+    UpdatePriority,
+    #[serde(rename = "assign")]
+    Assign,
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+// field ที่ต้องส่งขึ้นกับ op: taskStatusId, priorityLevelsId หรือ userIds
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct BulkTaskOperationRequest {
+    pub op: BulkTaskOperationType,
+
+    #[serde(rename = "taskIds")]
+    #[validate(length(min = 1, max = 100))]
+    pub task_ids: Vec<i64>,
+
+    #[serde(rename = "taskStatusId", default)]
+    pub task_status_id: Option<MasterDataRef>,
+
+    #[serde(rename = "priorityLevelsId", default)]
+    pub priority_levels_id: Option<MasterDataRef>,
+
+    #[serde(rename = "userIds", default)]
+    #[validate(length(min = 1, max = 50))]
+    pub user_ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct TaskLabelRequest {
+    #[serde(rename = "labelIds")]
+    #[validate(length(min = 1, max = 50))]
+    pub label_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskDependencyRequest {
+    // task ที่ต้องเสร็จก่อน task นี้
+    #[serde(rename = "blockedByTaskId")]
+    pub blocked_by_task_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ListTaskQuery {
+    #[serde(rename = "projectId")]
+    pub project_id: Option<i64>,
+
+    #[serde(rename = "taskStatusId")]
+    pub task_status_id: Option<i64>,
+
+    #[serde(rename = "priorityLevelsId")]
+    pub priority_levels_id: Option<i64>,
+
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<i64>,
+
+    // "me" หรือ user id
+    pub assignee: Option<String>,
+
+    #[serde(rename = "createdFrom")]
+    pub created_from: Option<NaiveDateTime>,
+
+    #[serde(rename = "createdTo")]
+    pub created_to: Option<NaiveDateTime>,
+
+    #[serde(rename = "updatedFrom")]
+    pub updated_from: Option<NaiveDateTime>,
+
+    #[serde(rename = "updatedTo")]
+    pub updated_to: Option<NaiveDateTime>,
+
+    #[serde(rename = "dueBefore")]
+    pub due_before: Option<NaiveDateTime>,
+
+    #[serde(rename = "dueThisWeek")]
+    pub due_this_week: Option<bool>,
+
+    pub overdue: Option<bool>,
+
+    #[validate(length(max = 255))]
+    pub q: Option<String>,
+
+    // ชื่อ label คั่นด้วย , เช่น bug,frontend
+    #[validate(length(max = 255))]
+    pub label: Option<String>,
+
+    #[serde(rename = "labelMatch")]
+    pub label_match: Option<LabelMatch>,
+
+    #[serde(rename = "sortBy")]
+    pub sort_by: Option<TaskSortField>,
+
+    #[serde(rename = "sortDirection")]
+    pub sort_direction: Option<SortDirection>,
+
+    pub cursor: Option<String>,
+
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+
+    // status,priority,createdBy
+    #[validate(length(max = 255))]
+    pub expand: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetTaskQuery {
+    // status,priority,createdBy
+    #[validate(length(max = 255))]
+    pub expand: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TaskFileFormat {
+    #[serde(rename = "csv")]
+    Csv,
+    #[default]
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "ndjson")]
+    Ndjson,
+}
+
+// ใช้คู่กับ ListTaskQuery (filter เดียวกับ GET /task แต่ไม่แบ่งหน้า)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportTaskQuery {
+    pub format: Option<TaskFileFormat>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportTaskQuery {
+    // ไม่ส่ง = ดูจาก Content-Type (text/csv, application/x-ndjson, อื่น ๆ เป็น json)
+    pub format: Option<TaskFileFormat>,
+
+    #[serde(rename = "dryRun")]
+    pub dry_run: Option<bool>,
+}
+
+// แถวของไฟล์ import ใช้ชื่อ field เดียวกับไฟล์ export (field อื่นที่ export ออกมาจะถูกข้าม)
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ImportTaskRow {
+    pub project_id: i64,
+
+    #[serde(default)]
+    pub parent_task_id: Option<i64>,
+
+    #[validate(length(min = 1))]
+    pub title: String,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    // รับได้ทั้ง id และ code ของ master data
+    pub task_status_id: MasterDataRef,
+
+    pub priority_levels_id: MasterDataRef,
+
+    #[serde(default)]
+    pub start_date: Option<NaiveDateTime>,
+
+    #[serde(default)]
+    pub due_date: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ListTaskHistoryQuery {
+    pub cursor: Option<String>,
+
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,21 @@
+use actix_web::web;
+use crate::application::interfaces::attachment::AttachmentUseCase;
+use crate::infrastructure::api::handlers::attachment::AttachmentHandler;
+use crate::shared::middleware::auth::JwtMiddleware;
+
+// อยู่ใต้ /task/{task_id} จึงต้อง register ก่อน scope /task ของ task routes
+pub fn configure_attachment_routes<T: AttachmentUseCase + Send + Sync + 'static>(cfg: &mut web::ServiceConfig, jwt_middleware: JwtMiddleware) {
+    cfg.service(
+        web::resource("/task/{task_id}/attachments")
+            .wrap(jwt_middleware.clone())
+            .route(web::get().to(AttachmentHandler::<T>::list_attachment))
+            // multipart/form-data field "file"
+            .route(web::post().to(AttachmentHandler::<T>::upload_attachment)),
+    );
+    cfg.service(
+        web::resource("/task/{task_id}/attachments/{attachment_id}")
+            .wrap(jwt_middleware)
+            .route(web::get().to(AttachmentHandler::<T>::download_attachment))
+            .route(web::delete().to(AttachmentHandler::<T>::delete_attachment)),
+    );
+}
@@ -1,12 +1,30 @@
 use actix_web::web;
 use crate::application::interfaces::auth::AuthUseCase;
 use crate::infrastructure::api::handlers::auth::AuthHandler;
+use crate::shared::middleware::auth::JwtMiddleware;
 
 pub fn configure_user_routes<T: AuthUseCase + Send + Sync + 'static>(
     cfg: &mut web::ServiceConfig,
+    jwt_middleware: JwtMiddleware,
 ) {
     cfg.service(
         web::scope("/users")
             .route("/login", web::post().to(AuthHandler::<T>::login))
+            .route("/refresh", web::post().to(AuthHandler::<T>::refresh))
+            .route("/register", web::post().to(AuthHandler::<T>::register))
+            // routes ที่ต้อง login
+            .service(
+                web::scope("")
+                    .wrap(jwt_middleware)
+                    .route("/logout", web::post().to(AuthHandler::<T>::logout))
+                    .route("/me", web::get().to(AuthHandler::<T>::me))
+                    .route("/me/password", web::put().to(AuthHandler::<T>::change_password))
+                    .route("", web::get().to(AuthHandler::<T>::list_users))
+                    .route("", web::post().to(AuthHandler::<T>::create_user))
+                    .route("/{user_id}", web::get().to(AuthHandler::<T>::get_user))
+                    .route("/{user_id}", web::put().to(AuthHandler::<T>::update_user))
+                    .route("/{user_id}", web::delete().to(AuthHandler::<T>::deactivate_user))
+                    .route("/{user_id}/password", web::put().to(AuthHandler::<T>::reset_password))
+            )
     );
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use actix_web::web;
+use crate::application::interfaces::comment::CommentUseCase;
+use crate::infrastructure::api::handlers::comment::CommentHandler;
+use crate::shared::middleware::auth::JwtMiddleware;
+
+// อยู่ใต้ /task/{task_id} จึงต้อง register ก่อน scope /task ของ task routes
+pub fn configure_comment_routes<T: CommentUseCase + Send + Sync + 'static>(cfg: &mut web::ServiceConfig, jwt_middleware: JwtMiddleware) {
+    cfg.service(
+        web::resource("/task/{task_id}/comments")
+            .wrap(jwt_middleware.clone())
+            .route(web::get().to(CommentHandler::<T>::list_comment))
+            .route(web::post().to(CommentHandler::<T>::create_comment)),
+    );
+    cfg.service(
+        web::resource("/task/{task_id}/comments/{comment_id}")
+            .wrap(jwt_middleware)
+            .route(web::put().to(CommentHandler::<T>::update_comment))
+            .route(web::delete().to(CommentHandler::<T>::delete_comment)),
+    );
+}
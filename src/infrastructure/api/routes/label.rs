@@ -0,0 +1,17 @@
+use actix_web::web;
+use crate::application::interfaces::label::LabelUseCase;
+use crate::infrastructure::api::handlers::label::LabelHandler;
+use crate::shared::middleware::auth::JwtMiddleware;
+
+pub fn configure_label_routes<T: LabelUseCase + Send + Sync + 'static>(cfg: &mut web::ServiceConfig, jwt_middleware: JwtMiddleware) {
+    cfg.service(
+        web::scope("/labels")
+            .wrap(jwt_middleware)
+            .route("", web::get().to(LabelHandler::<T>::list_label))
+            .route("", web::post().to(LabelHandler::<T>::create_label))
+            .route("/{label_id}", web::get().to(LabelHandler::<T>::get_label))
+            .route("/{label_id}", web::put().to(LabelHandler::<T>::update_label))
+            .route("/{label_id}", web::delete().to(LabelHandler::<T>::delete_label))
+        ,
+    );
+}
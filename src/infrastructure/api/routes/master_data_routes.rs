@@ -1,14 +1,28 @@
 use actix_web::web;
 use crate::application::interfaces::master_data::MasterDataUseCase;
 use crate::infrastructure::api::handlers::master_data_handler::MasterDataHandler;
+use crate::shared::middleware::auth::JwtMiddleware;
 
 pub fn configure_master_data_routes<T: MasterDataUseCase + Send + Sync + 'static>(
     cfg: &mut web::ServiceConfig,
+    jwt_middleware: JwtMiddleware,
 ) {
     cfg.service(
         web::scope("/master-data")
             .route("/task-status", web::get().to(MasterDataHandler::<T>::list_task_status))
+            .route("/task-status/{task_status_id}/transitions", web::get().to(MasterDataHandler::<T>::list_task_status_transition))
             .route("/role", web::get().to(MasterDataHandler::<T>::list_role))
             .route("/priority-levels", web::get().to(MasterDataHandler::<T>::list_priority_levels))
+            // routes จัดการ master data ของ Admin ({kind}: task-status, priority-levels, role)
+            .service(
+                web::scope("")
+                    .wrap(jwt_middleware)
+                    .route("/{kind}/all", web::get().to(MasterDataHandler::<T>::list_master_data))
+                    .route("/{kind}", web::post().to(MasterDataHandler::<T>::create_master_data))
+                    .route("/{kind}/order", web::put().to(MasterDataHandler::<T>::reorder_master_data))
+                    .route("/{kind}/{id}", web::put().to(MasterDataHandler::<T>::update_master_data))
+                    .route("/{kind}/{id}/activate", web::post().to(MasterDataHandler::<T>::activate_master_data))
+                    .route("/{kind}/{id}/deactivate", web::post().to(MasterDataHandler::<T>::deactivate_master_data))
+            )
     );
 }
\ No newline at end of file
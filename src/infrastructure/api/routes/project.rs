@@ -0,0 +1,23 @@
+use actix_web::web;
+use crate::application::interfaces::project::ProjectUseCase;
+use crate::infrastructure::api::handlers::project::ProjectHandler;
+use crate::shared::middleware::auth::JwtMiddleware;
+
+// GET /projects/{project_id}/tasks อยู่ใน task routes (ต้อง configure task routes ก่อน)
+pub fn configure_project_routes<T: ProjectUseCase + Send + Sync + 'static>(cfg: &mut web::ServiceConfig, jwt_middleware: JwtMiddleware) {
+    cfg.service(
+        web::scope("/projects")
+            .wrap(jwt_middleware)
+            .route("", web::get().to(ProjectHandler::<T>::list_project))
+            .route("", web::post().to(ProjectHandler::<T>::create_project))
+            .route("/{project_id}", web::get().to(ProjectHandler::<T>::get_project))
+            .route("/{project_id}", web::put().to(ProjectHandler::<T>::update_project))
+            .route("/{project_id}", web::delete().to(ProjectHandler::<T>::delete_project))
+            .route("/{project_id}/archive", web::post().to(ProjectHandler::<T>::archive_project))
+            .route("/{project_id}/unarchive", web::post().to(ProjectHandler::<T>::unarchive_project))
+            .route("/{project_id}/members", web::get().to(ProjectHandler::<T>::list_member))
+            .route("/{project_id}/members/{user_id}", web::put().to(ProjectHandler::<T>::set_member))
+            .route("/{project_id}/members/{user_id}", web::delete().to(ProjectHandler::<T>::remove_member))
+        ,
+    );
+}
@@ -3,17 +3,47 @@ use crate::application::interfaces::task::TaskUseCase;
 use crate::infrastructure::api::handlers::task::TaskHandler;
 use crate::shared::middleware::auth::JwtMiddleware;
 
-pub fn configure_task_routes<T: TaskUseCase + Send + Sync + 'static>(cfg: &mut web::ServiceConfig, jwt_secret: String) {
+// ขนาดไฟล์ import สูงสุด (ค่าเริ่มต้นของ body แบบ web::Bytes คือ 256KB)
+const TASK_IMPORT_MAX_BYTES: usize = 5 * 1024 * 1024;
+
+pub fn configure_task_routes<T: TaskUseCase + Send + Sync + 'static>(cfg: &mut web::ServiceConfig, jwt_middleware: JwtMiddleware) {
+    // task ใน project ต้อง register ก่อน scope /projects
+    cfg.service(
+        web::resource("/projects/{project_id}/tasks")
+            .wrap(jwt_middleware.clone())
+            .route(web::get().to(TaskHandler::<T>::list_project_task)),
+    );
     cfg.service(
         web::scope("/task")
-            .wrap(JwtMiddleware::new(jwt_secret))
+            .wrap(jwt_middleware)
             .route("", web::get().to(TaskHandler::<T>::list_task))
+            // ต้องมาก่อน /{task_id} เพื่อไม่ให้ trash/search ถูกมองเป็น id
+            .route("/trash", web::get().to(TaskHandler::<T>::list_trash))
+            .route("/search", web::get().to(TaskHandler::<T>::search_task))
+            .route("/export", web::get().to(TaskHandler::<T>::export_task))
             .route("/{task_id}", web::get().to(TaskHandler::<T>::get_task))
+            .route("/{task_id}/subtasks", web::get().to(TaskHandler::<T>::list_subtask))
             .route("", web::post().to(TaskHandler::<T>::create_task))
+            .route("/bulk", web::post().to(TaskHandler::<T>::bulk_task))
+            .service(
+                web::resource("/import")
+                    .app_data(web::PayloadConfig::new(TASK_IMPORT_MAX_BYTES))
+                    .route(web::post().to(TaskHandler::<T>::import_task)),
+            )
             .route("/{task_id}", web::put().to(TaskHandler::<T>::update_task))
+            // JSON Merge Patch (application/merge-patch+json)
+            .route("/{task_id}", web::patch().to(TaskHandler::<T>::patch_task))
             .route("/{task_id}/task-status", web::patch().to(TaskHandler::<T>::update_task_status))
             .route("/{task_id}/priority-levels", web::patch().to(TaskHandler::<T>::update_task_priority_levels))
             .route("/{task_id}", web::delete().to(TaskHandler::<T>::delete_task))
+            .route("/{task_id}/restore", web::post().to(TaskHandler::<T>::restore_task))
+            .route("/{task_id}/history", web::get().to(TaskHandler::<T>::list_task_history))
+            .route("/{task_id}/assignees", web::post().to(TaskHandler::<T>::assign_task))
+            .route("/{task_id}/assignees/{user_id}", web::delete().to(TaskHandler::<T>::unassign_task))
+            .route("/{task_id}/labels", web::post().to(TaskHandler::<T>::add_labels))
+            .route("/{task_id}/labels/{label_id}", web::delete().to(TaskHandler::<T>::remove_label))
+            .route("/{task_id}/dependencies", web::post().to(TaskHandler::<T>::add_dependency))
+            .route("/{task_id}/dependencies/{blocked_by_task_id}", web::delete().to(TaskHandler::<T>::remove_dependency))
         ,
     );
 }
\ No newline at end of file
@@ -9,7 +9,29 @@ pub struct ServerConfig {
     pub database_user: String,
     pub database_password: String,
     pub jwt_secret: String,
+    pub jwt_refresh_expire_seconds: i64,
     pub api_port: u16,
+    pub auto_migrate: bool,
+    pub allow_registration: bool,
+    pub task_trash_retention_days: i64,
+    pub task_purge_interval_seconds: u64,
+    // local หรือ s3
+    pub attachment_storage: String,
+    pub attachment_local_dir: String,
+    pub attachment_max_size_bytes: usize,
+    pub attachment_allowed_content_types: Vec<String>,
+    // ใช้เมื่อ attachment_storage = s3 (รองรับ S3-compatible เช่น MinIO ผ่าน endpoint)
+    pub s3_endpoint: Option<String>,
+    pub s3_region: String,
+    pub s3_bucket: Option<String>,
+    pub s3_access_key: Option<String>,
+    pub s3_secret_key: Option<String>,
+    // 0 คือไม่ cache master data
+    pub master_data_cache_ttl_seconds: u64,
+    // Cache-Control max-age ของ GET /master-data/*
+    pub master_data_cache_max_age_seconds: u64,
+    // รับ NOTIFY จาก instance อื่นเพื่อล้าง cache (ใช้เมื่อรันหลาย instance)
+    pub master_data_cache_listen: bool,
 }
 
 impl ServerConfig {
@@ -29,7 +51,57 @@ impl ServerConfig {
                 .expect("DB_PASSWORD must be set in environment variables to specify the database password"),
             jwt_secret: env::var("JWT_SECRET")
                 .expect("JWT_SECRET must be set to enable secure authentication"),
+            jwt_refresh_expire_seconds: env::var("JWT_REFRESH_EXPIRE_SECOND")
+                .unwrap_or_else(|_| "604800".into())
+                .parse::<i64>()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Invalid JWT_REFRESH_EXPIRE_SECOND: {}", e)))?,
             api_port: parse_port_from_env()?,
+            auto_migrate: env::var("DB_AUTO_MIGRATE")
+                .map(|value| value.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            allow_registration: env::var("ALLOW_REGISTRATION")
+                .map(|value| value.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            task_trash_retention_days: env::var("TASK_TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".into())
+                .parse::<i64>()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Invalid TASK_TRASH_RETENTION_DAYS: {}", e)))?,
+            task_purge_interval_seconds: env::var("TASK_PURGE_INTERVAL_SECOND")
+                .unwrap_or_else(|_| "3600".into())
+                .parse::<u64>()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Invalid TASK_PURGE_INTERVAL_SECOND: {}", e)))?,
+            attachment_storage: env::var("ATTACHMENT_STORAGE")
+                .unwrap_or_else(|_| "local".into())
+                .to_lowercase(),
+            attachment_local_dir: env::var("ATTACHMENT_LOCAL_DIR")
+                .unwrap_or_else(|_| "storage/attachments".into()),
+            attachment_max_size_bytes: env::var("ATTACHMENT_MAX_SIZE_BYTE")
+                .unwrap_or_else(|_| "10485760".into())
+                .parse::<usize>()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Invalid ATTACHMENT_MAX_SIZE_BYTE: {}", e)))?,
+            attachment_allowed_content_types: env::var("ATTACHMENT_ALLOWED_CONTENT_TYPES")
+                .unwrap_or_else(|_| "image/png,image/jpeg,image/gif,image/webp,application/pdf,text/plain".into())
+                .split(',')
+                .map(|content_type| content_type.trim().to_lowercase())
+                .filter(|content_type| !content_type.is_empty())
+                .collect(),
+            s3_endpoint: env::var("S3_ENDPOINT").ok(),
+            s3_region: env::var("S3_REGION")
+                .unwrap_or_else(|_| "us-east-1".into()),
+            s3_bucket: env::var("S3_BUCKET").ok(),
+            s3_access_key: env::var("S3_ACCESS_KEY").ok(),
+            s3_secret_key: env::var("S3_SECRET_KEY").ok(),
+            master_data_cache_ttl_seconds: env::var("MASTER_DATA_CACHE_TTL_SECOND")
+                .unwrap_or_else(|_| "300".into())
+                .parse::<u64>()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Invalid MASTER_DATA_CACHE_TTL_SECOND: {}", e)))?,
+            master_data_cache_max_age_seconds: env::var("MASTER_DATA_CACHE_MAX_AGE_SECOND")
+                .unwrap_or_else(|_| "60".into())
+                .parse::<u64>()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Invalid MASTER_DATA_CACHE_MAX_AGE_SECOND: {}", e)))?,
+            master_data_cache_listen: env::var("MASTER_DATA_CACHE_LISTEN")
+                .map(|value| value.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
         })
     }
 }
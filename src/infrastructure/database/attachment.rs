@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use deadpool_postgres::Pool;
+use std::sync::Arc;
+use tokio_postgres::Row;
+use crate::domain::entities::attachment::{Attachment, AttachmentCreateEntity};
+use crate::domain::repositories::attachment::AttachmentRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{ATTACHMENT_NOT_FOUND, RECORD_NOT_FOUND};
+use crate::shared::utils::snowflake::Snowflake;
+
+pub struct AttachmentRepositoriesImpl<S: Snowflake + Send + Sync> {
+    db_conn: Arc<Pool>,
+    snowflake_id: S,
+}
+
+impl<S: Snowflake + Send + Sync> AttachmentRepositoriesImpl<S> {
+    pub fn new(db_conn: Arc<Pool>, snowflake_id: S) -> Self {
+        Self { db_conn, snowflake_id }
+    }
+}
+
+const ATTACHMENT_COLUMNS: &str = "id, task_id, file_name, content_type, size_bytes, storage_key, created_by, created_at";
+
+fn map_attachment(row: &Row) -> Attachment {
+    Attachment {
+        id: row.get("id"),
+        task_id: row.get("task_id"),
+        file_name: row.get("file_name"),
+        content_type: row.get("content_type"),
+        size_bytes: row.get("size_bytes"),
+        storage_key: row.get("storage_key"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl<S: Snowflake + Send + Sync> AttachmentRepositories for AttachmentRepositoriesImpl<S> {
+    async fn list_attachment(&self, task_id: i64) -> Result<Vec<Attachment>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let rows = client
+            .query(
+                format!("SELECT {} FROM public.task_attachment WHERE task_id = $1 ORDER BY id ASC;", ATTACHMENT_COLUMNS).as_str(),
+                &[&task_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(map_attachment).collect())
+    }
+
+    async fn get_attachment(&self, id: i64) -> Result<Attachment, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one(format!("SELECT {} FROM public.task_attachment WHERE id = $1;", ATTACHMENT_COLUMNS).as_str(), &[&id])
+            .await.map_err(|e| {
+            if e.to_string().contains(RECORD_NOT_FOUND) {
+                return CustomError::NotFound(format!("{}: {}", ATTACHMENT_NOT_FOUND, id));
+            }
+            CustomError::RepositoryError(format!("Database query failed: {}", e))
+        })?;
+
+        Ok(map_attachment(&row))
+    }
+
+    async fn create_attachment(&self, attachment: AttachmentCreateEntity) -> Result<i64, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let new_id = self.snowflake_id.generate() as i64;
+
+        client
+            .execute(
+                "INSERT INTO public.task_attachment (id, task_id, file_name, content_type, size_bytes, storage_key, created_by, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, NOW());",
+                &[
+                    &new_id,
+                    &attachment.task_id,
+                    &attachment.file_name,
+                    &attachment.content_type,
+                    &attachment.size_bytes,
+                    &attachment.storage_key,
+                    &attachment.created_by,
+                ],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(new_id)
+    }
+
+    async fn delete_attachment(&self, id: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute("DELETE FROM public.task_attachment WHERE id = $1;", &[&id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -1,29 +1,58 @@
 use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use deadpool_postgres::Pool;
-use crate::domain::entities::auth::User;
-use crate::domain::repositories::auth::AuthRepositories;
+use tokio_postgres::error::SqlState;
+use tokio_postgres::Row;
+use crate::domain::entities::auth::{AccessTokenStatus, RefreshToken, RefreshTokenCreate, Role, UpdateUser, User, UserCreateEntity, UserProfile};
+use crate::domain::repositories::auth::{AuthRepositories, TokenDenylistRepositories};
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::shared::exceptions::error_message::{RECORD_NOT_FOUND, USERNAME_NOT_FOUND};
+use crate::shared::exceptions::error_message::{RECORD_NOT_FOUND, USERNAME_ALREADY_EXISTS, USERNAME_NOT_FOUND, USER_NOT_FOUND};
+use crate::shared::utils::snowflake::Snowflake;
 
-pub struct AuthRepositoriesImpl {
+pub struct AuthRepositoriesImpl<S: Snowflake + Send + Sync> {
     db_conn: Arc<Pool>,
+    snowflake_id: S,
 }
 
-impl AuthRepositoriesImpl {
+impl<S: Snowflake + Send + Sync> AuthRepositoriesImpl<S> {
+    pub fn new(db_conn: Arc<Pool>, snowflake_id: S) -> Self {
+        Self { db_conn, snowflake_id }
+    }
+}
+
+pub struct TokenDenylistRepositoriesImpl {
+    db_conn: Arc<Pool>,
+}
+
+impl TokenDenylistRepositoriesImpl {
     pub fn new(db_conn: Arc<Pool>) -> Self {
         Self { db_conn }
     }
 }
 
+const USER_PROFILE_QUERY: &str = "SELECT u.id, u.username, u.role_id, r.code AS role_code, u.active, u.created_at, u.updated_at FROM public.users u LEFT JOIN public.master_data_role r ON r.id = u.role_id";
+
+fn map_user_profile(row: &Row) -> UserProfile {
+    UserProfile {
+        id: row.get("id"),
+        username: row.get("username"),
+        role_id: row.get("role_id"),
+        role: row.get("role_code"),
+        active: row.get("active"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
 #[async_trait]
-impl AuthRepositories for AuthRepositoriesImpl {
+impl<S: Snowflake + Send + Sync> AuthRepositories for AuthRepositoriesImpl<S> {
     async fn user_exists(&self, username: &str) -> Result<User, CustomError> {
         let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
 
         let row = client
             .query_one(
-                "SELECT id, username, password FROM public.users WHERE username = $1 LIMIT 1;",
+                "SELECT id, username, password, role_id, active FROM public.users WHERE username = $1 LIMIT 1;",
                 &[&username],
             )
             .await.map_err(|e| {
@@ -36,8 +65,272 @@ impl AuthRepositories for AuthRepositoriesImpl {
         let user = User {
             id: row.get("id"),
             password: row.get("password"),
+            role_id: row.get("role_id"),
+            active: row.get("active"),
+        };
+
+        Ok(user)
+    }
+
+    async fn get_user(&self, id: i64) -> Result<User, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_one(
+                "SELECT id, password, role_id, active FROM public.users WHERE id = $1;",
+                &[&id],
+            )
+            .await.map_err(|e| {
+            if e.to_string().contains(RECORD_NOT_FOUND) {
+                return CustomError::Unauthorized(format!("{}: {}", USER_NOT_FOUND, id));
+            }
+            CustomError::RepositoryError(format!("Database query failed: {}", e))
+        })?;
+
+        let user = User {
+            id: row.get("id"),
+            password: row.get("password"),
+            role_id: row.get("role_id"),
+            active: row.get("active"),
         };
 
         Ok(user)
     }
-}
\ No newline at end of file
+
+    async fn create_refresh_token(&self, token: RefreshTokenCreate) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                "INSERT INTO public.refresh_tokens (token_hash, user_id, family_id, expires_at, created_at) VALUES ($1, $2, $3, $4, NOW());",
+                &[&token.token_hash, &token.user_id, &token.family_id, &token.expires_at],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn get_refresh_token(&self, token_hash: &str) -> Result<Option<RefreshToken>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_opt(
+                "SELECT user_id, family_id, expires_at, revoked_at FROM public.refresh_tokens WHERE token_hash = $1;",
+                &[&token_hash],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.map(|row| RefreshToken {
+            user_id: row.get("user_id"),
+            family_id: row.get("family_id"),
+            expires_at: row.get("expires_at"),
+            revoked_at: row.get("revoked_at"),
+        }))
+    }
+
+    async fn rotate_refresh_token(&self, token_hash: &str, new_token: RefreshTokenCreate) -> Result<bool, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+        let transaction = client.transaction().await.map_err(|e| CustomError::RepositoryError(format!("Failed to begin transaction: {}", e)))?;
+
+        // update แบบมีเงื่อนไข ถ้ามี request อื่นใช้ token นี้ไปก่อนจะได้ 0 แถว
+        let updated = transaction
+            .execute(
+                "UPDATE public.refresh_tokens SET revoked_at = NOW(), replaced_by = $1 WHERE token_hash = $2 AND revoked_at IS NULL;",
+                &[&new_token.token_hash, &token_hash],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        if updated == 0 {
+            return Ok(false);
+        }
+
+        transaction
+            .execute(
+                "INSERT INTO public.refresh_tokens (token_hash, user_id, family_id, expires_at, created_at) VALUES ($1, $2, $3, $4, NOW());",
+                &[&new_token.token_hash, &new_token.user_id, &new_token.family_id, &new_token.expires_at],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        transaction.commit().await.map_err(|e| CustomError::RepositoryError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(true)
+    }
+
+    async fn revoke_refresh_token_family(&self, family_id: &str) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                "UPDATE public.refresh_tokens SET revoked_at = NOW() WHERE family_id = $1 AND revoked_at IS NULL;",
+                &[&family_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_access_token(&self, jti: &str, user_id: i64, expires_at: NaiveDateTime) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                "INSERT INTO public.revoked_tokens (jti, user_id, expires_at, created_at) VALUES ($1, $2, $3, NOW()) ON CONFLICT (jti) DO NOTHING;",
+                &[&jti, &user_id, &expires_at],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        // token ที่หมดอายุแล้วใช้งานไม่ได้อยู่แล้ว ไม่ต้องเก็บใน denylist
+        client
+            .execute("DELETE FROM public.revoked_tokens WHERE expires_at < NOW();", &[])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_user_refresh_tokens(&self, user_id: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                "UPDATE public.refresh_tokens SET revoked_at = NOW() WHERE user_id = $1 AND revoked_at IS NULL;",
+                &[&user_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn list_users(&self) -> Result<Vec<UserProfile>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let rows = client
+            .query(format!("{} ORDER BY u.username ASC;", USER_PROFILE_QUERY).as_str(), &[])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(map_user_profile).collect())
+    }
+
+    async fn get_user_profile(&self, id: i64) -> Result<UserProfile, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_one(format!("{} WHERE u.id = $1;", USER_PROFILE_QUERY).as_str(), &[&id])
+            .await.map_err(|e| {
+            if e.to_string().contains(RECORD_NOT_FOUND) {
+                return CustomError::NotFound(format!("{}: {}", USER_NOT_FOUND, id));
+            }
+            CustomError::RepositoryError(format!("Database query failed: {}", e))
+        })?;
+
+        Ok(map_user_profile(&row))
+    }
+
+    async fn username_exists(&self, username: &str) -> Result<bool, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_one(
+                "SELECT (COUNT(id) > 0) as is_already_exists FROM public.users WHERE username = $1;",
+                &[&username],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.get::<_, bool>("is_already_exists"))
+    }
+
+    async fn create_user(&self, user: UserCreateEntity) -> Result<i64, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+        let new_id = self.snowflake_id.generate() as i64;
+
+        let row = client
+            .query_one(
+                "INSERT INTO public.users (id, username, password, role_id, active, created_by, created_at) VALUES ($1, $2, $3, $4, TRUE, $5, NOW()) RETURNING id;",
+                &[&new_id, &user.username, &user.password, &user.role_id, &user.created_by],
+            )
+            .await
+            .map_err(|e| match e.code() {
+                // กรณีสมัครพร้อมกันด้วย username เดียวกัน ผ่านการตรวจซ้ำมาแล้วแต่ชน unique constraint
+                Some(&SqlState::UNIQUE_VIOLATION) => CustomError::DataConflict(format!("{}: {}", USERNAME_ALREADY_EXISTS, user.username)),
+                _ => CustomError::RepositoryError(format!("Database query failed: {}", e)),
+            })?;
+
+        Ok(row.get(0))
+    }
+
+    async fn update_user(&self, user: UpdateUser) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                "UPDATE public.users
+                 SET role_id = $1,
+                     active = $2,
+                     updated_at = NOW(),
+                     updated_by = $3
+                 WHERE id = $4;",
+                &[&user.role_id, &user.active, &user.updated_by, &user.id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn update_password(&self, id: i64, password: String, updated_by: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                "UPDATE public.users
+                 SET password = $1,
+                     updated_at = NOW(),
+                     updated_by = $2
+                 WHERE id = $3;",
+                &[&password, &updated_by, &id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TokenDenylistRepositories for TokenDenylistRepositoriesImpl {
+    async fn access_token_status(&self, jti: &str, user_id: i64, role: Role) -> Result<AccessTokenStatus, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_one(
+                "SELECT
+                    EXISTS (SELECT 1 FROM public.revoked_tokens WHERE jti = $1) AS is_revoked,
+                    EXISTS (
+                        SELECT 1
+                        FROM public.users u
+                        JOIN public.master_data_role r ON r.id = u.role_id
+                        WHERE u.id = $2 AND u.active AND r.code = $3
+                    ) AS is_valid_user;",
+                &[&jti, &user_id, &role.code()],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(if row.get::<_, bool>("is_revoked") {
+            AccessTokenStatus::Revoked
+        } else if !row.get::<_, bool>("is_valid_user") {
+            AccessTokenStatus::UserChanged
+        } else {
+            AccessTokenStatus::Valid
+        })
+    }
+}
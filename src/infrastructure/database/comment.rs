@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use deadpool_postgres::Pool;
+use std::sync::Arc;
+use tokio_postgres::Row;
+use crate::domain::entities::comment::{Comment, CommentCreateEntity, CommentFilter, CommentPage, UpdateComment};
+use crate::domain::entities::task::TaskCursor;
+use crate::domain::repositories::comment::CommentRepositories;
+use crate::infrastructure::database::query_builder::QueryBuilder;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{COMMENT_NOT_FOUND, RECORD_NOT_FOUND};
+use crate::shared::utils::snowflake::Snowflake;
+
+pub struct CommentRepositoriesImpl<S: Snowflake + Send + Sync> {
+    db_conn: Arc<Pool>,
+    snowflake_id: S,
+}
+
+impl<S: Snowflake + Send + Sync> CommentRepositoriesImpl<S> {
+    pub fn new(db_conn: Arc<Pool>, snowflake_id: S) -> Self {
+        Self { db_conn, snowflake_id }
+    }
+}
+
+const COMMENT_COLUMNS: &str = "id, task_id, body, created_by, created_at, updated_at";
+
+fn map_comment(row: &Row) -> Comment {
+    Comment {
+        id: row.get("id"),
+        task_id: row.get("task_id"),
+        body: row.get("body"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+#[async_trait]
+impl<S: Snowflake + Send + Sync> CommentRepositories for CommentRepositoriesImpl<S> {
+    async fn list_comment(&self, filter: CommentFilter) -> Result<CommentPage, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let mut query = QueryBuilder::new();
+        let task_id = query.bind(filter.task_id);
+        query.and_where(format!("task_id = {}", task_id));
+        if let Some(cursor) = &filter.cursor {
+            let id = query.bind(cursor.id);
+            query.and_where(format!("id > {}", id));
+        }
+
+        // snowflake id เรียงตามเวลาที่สร้าง เก่าสุดก่อน ดึงเกิน 1 แถวเพื่อดูว่ายังมีหน้าถัดไปหรือไม่
+        let sql = format!(
+            "SELECT {} FROM public.task_comment{} ORDER BY id ASC LIMIT {};",
+            COMMENT_COLUMNS,
+            query.where_clause(),
+            filter.limit + 1,
+        );
+
+        let rows = client
+            .query(sql.as_str(), &query.params())
+            .await.map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let mut items: Vec<Comment> = rows.iter().map(map_comment).collect();
+
+        let has_more = items.len() as i64 > filter.limit;
+        items.truncate(filter.limit as usize);
+
+        let next_cursor = if has_more {
+            items.last().map(|comment| TaskCursor { id: comment.id, value: None }.encode())
+        } else {
+            None
+        };
+
+        Ok(CommentPage {
+            items,
+            next_cursor,
+            has_more,
+        })
+    }
+
+    async fn get_comment(&self, id: i64) -> Result<Comment, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one(format!("SELECT {} FROM public.task_comment WHERE id = $1;", COMMENT_COLUMNS).as_str(), &[&id])
+            .await.map_err(|e| {
+            if e.to_string().contains(RECORD_NOT_FOUND) {
+                return CustomError::NotFound(format!("{}: {}", COMMENT_NOT_FOUND, id));
+            }
+            CustomError::RepositoryError(format!("Database query failed: {}", e))
+        })?;
+
+        Ok(map_comment(&row))
+    }
+
+    async fn create_comment(&self, comment: CommentCreateEntity) -> Result<i64, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let new_id = self.snowflake_id.generate() as i64;
+
+        client
+            .execute(
+                "INSERT INTO public.task_comment (id, task_id, body, created_by, created_at) VALUES ($1, $2, $3, $4, NOW());",
+                &[&new_id, &comment.task_id, &comment.body, &comment.created_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(new_id)
+    }
+
+    async fn update_comment(&self, comment: UpdateComment) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute(
+                "UPDATE public.task_comment SET body = $1, updated_at = NOW() WHERE id = $2;",
+                &[&comment.body, &comment.id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete_comment(&self, id: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute("DELETE FROM public.task_comment WHERE id = $1;", &[&id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+}
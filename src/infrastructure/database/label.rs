@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use deadpool_postgres::Pool;
+use std::sync::Arc;
+use tokio_postgres::error::SqlState;
+use tokio_postgres::Row;
+use crate::domain::entities::label::{Label, LabelCreateEntity, UpdateLabel};
+use crate::domain::repositories::label::LabelRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{LABEL_ALREADY_EXISTS, LABEL_NOT_FOUND, RECORD_NOT_FOUND};
+use crate::shared::utils::snowflake::Snowflake;
+
+pub struct LabelRepositoriesImpl<S: Snowflake + Send + Sync> {
+    db_conn: Arc<Pool>,
+    snowflake_id: S,
+}
+
+impl<S: Snowflake + Send + Sync> LabelRepositoriesImpl<S> {
+    pub fn new(db_conn: Arc<Pool>, snowflake_id: S) -> Self {
+        Self { db_conn, snowflake_id }
+    }
+}
+
+const LABEL_COLUMNS: &str = "id, name, color, created_by, created_at, updated_at, updated_by";
+
+fn map_label(row: &Row) -> Label {
+    Label {
+        id: row.get("id"),
+        name: row.get("name"),
+        color: row.get("color"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        updated_by: row.get("updated_by"),
+    }
+}
+
+#[async_trait]
+impl<S: Snowflake + Send + Sync> LabelRepositories for LabelRepositoriesImpl<S> {
+    async fn list_label(&self) -> Result<Vec<Label>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let rows = client
+            .query(format!("SELECT {} FROM public.label ORDER BY lower(name) ASC, id ASC;", LABEL_COLUMNS).as_str(), &[])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(map_label).collect())
+    }
+
+    async fn get_label(&self, id: i64) -> Result<Label, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one(format!("SELECT {} FROM public.label WHERE id = $1;", LABEL_COLUMNS).as_str(), &[&id])
+            .await.map_err(|e| {
+            if e.to_string().contains(RECORD_NOT_FOUND) {
+                return CustomError::NotFound(format!("{}: {}", LABEL_NOT_FOUND, id));
+            }
+            CustomError::RepositoryError(format!("Database query failed: {}", e))
+        })?;
+
+        Ok(map_label(&row))
+    }
+
+    async fn label_name_exists(&self, name: String, exclude_id: Option<i64>) -> Result<bool, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one(
+                "SELECT EXISTS (SELECT 1 FROM public.label WHERE lower(name) = lower($1) AND id IS DISTINCT FROM $2) AS name_exists;",
+                &[&name, &exclude_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.get("name_exists"))
+    }
+
+    async fn create_label(&self, label: LabelCreateEntity) -> Result<i64, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let new_id = self.snowflake_id.generate() as i64;
+
+        client
+            .execute(
+                "INSERT INTO public.label (id, name, color, created_by, created_at) VALUES ($1, $2, $3, $4, NOW());",
+                &[&new_id, &label.name, &label.color, &label.created_by],
+            )
+            .await
+            .map_err(|e| match e.code() {
+                // สร้างชื่อเดียวกันพร้อมกัน ผ่านการตรวจซ้ำมาแล้วแต่ชน unique index ของชื่อ
+                Some(&SqlState::UNIQUE_VIOLATION) => CustomError::DataConflict(format!("{}: {}", LABEL_ALREADY_EXISTS, label.name)),
+                _ => CustomError::RepositoryError(format!("Database query failed: {}", e)),
+            })?;
+
+        Ok(new_id)
+    }
+
+    async fn update_label(&self, label: UpdateLabel) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute(
+                "UPDATE public.label
+                 SET name = $1,
+                     color = $2,
+                     updated_at = NOW(),
+                     updated_by = $3
+                 WHERE id = $4;",
+                &[&label.name, &label.color, &label.updated_by, &label.id],
+            )
+            .await
+            .map_err(|e| match e.code() {
+                Some(&SqlState::UNIQUE_VIOLATION) => CustomError::DataConflict(format!("{}: {}", LABEL_ALREADY_EXISTS, label.name)),
+                _ => CustomError::RepositoryError(format!("Database query failed: {}", e)),
+            })?;
+
+        Ok(())
+    }
+
+    async fn delete_label(&self, id: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute("DELETE FROM public.label WHERE id = $1;", &[&id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+}
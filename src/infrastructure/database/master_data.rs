@@ -2,9 +2,19 @@
 use async_trait::async_trait;
 use deadpool_postgres::Pool;
 use std::sync::Arc;
-use crate::domain::entities::master_data::{MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus};
-use crate::domain::repositories::master_data::MasterDataRepositories;
+use serde_json::json;
+use tokio_postgres::error::SqlState;
+use tokio_postgres::Row;
+use crate::domain::entities::master_data::{
+    MasterDataCreateEntity, MasterDataDeactivate, MasterDataItem, MasterDataKind, MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus,
+    MasterDataTaskStatusTransition, UpdateMasterData,
+};
+use crate::domain::entities::task::{TaskFieldChange, TaskHistoryOperation};
+use crate::domain::repositories::master_data::{MasterDataAdminRepositories, MasterDataRepositories};
+use crate::infrastructure::database::master_data_cache::{MasterDataCache, MASTER_DATA_CHANGED_CHANNEL};
 use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{MASTER_DATA_CODE_ALREADY_EXISTS, MASTER_DATA_IN_USE, MASTER_DATA_NOT_FOUND, RECORD_NOT_FOUND};
+use crate::shared::utils::snowflake::Snowflake;
 
 pub struct MasterDataRepositoriesImpl {
     db_conn: Arc<Pool>,
@@ -23,7 +33,7 @@ impl MasterDataRepositories for MasterDataRepositoriesImpl {
 
         let rows = client
             .query(
-                "SELECT id, title, code FROM public.master_data_task_status WHERE active IS TRUE;",
+                "SELECT id, title, code FROM public.master_data_task_status WHERE active IS TRUE ORDER BY seq ASC;",
                 &[],
             )
             .await
@@ -45,7 +55,7 @@ impl MasterDataRepositories for MasterDataRepositoriesImpl {
         let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
         let rows = client
             .query(
-                "SELECT id, title, code FROM public.master_data_role WHERE active IS TRUE;",
+                "SELECT id, title, code FROM public.master_data_role WHERE active IS TRUE ORDER BY seq ASC;",
                 &[],
             )
             .await
@@ -84,4 +94,341 @@ impl MasterDataRepositories for MasterDataRepositoriesImpl {
 
         Ok(items)
     }
-}
\ No newline at end of file
+
+    async fn list_task_status_transition(&self, from_status_id: i64) -> Result<Vec<MasterDataTaskStatusTransition>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+        let rows = client
+            .query(
+                "SELECT t.from_status_id, t.to_status_id, s.title, s.code, r.code AS role_code
+                 FROM public.master_data_task_status_transition t
+                 INNER JOIN public.master_data_task_status s ON s.id = t.to_status_id AND s.active IS TRUE
+                 LEFT JOIN public.master_data_role r ON r.id = t.role_id
+                 WHERE t.from_status_id = $1 AND t.active IS TRUE
+                 ORDER BY t.to_status_id ASC, r.code ASC;",
+                &[&from_status_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let items: Vec<MasterDataTaskStatusTransition> = rows
+            .iter()
+            .map(|row| MasterDataTaskStatusTransition {
+                from_status_id: row.get("from_status_id"),
+                to_status_id: row.get("to_status_id"),
+                title: row.get("title"),
+                code: row.get("code"),
+                role_code: row.get("role_code"),
+            })
+            .collect();
+
+        Ok(items)
+    }
+}
+
+pub struct MasterDataAdminRepositoriesImpl<S: Snowflake + Send + Sync> {
+    db_conn: Arc<Pool>,
+    snowflake_id: S,
+    cache: Arc<MasterDataCache>,
+}
+
+impl<S: Snowflake + Send + Sync> MasterDataAdminRepositoriesImpl<S> {
+    pub fn new(db_conn: Arc<Pool>, snowflake_id: S, cache: Arc<MasterDataCache>) -> Self {
+        Self { db_conn, snowflake_id, cache }
+    }
+}
+
+// แจ้ง instance อื่นให้ล้าง cache (ถ้าอยู่ใน transaction จะส่งเมื่อ commit)
+const NOTIFY_MASTER_DATA_CHANGED: &str = "SELECT pg_notify($1, $2);";
+
+fn master_data_table(kind: MasterDataKind) -> &'static str {
+    match kind {
+        MasterDataKind::TaskStatus => "public.master_data_task_status",
+        MasterDataKind::PriorityLevels => "public.master_data_priority_levels",
+        MasterDataKind::Role => "public.master_data_role",
+    }
+}
+
+// column ของ task ที่อ้างอิง master data (role ไม่ได้ผูกกับ task)
+fn task_reference_column(kind: MasterDataKind) -> Option<&'static str> {
+    match kind {
+        MasterDataKind::TaskStatus => Some("task_status_id"),
+        MasterDataKind::PriorityLevels => Some("priority_levels_id"),
+        MasterDataKind::Role => None,
+    }
+}
+
+const MASTER_DATA_COLUMNS: &str = "id, seq, title, code, active, created_by, created_at, updated_at, updated_by";
+
+fn map_master_data_item(row: &Row) -> MasterDataItem {
+    MasterDataItem {
+        id: row.get("id"),
+        seq: row.get("seq"),
+        title: row.get("title"),
+        code: row.get("code"),
+        active: row.get("active"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        updated_by: row.get("updated_by"),
+    }
+}
+
+#[async_trait]
+impl<S: Snowflake + Send + Sync> MasterDataAdminRepositories for MasterDataAdminRepositoriesImpl<S> {
+    async fn list_master_data(&self, kind: MasterDataKind) -> Result<Vec<MasterDataItem>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let rows = client
+            .query(format!("SELECT {} FROM {} ORDER BY seq ASC;", MASTER_DATA_COLUMNS, master_data_table(kind)).as_str(), &[])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(map_master_data_item).collect())
+    }
+
+    async fn get_master_data(&self, kind: MasterDataKind, id: i64) -> Result<MasterDataItem, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_one(format!("SELECT {} FROM {} WHERE id = $1;", MASTER_DATA_COLUMNS, master_data_table(kind)).as_str(), &[&id])
+            .await
+            .map_err(|e| {
+                if e.to_string().contains(RECORD_NOT_FOUND) {
+                    return CustomError::NotFound(format!("{}: {}", MASTER_DATA_NOT_FOUND, id));
+                }
+                CustomError::RepositoryError(format!("Database query failed: {}", e))
+            })?;
+
+        Ok(map_master_data_item(&row))
+    }
+
+    async fn master_data_code_exists(&self, kind: MasterDataKind, code: String) -> Result<bool, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        let row = client
+            .query_one(format!("SELECT EXISTS (SELECT 1 FROM {} WHERE code = $1) AS code_exists;", master_data_table(kind)).as_str(), &[&code])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.get("code_exists"))
+    }
+
+    async fn create_master_data(&self, kind: MasterDataKind, item: MasterDataCreateEntity) -> Result<i64, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+        let new_id = self.snowflake_id.generate() as i64;
+
+        // lock ตารางกันการเพิ่มหรือจัดลำดับพร้อมกันได้ seq ซ้ำ (ยังอ่านได้ตามปกติ)
+        transaction
+            .execute(format!("LOCK TABLE {} IN SHARE ROW EXCLUSIVE MODE;", master_data_table(kind)).as_str(), &[])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        transaction
+            .execute(
+                format!(
+                    "INSERT INTO {0} (id, seq, title, code, active, created_by, created_at)
+                     SELECT $1, COALESCE(MAX(seq), 0) + 1, $2, $3, TRUE, $4, NOW() FROM {0};",
+                    master_data_table(kind),
+                ).as_str(),
+                &[&new_id, &item.title, &item.code, &item.created_by],
+            )
+            .await
+            .map_err(|e| match e.code() {
+                // เพิ่ม code เดียวกันพร้อมกัน ผ่านการตรวจซ้ำมาแล้วแต่ชน unique constraint
+                Some(&SqlState::UNIQUE_VIOLATION) => CustomError::DataConflict(format!("{}: {}", MASTER_DATA_CODE_ALREADY_EXISTS, item.code)),
+                _ => CustomError::RepositoryError(format!("Database query failed: {}", e)),
+            })?;
+
+        transaction
+            .execute(NOTIFY_MASTER_DATA_CHANGED, &[&MASTER_DATA_CHANGED_CHANNEL, &kind.code()])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+        self.cache.invalidate(kind);
+
+        Ok(new_id)
+    }
+
+    async fn update_master_data(&self, kind: MasterDataKind, item: UpdateMasterData) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                format!("UPDATE {} SET title = $1, updated_at = NOW(), updated_by = $2 WHERE id = $3;", master_data_table(kind)).as_str(),
+                &[&item.title, &item.updated_by, &item.id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        client
+            .execute(NOTIFY_MASTER_DATA_CHANGED, &[&MASTER_DATA_CHANGED_CHANNEL, &kind.code()])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        self.cache.invalidate(kind);
+
+        Ok(())
+    }
+
+    async fn activate_master_data(&self, kind: MasterDataKind, id: i64, updated_by: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+        client
+            .execute(
+                format!("UPDATE {} SET active = TRUE, updated_at = NOW(), updated_by = $1 WHERE id = $2 AND active IS FALSE;", master_data_table(kind)).as_str(),
+                &[&updated_by, &id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        client
+            .execute(NOTIFY_MASTER_DATA_CHANGED, &[&MASTER_DATA_CHANGED_CHANNEL, &kind.code()])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        self.cache.invalidate(kind);
+
+        Ok(())
+    }
+
+    async fn deactivate_master_data(&self, kind: MasterDataKind, deactivate: MasterDataDeactivate) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        transaction
+            .execute(format!("SELECT id FROM {} WHERE id = $1 FOR UPDATE;", master_data_table(kind)).as_str(), &[&deactivate.id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        if let Some(replacement_id) = deactivate.replacement_id {
+            match task_reference_column(kind) {
+                Some(column) => {
+                    // task ในถังขยะย้ายด้วย เพื่อไม่ให้กู้คืนกลับมาพร้อมค่าที่ปิดใช้งานแล้ว
+                    let rows = transaction
+                        .query(
+                            format!(
+                                "UPDATE public.task SET {0} = $2, version = version + 1, updated_at = NOW(), updated_by = $3 WHERE {0} = $1 RETURNING id;",
+                                column,
+                            ).as_str(),
+                            &[&deactivate.id, &replacement_id, &deactivate.updated_by],
+                        )
+                        .await
+                        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+                    // บันทึกประวัติของแต่ละ task เหมือนการแก้ไขปกติ
+                    let task_ids: Vec<i64> = rows.iter().map(|row| row.get("id")).collect();
+                    let history_ids: Vec<i64> = task_ids.iter().map(|_| self.snowflake_id.generate() as i64).collect();
+                    let changes = serde_json::to_value(vec![TaskFieldChange {
+                        field: column.to_string(),
+                        old: json!(deactivate.id),
+                        new: json!(replacement_id),
+                    }])
+                    .map_err(|e| CustomError::RepositoryError(format!("Failed to serialize task history: {}", e)))?;
+
+                    transaction
+                        .execute(
+                            "INSERT INTO public.task_history (id, task_id, operation, changes, actor_id, created_at)
+                             SELECT history_id, task_id, $3, $4, $5, NOW() FROM UNNEST($1::bigint[], $2::bigint[]) AS h(history_id, task_id);",
+                            &[&history_ids, &task_ids, &TaskHistoryOperation::Update.code(), &changes, &deactivate.updated_by],
+                        )
+                        .await
+                        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+                }
+                None => {
+                    transaction
+                        .execute(
+                            "UPDATE public.users SET role_id = $2, updated_at = NOW(), updated_by = $3 WHERE role_id = $1;",
+                            &[&deactivate.id, &replacement_id, &deactivate.updated_by],
+                        )
+                        .await
+                        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+                    transaction
+                        .execute("UPDATE public.project_member SET role_id = $2 WHERE role_id = $1;", &[&deactivate.id, &replacement_id])
+                        .await
+                        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+                }
+            }
+        }
+
+        // นับใน transaction เดียวกับการปิดใช้งาน กันข้อมูลที่อ้างอิงเข้ามาระหว่างตรวจสอบ
+        let usage_sql = match task_reference_column(kind) {
+            Some(column) => format!("SELECT COUNT(*) AS usage FROM public.task WHERE {} = $1;", column),
+            None => "SELECT (SELECT COUNT(*) FROM public.users WHERE role_id = $1) + (SELECT COUNT(*) FROM public.project_member WHERE role_id = $1) AS usage;".to_string(),
+        };
+        let usage: i64 = transaction
+            .query_one(usage_sql.as_str(), &[&deactivate.id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?
+            .get("usage");
+        if usage > 0 {
+            return Err(CustomError::DataConflict(format!("{}: {}", MASTER_DATA_IN_USE, usage)));
+        }
+
+        transaction
+            .execute(
+                format!("UPDATE {} SET active = FALSE, updated_at = NOW(), updated_by = $1 WHERE id = $2;", master_data_table(kind)).as_str(),
+                &[&deactivate.updated_by, &deactivate.id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        transaction
+            .execute(NOTIFY_MASTER_DATA_CHANGED, &[&MASTER_DATA_CHANGED_CHANNEL, &kind.code()])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+        self.cache.invalidate(kind);
+
+        Ok(())
+    }
+
+    async fn reorder_master_data(&self, kind: MasterDataKind, ids: Vec<i64>, updated_by: i64) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        // lock แบบเดียวกับตอนเพิ่มรายการ กันไม่ให้ได้ seq ชนกับการเพิ่มที่ทำพร้อมกัน
+        transaction
+            .execute(format!("LOCK TABLE {} IN SHARE ROW EXCLUSIVE MODE;", master_data_table(kind)).as_str(), &[])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        // seq เป็น UNIQUE จึงย้ายไปค่าติดลบก่อนแล้วค่อยกำหนดลำดับใหม่
+        transaction
+            .execute(format!("UPDATE {} SET seq = -seq WHERE id = ANY($1);", master_data_table(kind)).as_str(), &[&ids])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        transaction
+            .execute(
+                format!(
+                    "UPDATE {} m SET seq = o.seq, updated_at = NOW(), updated_by = $2
+                     FROM UNNEST($1::bigint[]) WITH ORDINALITY AS o(id, seq)
+                     WHERE m.id = o.id;",
+                    master_data_table(kind),
+                ).as_str(),
+                &[&ids, &updated_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        transaction
+            .execute(NOTIFY_MASTER_DATA_CHANGED, &[&MASTER_DATA_CHANGED_CHANNEL, &kind.code()])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+        self.cache.invalidate(kind);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use crate::domain::entities::master_data::{MasterDataKind, MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus, MasterDataTaskStatusTransition};
+use crate::domain::repositories::master_data::MasterDataRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+
+// channel ของ Postgres LISTEN/NOTIFY ที่ใช้แจ้งทุก instance ให้ล้าง cache (payload คือ kind เช่น task-status)
+pub const MASTER_DATA_CHANGED_CHANNEL: &str = "master_data_changed";
+
+struct CacheEntry<T> {
+    items: Arc<Vec<T>>,
+    loaded_at: Instant,
+}
+
+struct CacheState<T> {
+    entry: Option<CacheEntry<T>>,
+    // ค่าที่เริ่มโหลดก่อนเวลานี้ถือว่าเก่า ไม่นำมาเก็บ
+    invalidated_at: Option<Instant>,
+}
+
+type CacheSlot<T> = RwLock<CacheState<T>>;
+
+fn empty_slot<T>() -> CacheSlot<T> {
+    RwLock::new(CacheState { entry: None, invalidated_at: None })
+}
+
+// cache ของ master data ที่ active ใช้ร่วมกันทุก repository ใน instance เดียวกัน
+pub struct MasterDataCache {
+    // ttl = 0 คือไม่ cache
+    ttl: Duration,
+    task_status: CacheSlot<MasterDataTaskStatus>,
+    role: CacheSlot<MasterDataRole>,
+    priority_levels: CacheSlot<MasterDataPriorityLevels>,
+}
+
+impl MasterDataCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            task_status: empty_slot(),
+            role: empty_slot(),
+            priority_levels: empty_slot(),
+        }
+    }
+
+    pub fn invalidate(&self, kind: MasterDataKind) {
+        match kind {
+            MasterDataKind::TaskStatus => clear(&self.task_status),
+            MasterDataKind::Role => clear(&self.role),
+            MasterDataKind::PriorityLevels => clear(&self.priority_levels),
+        }
+    }
+
+    pub fn invalidate_all(&self) {
+        self.invalidate(MasterDataKind::TaskStatus);
+        self.invalidate(MasterDataKind::Role);
+        self.invalidate(MasterDataKind::PriorityLevels);
+    }
+
+    fn get<T>(&self, slot: &CacheSlot<T>) -> Option<Arc<Vec<T>>> {
+        let state = slot.read().unwrap_or_else(|e| e.into_inner());
+        state
+            .entry
+            .as_ref()
+            .filter(|entry| entry.loaded_at.elapsed() < self.ttl)
+            .map(|entry| Arc::clone(&entry.items))
+    }
+
+    fn put<T>(&self, slot: &CacheSlot<T>, items: Arc<Vec<T>>, loaded_at: Instant) {
+        let mut state = slot.write().unwrap_or_else(|e| e.into_inner());
+        // ข้อมูลถูกแก้ไขระหว่างโหลด หรือมีค่าที่โหลดมาใหม่กว่าแล้ว (โหลดพร้อมกันหลาย request)
+        if state.invalidated_at.is_some_and(|invalidated_at| invalidated_at >= loaded_at)
+            || state.entry.as_ref().is_some_and(|entry| entry.loaded_at > loaded_at)
+        {
+            return;
+        }
+        state.entry = Some(CacheEntry { items, loaded_at });
+    }
+}
+
+fn clear<T>(slot: &CacheSlot<T>) {
+    let mut state = slot.write().unwrap_or_else(|e| e.into_inner());
+    state.entry = None;
+    state.invalidated_at = Some(Instant::now());
+}
+
+// ครอบ MasterDataRepositories เพื่อ cache รายการ active ตาม TTL
+pub struct CachedMasterDataRepositories<R: MasterDataRepositories> {
+    repository: R,
+    cache: Arc<MasterDataCache>,
+}
+
+impl<R: MasterDataRepositories> CachedMasterDataRepositories<R> {
+    pub fn new(repository: R, cache: Arc<MasterDataCache>) -> Self {
+        Self { repository, cache }
+    }
+
+    async fn load<T: Clone, F>(&self, slot: &CacheSlot<T>, fetch: F) -> Result<Vec<T>, CustomError>
+    where
+        F: std::future::Future<Output = Result<Vec<T>, CustomError>>,
+    {
+        if let Some(items) = self.cache.get(slot) {
+            return Ok(items.as_ref().clone());
+        }
+
+        // จดเวลาก่อน query เพื่อเทียบกับเวลาที่ถูก invalidate
+        let loaded_at = Instant::now();
+        let items = fetch.await?;
+        if !self.cache.ttl.is_zero() {
+            self.cache.put(slot, Arc::new(items.clone()), loaded_at);
+        }
+
+        Ok(items)
+    }
+}
+
+#[async_trait]
+impl<R: MasterDataRepositories> MasterDataRepositories for CachedMasterDataRepositories<R> {
+    async fn list_task_status(&self) -> Result<Vec<MasterDataTaskStatus>, CustomError> {
+        self.load(&self.cache.task_status, self.repository.list_task_status()).await
+    }
+
+    async fn list_role(&self) -> Result<Vec<MasterDataRole>, CustomError> {
+        self.load(&self.cache.role, self.repository.list_role()).await
+    }
+
+    async fn list_priority_levels(&self) -> Result<Vec<MasterDataPriorityLevels>, CustomError> {
+        self.load(&self.cache.priority_levels, self.repository.list_priority_levels()).await
+    }
+
+    async fn list_task_status_transition(&self, from_status_id: i64) -> Result<Vec<MasterDataTaskStatusTransition>, CustomError> {
+        self.repository.list_task_status_transition(from_status_id).await
+    }
+}
\ No newline at end of file
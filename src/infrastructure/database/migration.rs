@@ -0,0 +1,298 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use deadpool_postgres::{Object, Pool};
+use crate::shared::exceptions::custom_error::CustomError;
+
+// key ของ advisory lock สำหรับ migration (ค่าคงที่ เพื่อให้ทุก replica ล็อกตัวเดียวกัน)
+const MIGRATION_LOCK_KEY: i64 = 7_250_000_000_000_000_001;
+
+// ตารางเก็บ version ใช้โครงสร้างเดียวกับ golang-migrate เพื่อให้ database เดิมใช้ต่อได้
+const CREATE_SCHEMA_MIGRATIONS: &str =
+    "CREATE TABLE IF NOT EXISTS public.schema_migrations (version bigint PRIMARY KEY NOT NULL, dirty boolean NOT NULL);";
+
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+// รายการ migration ทั้งหมด เรียงตาม version (ฝังไว้ใน binary)
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "init_schema",
+        up: include_str!("migrations/000001_init_schema.up.sql"),
+        down: include_str!("migrations/000001_init_schema.down.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "auth_token",
+        up: include_str!("migrations/000002_auth_token.up.sql"),
+        down: include_str!("migrations/000002_auth_token.down.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "user_active",
+        up: include_str!("migrations/000003_user_active.up.sql"),
+        down: include_str!("migrations/000003_user_active.down.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "task_assignee",
+        up: include_str!("migrations/000004_task_assignee.up.sql"),
+        down: include_str!("migrations/000004_task_assignee.down.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "task_schedule",
+        up: include_str!("migrations/000005_task_schedule.up.sql"),
+        down: include_str!("migrations/000005_task_schedule.down.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "task_status_transition",
+        up: include_str!("migrations/000006_task_status_transition.up.sql"),
+        down: include_str!("migrations/000006_task_status_transition.down.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "task_history",
+        up: include_str!("migrations/000007_task_history.up.sql"),
+        down: include_str!("migrations/000007_task_history.down.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "task_soft_delete",
+        up: include_str!("migrations/000008_task_soft_delete.up.sql"),
+        down: include_str!("migrations/000008_task_soft_delete.down.sql"),
+    },
+    Migration {
+        version: 9,
+        name: "task_version",
+        up: include_str!("migrations/000009_task_version.up.sql"),
+        down: include_str!("migrations/000009_task_version.down.sql"),
+    },
+    Migration {
+        version: 10,
+        name: "project",
+        up: include_str!("migrations/000010_project.up.sql"),
+        down: include_str!("migrations/000010_project.down.sql"),
+    },
+    Migration {
+        version: 11,
+        name: "task_parent",
+        up: include_str!("migrations/000011_task_parent.up.sql"),
+        down: include_str!("migrations/000011_task_parent.down.sql"),
+    },
+    Migration {
+        version: 12,
+        name: "task_dependency",
+        up: include_str!("migrations/000012_task_dependency.up.sql"),
+        down: include_str!("migrations/000012_task_dependency.down.sql"),
+    },
+    Migration {
+        version: 13,
+        name: "task_comment",
+        up: include_str!("migrations/000013_task_comment.up.sql"),
+        down: include_str!("migrations/000013_task_comment.down.sql"),
+    },
+    Migration {
+        version: 14,
+        name: "task_attachment",
+        up: include_str!("migrations/000014_task_attachment.up.sql"),
+        down: include_str!("migrations/000014_task_attachment.down.sql"),
+    },
+    Migration {
+        version: 15,
+        name: "label",
+        up: include_str!("migrations/000015_label.up.sql"),
+        down: include_str!("migrations/000015_label.down.sql"),
+    },
+    Migration {
+        version: 16,
+        name: "task_search",
+        up: include_str!("migrations/000016_task_search.up.sql"),
+        down: include_str!("migrations/000016_task_search.down.sql"),
+    },
+    Migration {
+        version: 17,
+        name: "master_data_seq",
+        up: include_str!("migrations/000017_master_data_seq.up.sql"),
+        down: include_str!("migrations/000017_master_data_seq.down.sql"),
+    },
+];
+
+#[derive(Debug, PartialEq)]
+pub enum MigrationCommand {
+    Up,
+    Down,
+    Status,
+}
+
+impl FromStr for MigrationCommand {
+    type Err = CustomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "up" => Ok(MigrationCommand::Up),
+            "down" => Ok(MigrationCommand::Down),
+            "status" => Ok(MigrationCommand::Status),
+            _ => Err(CustomError::ValidationError(format!("Unknown migrate command: {} (expected up|down|status)", s))),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MigrationStatus {
+    pub version: Option<i64>,
+    pub dirty: bool,
+    pub pending: Vec<i64>,
+}
+
+pub async fn run_migration(pool: Arc<Pool>, command: MigrationCommand) -> Result<(), CustomError> {
+    let mut client = pool.get().await.map_err(|e| CustomError::RepositoryError(format!("Failed to get database connection: {}", e)))?;
+
+    // advisory lock เป็นระดับ session จึงต้องใช้ client ตัวเดียวตลอดการทำงาน
+    client
+        .execute("SELECT pg_advisory_lock($1);", &[&MIGRATION_LOCK_KEY])
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Failed to acquire migration lock: {}", e)))?;
+
+    let result = match command {
+        MigrationCommand::Up => migrate_up(&mut client).await,
+        MigrationCommand::Down => migrate_down(&mut client).await,
+        MigrationCommand::Status => migration_status(&client).await.map(|status| print_status(&status)),
+    };
+
+    client
+        .execute("SELECT pg_advisory_unlock($1);", &[&MIGRATION_LOCK_KEY])
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Failed to release migration lock: {}", e)))?;
+
+    result
+}
+
+async fn migration_status(client: &Object) -> Result<MigrationStatus, CustomError> {
+    client
+        .batch_execute(CREATE_SCHEMA_MIGRATIONS)
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Failed to create schema_migrations: {}", e)))?;
+
+    let row = client
+        .query_opt("SELECT version, dirty FROM public.schema_migrations LIMIT 1;", &[])
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+    let (version, dirty) = match row {
+        Some(row) => (Some(row.get::<_, i64>("version")), row.get::<_, bool>("dirty")),
+        None => (None, false),
+    };
+
+    Ok(MigrationStatus {
+        version,
+        dirty,
+        pending: pending_versions(version),
+    })
+}
+
+pub fn pending_versions(current: Option<i64>) -> Vec<i64> {
+    MIGRATIONS
+        .iter()
+        .map(|migration| migration.version)
+        .filter(|version| current.is_none_or(|current| *version > current))
+        .collect()
+}
+
+async fn migrate_up(client: &mut Object) -> Result<(), CustomError> {
+    let status = migration_status(client).await?;
+    ensure_clean(&status)?;
+
+    if status.pending.is_empty() {
+        println!("Migration: no change (version {})", status.version.unwrap_or(0));
+        return Ok(());
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| status.pending.contains(&m.version)) {
+        apply(client, migration.up, Some(migration.version)).await?;
+        println!("Migration: {}/u {}", migration.version, migration.name);
+    }
+
+    Ok(())
+}
+
+async fn migrate_down(client: &mut Object) -> Result<(), CustomError> {
+    let status = migration_status(client).await?;
+    ensure_clean(&status)?;
+
+    let current = match status.version {
+        Some(version) => version,
+        None => {
+            println!("Migration: no change (nothing to roll back)");
+            return Ok(());
+        }
+    };
+
+    let migration = MIGRATIONS
+        .iter()
+        .find(|m| m.version == current)
+        .ok_or(CustomError::SystemError(format!("Migration version {} is not bundled in this binary", current)))?;
+
+    // ย้อนกลับทีละ 1 version
+    let previous = MIGRATIONS
+        .iter()
+        .map(|m| m.version)
+        .filter(|version| *version < current)
+        .max();
+
+    apply(client, migration.down, previous).await?;
+    println!("Migration: {}/d {}", migration.version, migration.name);
+
+    Ok(())
+}
+
+// รัน sql และบันทึก version ใหม่ภายใน transaction เดียวกัน
+async fn apply(client: &mut Object, sql: &str, version: Option<i64>) -> Result<(), CustomError> {
+    let transaction = client.transaction().await.map_err(|e| CustomError::RepositoryError(format!("Failed to begin transaction: {}", e)))?;
+
+    transaction
+        .batch_execute(sql)
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Migration failed: {}", e)))?;
+    transaction
+        .execute("DELETE FROM public.schema_migrations;", &[])
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+    if let Some(version) = version {
+        transaction
+            .execute("INSERT INTO public.schema_migrations (version, dirty) VALUES ($1, false);", &[&version])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+    }
+
+    transaction.commit().await.map_err(|e| CustomError::RepositoryError(format!("Failed to commit migration: {}", e)))
+}
+
+fn ensure_clean(status: &MigrationStatus) -> Result<(), CustomError> {
+    if status.dirty {
+        return Err(CustomError::SystemError(format!(
+            "Database is dirty at version {}, fix it manually before running migration",
+            status.version.unwrap_or(0)
+        )));
+    }
+
+    Ok(())
+}
+
+fn print_status(status: &MigrationStatus) {
+    match status.version {
+        Some(version) => println!("Migration: current version {} (dirty: {})", version, status.dirty),
+        None => println!("Migration: no version applied"),
+    }
+
+    if status.pending.is_empty() {
+        println!("Migration: up to date");
+    } else {
+        println!("Migration: pending {:?}", status.pending);
+    }
+}
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS "task";
+
+DROP TABLE IF EXISTS "users";
+
+DROP TABLE IF EXISTS "master_data_priority_levels";
+
+DROP TABLE IF EXISTS "master_data_role";
+
+DROP TABLE IF EXISTS "master_data_task_status";
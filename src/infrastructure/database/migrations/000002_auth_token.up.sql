@@ -0,0 +1,45 @@
+CREATE TABLE "refresh_tokens"
+(
+    "token_hash"  varchar(64) PRIMARY KEY NOT NULL,
+    "user_id"     bigint                  NOT NULL,
+    "family_id"   varchar(64)             NOT NULL,
+    "expires_at"  timestamp               NOT NULL,
+    "revoked_at"  timestamp,
+    "replaced_by" varchar(64),
+    "created_at"  timestamp               NOT NULL DEFAULT (now())
+);
+
+CREATE TABLE "revoked_tokens"
+(
+    "jti"        varchar(64) PRIMARY KEY NOT NULL,
+    "user_id"    bigint                  NOT NULL,
+    "expires_at" timestamp               NOT NULL,
+    "created_at" timestamp               NOT NULL DEFAULT (now())
+);
+
+CREATE INDEX "refresh_tokens_family_id_idx" ON "refresh_tokens" USING BTREE ("family_id");
+
+CREATE INDEX "refresh_tokens_user_id_idx" ON "refresh_tokens" USING BTREE ("user_id");
+
+CREATE INDEX "revoked_tokens_expires_at_idx" ON "revoked_tokens" USING BTREE ("expires_at");
+
+COMMENT
+ON COLUMN "refresh_tokens"."token_hash" IS 'sha256 ของ refresh token';
+
+COMMENT
+ON COLUMN "refresh_tokens"."family_id" IS 'กลุ่มของ token ที่ rotate ต่อกันมาจาก login ครั้งเดียวกัน';
+
+COMMENT
+ON COLUMN "refresh_tokens"."revoked_at" IS 'วันที่ถูกใช้/ยกเลิก';
+
+COMMENT
+ON COLUMN "refresh_tokens"."replaced_by" IS 'token_hash ของ token ใหม่ที่ได้จากการ rotate';
+
+COMMENT
+ON COLUMN "revoked_tokens"."jti" IS 'jti ของ access token ที่ถูกยกเลิก';
+
+COMMENT
+ON COLUMN "revoked_tokens"."expires_at" IS 'วันหมดอายุของ access token (ลบทิ้งได้หลังจากนี้)';
+
+ALTER TABLE "refresh_tokens"
+    ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
@@ -0,0 +1,5 @@
+ALTER TABLE "users"
+    ADD COLUMN "active" boolean NOT NULL DEFAULT true;
+
+COMMENT
+ON COLUMN "users"."active" IS 'เปิด/ปิด ใช้งาน';
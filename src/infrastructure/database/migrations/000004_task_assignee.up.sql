@@ -0,0 +1,22 @@
+CREATE TABLE "task_assignee"
+(
+    "task_id"     bigint    NOT NULL,
+    "user_id"     bigint    NOT NULL,
+    "assigned_by" bigint    NOT NULL,
+    "assigned_at" timestamp NOT NULL DEFAULT (now()),
+    PRIMARY KEY ("task_id", "user_id")
+);
+
+CREATE INDEX "task_assignee_user_id_idx" ON "task_assignee" USING BTREE ("user_id");
+
+COMMENT
+ON COLUMN "task_assignee"."user_id" IS 'ผู้รับผิดชอบ task';
+
+COMMENT
+ON COLUMN "task_assignee"."assigned_by" IS 'ผู้มอบหมายงาน';
+
+ALTER TABLE "task_assignee"
+    ADD FOREIGN KEY ("task_id") REFERENCES "task" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "task_assignee"
+    ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
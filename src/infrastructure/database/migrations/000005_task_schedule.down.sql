@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS "task_due_date_idx";
+
+ALTER TABLE "task"
+    DROP COLUMN IF EXISTS "start_date",
+    DROP COLUMN IF EXISTS "due_date";
@@ -0,0 +1,11 @@
+ALTER TABLE "task"
+    ADD COLUMN "start_date" timestamp,
+    ADD COLUMN "due_date"   timestamp;
+
+CREATE INDEX "task_due_date_idx" ON "task" USING BTREE ("due_date");
+
+COMMENT
+ON COLUMN "task"."start_date" IS 'วันที่เริ่มงาน';
+
+COMMENT
+ON COLUMN "task"."due_date" IS 'กำหนดส่ง';
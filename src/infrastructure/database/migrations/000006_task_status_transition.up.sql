@@ -0,0 +1,52 @@
+CREATE TABLE "master_data_task_status_transition"
+(
+    "id"             bigint UNIQUE PRIMARY KEY NOT NULL,
+    "from_status_id" bigint                    NOT NULL,
+    "to_status_id"   bigint                    NOT NULL,
+    "role_id"        bigint,
+    "active"         boolean                   NOT NULL DEFAULT true,
+    "created_by"     bigint                    NOT NULL,
+    "created_at"     timestamp                 NOT NULL DEFAULT (now()),
+    "updated_at"     timestamp,
+    "updated_by"     bigint
+);
+
+CREATE UNIQUE INDEX "master_data_task_status_transition_uniq_idx"
+    ON "master_data_task_status_transition" ("from_status_id", "to_status_id", COALESCE("role_id", 0));
+
+CREATE INDEX "master_data_task_status_transition_from_idx"
+    ON "master_data_task_status_transition" USING BTREE ("from_status_id");
+
+COMMENT
+ON COLUMN "master_data_task_status_transition"."from_status_id" IS 'สถานะปัจจุบัน';
+
+COMMENT
+ON COLUMN "master_data_task_status_transition"."to_status_id" IS 'สถานะถัดไปที่เปลี่ยนได้';
+
+COMMENT
+ON COLUMN "master_data_task_status_transition"."role_id" IS 'จำกัดเฉพาะ role นี้ (NULL = ทุก role)';
+
+ALTER TABLE "master_data_task_status_transition"
+    ADD FOREIGN KEY ("from_status_id") REFERENCES "master_data_task_status" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "master_data_task_status_transition"
+    ADD FOREIGN KEY ("to_status_id") REFERENCES "master_data_task_status" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "master_data_task_status_transition"
+    ADD FOREIGN KEY ("role_id") REFERENCES "master_data_role" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- workflow เริ่มต้น: Pending -> In Progress -> Completed, ย้อนกลับ Completed ได้เฉพาะ Admin/Manager
+INSERT INTO master_data_task_status_transition(id, from_status_id, to_status_id, role_id, created_by)
+VALUES (7252117538346840064, 7250066646188953600, 7250066663482068992, NULL, 0);
+
+INSERT INTO master_data_task_status_transition(id, from_status_id, to_status_id, role_id, created_by)
+VALUES (7252117558504693760, 7250066663482068992, 7250066646188953600, NULL, 0);
+
+INSERT INTO master_data_task_status_transition(id, from_status_id, to_status_id, role_id, created_by)
+VALUES (7252117575562928128, 7250066663482068992, 7250066683811860480, NULL, 0);
+
+INSERT INTO master_data_task_status_transition(id, from_status_id, to_status_id, role_id, created_by)
+VALUES (7252117592860237824, 7250066683811860480, 7250066663482068992, 7250548959330963456, 0);
+
+INSERT INTO master_data_task_status_transition(id, from_status_id, to_status_id, role_id, created_by)
+VALUES (7252117609956220928, 7250066683811860480, 7250066663482068992, 7250549955788541952, 0);
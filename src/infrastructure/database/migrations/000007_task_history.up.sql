@@ -0,0 +1,23 @@
+CREATE TABLE "task_history"
+(
+    "id"         bigint UNIQUE PRIMARY KEY NOT NULL,
+    "task_id"    bigint                    NOT NULL,
+    "operation"  varchar(20)               NOT NULL,
+    "changes"    jsonb                     NOT NULL DEFAULT '[]',
+    "actor_id"   bigint                    NOT NULL,
+    "created_at" timestamp                 NOT NULL DEFAULT (now())
+);
+
+CREATE INDEX "task_history_task_id_idx" ON "task_history" USING BTREE ("task_id", "id");
+
+COMMENT
+ON TABLE "task_history" IS 'ประวัติการเปลี่ยนแปลง task (เพิ่มได้อย่างเดียว)';
+
+COMMENT
+ON COLUMN "task_history"."operation" IS 'CREATE, UPDATE, DELETE, ASSIGN, UNASSIGN';
+
+COMMENT
+ON COLUMN "task_history"."changes" IS 'รายการ field ที่เปลี่ยน [{field, old, new}]';
+
+COMMENT
+ON COLUMN "task_history"."actor_id" IS 'ผู้ทำรายการ (sub ใน JWT)';
@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS "task_deleted_at_idx";
+
+ALTER TABLE "task"
+    DROP COLUMN IF EXISTS "deleted_at",
+    DROP COLUMN IF EXISTS "deleted_by";
+
+COMMENT
+ON COLUMN "task_history"."operation" IS 'CREATE, UPDATE, DELETE, ASSIGN, UNASSIGN';
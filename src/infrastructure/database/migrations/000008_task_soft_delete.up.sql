@@ -0,0 +1,14 @@
+ALTER TABLE "task"
+    ADD COLUMN "deleted_at" timestamp,
+    ADD COLUMN "deleted_by" bigint;
+
+CREATE INDEX "task_deleted_at_idx" ON "task" USING BTREE ("deleted_at") WHERE "deleted_at" IS NOT NULL;
+
+COMMENT
+ON COLUMN "task"."deleted_at" IS 'วันที่ย้ายไปถังขยะ (NULL = ยังใช้งานอยู่)';
+
+COMMENT
+ON COLUMN "task"."deleted_by" IS 'ผู้ลบ';
+
+COMMENT
+ON COLUMN "task_history"."operation" IS 'CREATE, UPDATE, DELETE, ASSIGN, UNASSIGN, RESTORE, PURGE';
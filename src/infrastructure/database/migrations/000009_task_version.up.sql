@@ -0,0 +1,5 @@
+ALTER TABLE "task"
+    ADD COLUMN "version" integer NOT NULL DEFAULT 1;
+
+COMMENT
+ON COLUMN "task"."version" IS 'เพิ่มขึ้นทุกครั้งที่แก้ไข ใช้เป็น ETag ตรวจสอบการแก้ไขซ้อนกัน';
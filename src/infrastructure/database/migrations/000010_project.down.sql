@@ -0,0 +1,6 @@
+ALTER TABLE "task"
+    DROP COLUMN IF EXISTS "project_id";
+
+DROP TABLE IF EXISTS "project_member";
+
+DROP TABLE IF EXISTS "project";
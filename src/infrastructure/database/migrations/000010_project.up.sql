@@ -0,0 +1,59 @@
+CREATE TABLE "project"
+(
+    "id"          bigint UNIQUE PRIMARY KEY NOT NULL,
+    "name"        varchar(255)              NOT NULL,
+    "description" text,
+    "archived_at" timestamp,
+    "archived_by" bigint,
+    "created_by"  bigint                    NOT NULL,
+    "created_at"  timestamp                 NOT NULL DEFAULT (now()),
+    "updated_at"  timestamp,
+    "updated_by"  bigint
+);
+
+CREATE TABLE "project_member"
+(
+    "project_id" bigint    NOT NULL,
+    "user_id"    bigint    NOT NULL,
+    "role_id"    bigint    NOT NULL,
+    "added_by"   bigint    NOT NULL,
+    "added_at"   timestamp NOT NULL DEFAULT (now()),
+    PRIMARY KEY ("project_id", "user_id")
+);
+
+CREATE INDEX "project_member_user_id_idx" ON "project_member" USING BTREE ("user_id");
+
+COMMENT
+ON COLUMN "project"."archived_at" IS 'วันที่เก็บเข้าคลัง (NULL = ใช้งานอยู่) task ใน project ที่เก็บแล้วแก้ไขไม่ได้';
+
+COMMENT
+ON COLUMN "project_member"."role_id" IS 'role ของสมาชิกใน project นี้ ใช้ตรวจสิทธิ์ของ task ใน project';
+
+ALTER TABLE "project_member"
+    ADD FOREIGN KEY ("project_id") REFERENCES "project" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "project_member"
+    ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "project_member"
+    ADD FOREIGN KEY ("role_id") REFERENCES "master_data_role" ("id") ON UPDATE CASCADE;
+
+-- task เดิมทั้งหมดย้ายเข้า project เริ่มต้น และผู้ใช้งานเดิมเป็นสมาชิกด้วย role เดิม
+INSERT INTO project(id, name, description, created_by)
+VALUES (7255437512398848000, 'General', 'Project เริ่มต้นสำหรับ task ที่สร้างก่อนมี project', 0);
+
+INSERT INTO project_member(project_id, user_id, role_id, added_by)
+SELECT 7255437512398848000, id, role_id, 0 FROM users WHERE role_id IS NOT NULL;
+
+ALTER TABLE "task"
+    ADD COLUMN "project_id" bigint;
+
+UPDATE task SET project_id = 7255437512398848000;
+
+ALTER TABLE "task"
+    ALTER COLUMN "project_id" SET NOT NULL;
+
+CREATE INDEX "task_project_id_idx" ON "task" USING BTREE ("project_id");
+
+ALTER TABLE "task"
+    ADD FOREIGN KEY ("project_id") REFERENCES "project" ("id") ON UPDATE CASCADE;
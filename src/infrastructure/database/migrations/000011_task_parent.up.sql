@@ -0,0 +1,11 @@
+ALTER TABLE "task"
+    ADD COLUMN "parent_task_id" bigint;
+
+COMMENT
+ON COLUMN "task"."parent_task_id" IS 'task แม่ (NULL = task ระดับบนสุด) ต้องอยู่ใน project เดียวกัน';
+
+CREATE INDEX "task_parent_task_id_idx" ON "task" USING BTREE ("parent_task_id");
+
+-- ลบ task แม่ถาวรแล้ว subtask ที่ยังเหลือจะกลายเป็น task ระดับบนสุด
+ALTER TABLE "task"
+    ADD FOREIGN KEY ("parent_task_id") REFERENCES "task" ("id") ON DELETE SET NULL;
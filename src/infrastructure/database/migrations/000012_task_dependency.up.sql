@@ -0,0 +1,23 @@
+CREATE TABLE "task_dependency"
+(
+    "task_id"            bigint    NOT NULL,
+    "blocked_by_task_id" bigint    NOT NULL,
+    "created_by"         bigint    NOT NULL,
+    "created_at"         timestamp NOT NULL DEFAULT (now()),
+    PRIMARY KEY ("task_id", "blocked_by_task_id"),
+    CHECK ("task_id" <> "blocked_by_task_id")
+);
+
+CREATE INDEX "task_dependency_blocked_by_task_id_idx" ON "task_dependency" USING BTREE ("blocked_by_task_id");
+
+COMMENT
+ON COLUMN "task_dependency"."task_id" IS 'task ที่เริ่ม/ปิดไม่ได้จนกว่า task ที่ block จะเสร็จ';
+
+COMMENT
+ON COLUMN "task_dependency"."blocked_by_task_id" IS 'task ที่ต้องเสร็จก่อน';
+
+ALTER TABLE "task_dependency"
+    ADD FOREIGN KEY ("task_id") REFERENCES "task" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "task_dependency"
+    ADD FOREIGN KEY ("blocked_by_task_id") REFERENCES "task" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
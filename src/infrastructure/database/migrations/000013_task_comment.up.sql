@@ -0,0 +1,20 @@
+CREATE TABLE "task_comment"
+(
+    "id"         bigint PRIMARY KEY NOT NULL,
+    "task_id"    bigint    NOT NULL,
+    "body"       text      NOT NULL,
+    "created_by" bigint    NOT NULL,
+    "created_at" timestamp NOT NULL DEFAULT (now()),
+    "updated_at" timestamp
+);
+
+CREATE INDEX "task_comment_task_id_idx" ON "task_comment" USING BTREE ("task_id", "id");
+
+COMMENT
+ON COLUMN "task_comment"."updated_at" IS 'มีค่าเมื่อผู้เขียนแก้ไขความคิดเห็น';
+
+ALTER TABLE "task_comment"
+    ADD FOREIGN KEY ("task_id") REFERENCES "task" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "task_comment"
+    ADD FOREIGN KEY ("created_by") REFERENCES "users" ("id") ON DELETE NO ACTION ON UPDATE CASCADE;
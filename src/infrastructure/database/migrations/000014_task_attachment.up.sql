@@ -0,0 +1,22 @@
+CREATE TABLE "task_attachment"
+(
+    "id"           bigint PRIMARY KEY NOT NULL,
+    "task_id"      bigint       NOT NULL,
+    "file_name"    varchar(255) NOT NULL,
+    "content_type" varchar(255) NOT NULL,
+    "size_bytes"   bigint       NOT NULL,
+    "storage_key"  varchar(255) NOT NULL UNIQUE,
+    "created_by"   bigint       NOT NULL,
+    "created_at"   timestamp    NOT NULL DEFAULT (now())
+);
+
+CREATE INDEX "task_attachment_task_id_idx" ON "task_attachment" USING BTREE ("task_id");
+
+COMMENT
+ON COLUMN "task_attachment"."storage_key" IS 'key ของไฟล์ใน storage (local หรือ S3)';
+
+ALTER TABLE "task_attachment"
+    ADD FOREIGN KEY ("task_id") REFERENCES "task" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "task_attachment"
+    ADD FOREIGN KEY ("created_by") REFERENCES "users" ("id") ON DELETE NO ACTION ON UPDATE CASCADE;
@@ -0,0 +1,38 @@
+CREATE TABLE "label"
+(
+    "id"         bigint PRIMARY KEY NOT NULL,
+    "name"       varchar(50) NOT NULL,
+    "color"      varchar(7)  NOT NULL,
+    "created_by" bigint      NOT NULL,
+    "created_at" timestamp   NOT NULL DEFAULT (now()),
+    "updated_at" timestamp,
+    "updated_by" bigint
+);
+
+CREATE UNIQUE INDEX "label_name_key" ON "label" USING BTREE (lower("name"));
+
+COMMENT
+ON COLUMN "label"."color" IS 'สีในรูปแบบ #rrggbb';
+
+ALTER TABLE "label"
+    ADD FOREIGN KEY ("created_by") REFERENCES "users" ("id") ON DELETE NO ACTION ON UPDATE CASCADE;
+
+ALTER TABLE "label"
+    ADD FOREIGN KEY ("updated_by") REFERENCES "users" ("id") ON DELETE NO ACTION ON UPDATE CASCADE;
+
+CREATE TABLE "task_label"
+(
+    "task_id"    bigint    NOT NULL,
+    "label_id"   bigint    NOT NULL,
+    "labeled_by" bigint    NOT NULL,
+    "labeled_at" timestamp NOT NULL DEFAULT (now()),
+    PRIMARY KEY ("task_id", "label_id")
+);
+
+CREATE INDEX "task_label_label_id_idx" ON "task_label" USING BTREE ("label_id");
+
+ALTER TABLE "task_label"
+    ADD FOREIGN KEY ("task_id") REFERENCES "task" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE "task_label"
+    ADD FOREIGN KEY ("label_id") REFERENCES "label" ("id") ON DELETE CASCADE ON UPDATE CASCADE;
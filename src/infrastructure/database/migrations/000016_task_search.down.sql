@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS "task_comment_search_vector_idx";
+DROP INDEX IF EXISTS "task_search_vector_idx";
+
+ALTER TABLE "task_comment"
+    DROP COLUMN IF EXISTS "search_vector";
+
+ALTER TABLE "task"
+    DROP COLUMN IF EXISTS "search_vector";
@@ -0,0 +1,19 @@
+-- ใช้ config simple (ไม่ตัดรากศัพท์) เพราะข้อมูลปนทั้งภาษาไทยและภาษาอังกฤษ
+-- แปลง < > เป็น \x02 \x03 ก่อน ไม่ให้ parser มองข้อความเป็น HTML tag แล้วตัดคำทิ้ง
+ALTER TABLE "task"
+    ADD COLUMN "search_vector" tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', translate(coalesce("title", ''), '<>', E'\x02\x03')), 'A') ||
+        setweight(to_tsvector('simple', translate(coalesce("description", ''), '<>', E'\x02\x03')), 'B')
+    ) STORED;
+
+CREATE INDEX "task_search_vector_idx" ON "task" USING GIN ("search_vector");
+
+ALTER TABLE "task_comment"
+    ADD COLUMN "search_vector" tsvector GENERATED ALWAYS AS (
+        to_tsvector('simple', translate("body", '<>', E'\x02\x03'))
+    ) STORED;
+
+CREATE INDEX "task_comment_search_vector_idx" ON "task_comment" USING GIN ("search_vector");
+
+COMMENT
+ON COLUMN "task"."search_vector" IS 'title (น้ำหนัก A) และ description (น้ำหนัก B) สำหรับ full-text search';
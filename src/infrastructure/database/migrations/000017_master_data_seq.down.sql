@@ -0,0 +1,5 @@
+ALTER TABLE "master_data_role"
+    DROP COLUMN IF EXISTS "seq";
+
+ALTER TABLE "master_data_task_status"
+    DROP COLUMN IF EXISTS "seq";
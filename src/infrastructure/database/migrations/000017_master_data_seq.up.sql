@@ -0,0 +1,30 @@
+-- ลำดับการแสดงผลของสถานะงานและ role (เหมือน master_data_priority_levels) เริ่มจากลำดับที่สร้าง
+ALTER TABLE "master_data_task_status"
+    ADD COLUMN "seq" integer;
+
+UPDATE "master_data_task_status" s
+SET "seq" = o.seq
+FROM (SELECT id, ROW_NUMBER() OVER (ORDER BY id) AS seq FROM "master_data_task_status") o
+WHERE o.id = s.id;
+
+ALTER TABLE "master_data_task_status"
+    ALTER COLUMN "seq" SET NOT NULL,
+    ADD CONSTRAINT "master_data_task_status_seq_key" UNIQUE ("seq");
+
+ALTER TABLE "master_data_role"
+    ADD COLUMN "seq" integer;
+
+UPDATE "master_data_role" r
+SET "seq" = o.seq
+FROM (SELECT id, ROW_NUMBER() OVER (ORDER BY id) AS seq FROM "master_data_role") o
+WHERE o.id = r.id;
+
+ALTER TABLE "master_data_role"
+    ALTER COLUMN "seq" SET NOT NULL,
+    ADD CONSTRAINT "master_data_role_seq_key" UNIQUE ("seq");
+
+COMMENT
+ON COLUMN "master_data_task_status"."seq" IS 'ลำดับ';
+
+COMMENT
+ON COLUMN "master_data_role"."seq" IS 'ลำดับ';
@@ -1,5 +1,12 @@
 pub mod connection;
+pub mod migration;
+pub mod query_builder;
 pub mod master_data;
 pub mod task;
 pub mod auth;
-pub mod health_check;
\ No newline at end of file
+pub mod health_check;
+pub mod project;
+pub mod comment;
+pub mod attachment;
+pub mod label;
+pub mod master_data_cache;
\ No newline at end of file
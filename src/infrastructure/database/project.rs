@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+use deadpool_postgres::Pool;
+use std::sync::Arc;
+use tokio_postgres::Row;
+use crate::domain::entities::auth::Role;
+use crate::domain::entities::project::{Project, ProjectCreateEntity, ProjectFilter, ProjectMember, ProjectMemberSet, UpdateProject};
+use crate::domain::repositories::project::ProjectRepositories;
+use crate::infrastructure::database::query_builder::QueryBuilder;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{PROJECT_NOT_FOUND, RECORD_NOT_FOUND, ROLE_NOT_FOUND};
+use crate::shared::utils::snowflake::Snowflake;
+
+pub struct ProjectRepositoriesImpl<S: Snowflake + Send + Sync> {
+    db_conn: Arc<Pool>,
+    snowflake_id: S,
+}
+
+impl<S: Snowflake + Send + Sync> ProjectRepositoriesImpl<S> {
+    pub fn new(db_conn: Arc<Pool>, snowflake_id: S) -> Self {
+        Self { db_conn, snowflake_id }
+    }
+}
+
+const PROJECT_COLUMNS: &str = "id, name, description, archived_at, archived_by, created_by, created_at, updated_at, updated_by";
+
+fn map_project(row: &Row) -> Project {
+    Project {
+        id: row.get("id"),
+        name: row.get("name"),
+        description: row.get("description"),
+        archived_at: row.get("archived_at"),
+        archived_by: row.get("archived_by"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        updated_by: row.get("updated_by"),
+    }
+}
+
+fn map_project_member(row: &Row) -> ProjectMember {
+    ProjectMember {
+        user_id: row.get("user_id"),
+        username: row.get("username"),
+        role_id: row.get("role_id"),
+        role: row.get("role"),
+        added_by: row.get("added_by"),
+        added_at: row.get("added_at"),
+    }
+}
+
+#[async_trait]
+impl<S: Snowflake + Send + Sync> ProjectRepositories for ProjectRepositoriesImpl<S> {
+    async fn list_project(&self, filter: ProjectFilter) -> Result<Vec<Project>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let mut query = QueryBuilder::new();
+        if filter.archived {
+            query.and_where("archived_at IS NOT NULL".to_string());
+        } else {
+            query.and_where("archived_at IS NULL".to_string());
+        }
+        if let Some(member_id) = filter.member_id {
+            let member_id = query.bind(member_id);
+            query.and_where(format!(
+                "EXISTS (SELECT 1 FROM public.project_member pm WHERE pm.project_id = project.id AND pm.user_id = {})",
+                member_id,
+            ));
+        }
+
+        let sql = format!("SELECT {} FROM public.project{} ORDER BY name ASC, id ASC;", PROJECT_COLUMNS, query.where_clause());
+        let rows = client
+            .query(sql.as_str(), &query.params())
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(map_project).collect())
+    }
+
+    async fn get_project(&self, id: i64) -> Result<Project, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one(format!("SELECT {} FROM public.project WHERE id = $1;", PROJECT_COLUMNS).as_str(), &[&id])
+            .await.map_err(|e| {
+            if e.to_string().contains(RECORD_NOT_FOUND) {
+                return CustomError::NotFound(format!("{}: {}", PROJECT_NOT_FOUND, id));
+            }
+            CustomError::RepositoryError(format!("Database query failed: {}", e))
+        })?;
+
+        Ok(map_project(&row))
+    }
+
+    async fn create_project(&self, project: ProjectCreateEntity) -> Result<i64, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+        let new_id = self.snowflake_id.generate() as i64;
+
+        transaction
+            .execute(
+                "INSERT INTO public.project (id, name, description, created_by, created_at) VALUES ($1, $2, $3, $4, NOW());",
+                &[&new_id, &project.name, &project.description, &project.created_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        // ผู้สร้างเป็น Admin ของ project
+        let inserted = transaction
+            .execute(
+                "INSERT INTO public.project_member (project_id, user_id, role_id, added_by, added_at)
+                 SELECT $1, $2, id, $2, NOW() FROM public.master_data_role WHERE code = 'ADMIN' AND active = true;",
+                &[&new_id, &project.created_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        if inserted == 0 {
+            return Err(CustomError::SystemError(ROLE_NOT_FOUND.to_string()));
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(new_id)
+    }
+
+    async fn update_project(&self, project: UpdateProject) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute(
+                "UPDATE public.project
+                 SET name = $1,
+                     description = $2,
+                     updated_at = NOW(),
+                     updated_by = $3
+                 WHERE id = $4;",
+                &[&project.name, &project.description, &project.updated_by, &project.id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn set_project_archived(&self, id: i64, archived_by: Option<i64>) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        // archive ซ้ำจะไม่เปลี่ยนวันที่เก็บเข้าคลังเดิม
+        client
+            .execute(
+                "UPDATE public.project
+                 SET archived_at = CASE WHEN $2::bigint IS NULL THEN NULL ELSE COALESCE(archived_at, NOW()) END,
+                     archived_by = CASE WHEN $2::bigint IS NULL THEN NULL ELSE COALESCE(archived_by, $2) END
+                 WHERE id = $1;",
+                &[&id, &archived_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete_project(&self, id: i64) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute("DELETE FROM public.project WHERE id = $1;", &[&id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn count_task(&self, project_id: i64) -> Result<i64, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one("SELECT COUNT(id) AS task_count FROM public.task WHERE project_id = $1;", &[&project_id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.get("task_count"))
+    }
+
+    async fn member_role(&self, project_id: i64, user_id: i64) -> Result<Option<Role>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_opt(
+                "SELECT r.code FROM public.project_member pm
+                 JOIN public.master_data_role r ON r.id = pm.role_id AND r.active = true
+                 WHERE pm.project_id = $1 AND pm.user_id = $2;",
+                &[&project_id, &user_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.and_then(|row| Role::from_code(row.get("code"))))
+    }
+
+    async fn list_member(&self, project_id: i64) -> Result<Vec<ProjectMember>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let rows = client
+            .query(
+                "SELECT pm.user_id, u.username, pm.role_id, r.code AS role, pm.added_by, pm.added_at
+                 FROM public.project_member pm
+                 JOIN public.users u ON u.id = pm.user_id
+                 JOIN public.master_data_role r ON r.id = pm.role_id
+                 WHERE pm.project_id = $1
+                 ORDER BY u.username ASC;",
+                &[&project_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(map_project_member).collect())
+    }
+
+    async fn set_member(&self, member: ProjectMemberSet) -> Result<(), CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        client
+            .execute(
+                "INSERT INTO public.project_member (project_id, user_id, role_id, added_by, added_at)
+                 VALUES ($1, $2, $3, $4, NOW())
+                 ON CONFLICT (project_id, user_id) DO UPDATE SET role_id = EXCLUDED.role_id;",
+                &[&member.project_id, &member.user_id, &member.role_id, &member.added_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn remove_member(&self, project_id: i64, user_id: i64) -> Result<bool, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let deleted = client
+            .execute(
+                "DELETE FROM public.project_member WHERE project_id = $1 AND user_id = $2;",
+                &[&project_id, &user_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn is_active_user(&self, user_id: i64) -> Result<bool, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_one(
+                "SELECT (COUNT(id) > 0) AS is_active FROM public.users WHERE id = $1 AND active = true;",
+                &[&user_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.get::<_, bool>("is_active"))
+    }
+}
@@ -0,0 +1,48 @@
+use tokio_postgres::types::ToSql;
+
+// ตัวช่วยสร้าง WHERE แบบ dynamic พร้อมเก็บ parameter ตามลำดับ $1, $2, ...
+#[derive(Default)]
+pub struct QueryBuilder {
+    conditions: Vec<String>,
+    params: Vec<Box<dyn ToSql + Sync + Send>>,
+}
+
+impl QueryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // เพิ่ม parameter และคืน placeholder เช่น "$3"
+    pub fn bind<T: ToSql + Sync + Send + 'static>(&mut self, value: T) -> String {
+        self.params.push(Box::new(value));
+        format!("${}", self.params.len())
+    }
+
+    pub fn and_where(&mut self, condition: String) {
+        self.conditions.push(condition);
+    }
+
+    pub fn where_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            return String::new();
+        }
+
+        format!(" WHERE {}", self.conditions.join(" AND "))
+    }
+
+    pub fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        self.params
+            .iter()
+            .map(|param| param.as_ref() as &(dyn ToSql + Sync))
+            .collect()
+    }
+}
+
+// escape อักขระพิเศษของ LIKE แล้วครอบด้วย % สำหรับค้นหาแบบ contains
+pub fn like_pattern(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
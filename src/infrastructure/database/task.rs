@@ -2,10 +2,17 @@
 use async_trait::async_trait;
 use deadpool_postgres::Pool;
 use std::sync::Arc;
-use crate::domain::entities::task::{Task, TaskCreateEntity, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
+use chrono::NaiveDateTime;
+use deadpool_postgres::Transaction;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio_postgres::types::ToSql;
+use tokio_postgres::Row;
+use crate::domain::entities::task::{LabelMatch, PatchTask, SortDirection, Task, TaskAssign, TaskBulkAction, TaskBulkItem, TaskBulkItemResult, TaskBulkMode, TaskDependency, TaskFieldChange, TaskHistory, TaskHistoryFilter, TaskHistoryOperation, TaskHistoryPage, TaskCreateEntity, TaskCursor, TaskExpand, TaskFilter, TaskLabelAdd, TaskPage, TaskPurgeResult, TaskSearchHighlight, TaskSearchHit, TaskSearchPage, TaskSortField, UpdateTask, UpdateTaskPriorityLevels, UpdateTaskStatus};
 use crate::domain::repositories::task::TaskRepositories;
+use crate::infrastructure::database::query_builder::{like_pattern, QueryBuilder};
 use crate::shared::exceptions::custom_error::CustomError;
-use crate::shared::exceptions::error_message::{RECORD_NOT_FOUND, TASK_NOT_FOUND};
+use crate::shared::exceptions::error_message::{INVALID_CURSOR, RECORD_NOT_FOUND, TASK_NOT_FOUND, TASK_NOT_FOUND_IN_TRASH, TASK_VERSION_MISMATCH};
 use crate::shared::utils::snowflake::Snowflake;
 
 pub struct TaskRepositoriesImpl<S: Snowflake + Send + Sync> {
@@ -17,47 +24,794 @@ impl<S: Snowflake + Send + Sync> TaskRepositoriesImpl<S> {
     pub fn new(db_conn: Arc<Pool>, snowflake_id: S) -> Self {
         Self { db_conn, snowflake_id }
     }
+
+    // บันทึกประวัติใน transaction เดียวกับการแก้ไข task
+    async fn insert_history(
+        &self,
+        transaction: &Transaction<'_>,
+        task_id: i64,
+        operation: TaskHistoryOperation,
+        changes: Vec<TaskFieldChange>,
+        actor_id: i64,
+    ) -> Result<(), CustomError> {
+        let id = self.snowflake_id.generate() as i64;
+        let changes = serde_json::to_value(changes)
+            .map_err(|e| CustomError::RepositoryError(format!("Failed to serialize task history: {}", e)))?;
+
+        transaction
+            .execute(
+                "INSERT INTO public.task_history (id, task_id, operation, changes, actor_id, created_at) VALUES ($1, $2, $3, $4, $5, NOW());",
+                &[&id, &task_id, &operation.code(), &changes, &actor_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn update_with_history(
+        &self,
+        id: i64,
+        version: Option<i32>,
+        updated_by: i64,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        self.update_in_transaction(&transaction, id, version, updated_by, sql, params).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    // lock แถวเดิมไว้ ตรวจ version อัปเดต แล้วบันทึก field ที่เปลี่ยนจริง (sql ต้องไม่มี RETURNING และ ;)
+    async fn update_in_transaction(
+        &self,
+        transaction: &Transaction<'_>,
+        id: i64,
+        version: Option<i32>,
+        updated_by: i64,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<(), CustomError> {
+        let old = transaction
+            .query_opt(
+                format!("SELECT version, {} FROM public.task WHERE id = $1 AND deleted_at IS NULL FOR UPDATE;", TASK_AUDIT_COLUMNS).as_str(),
+                &[&id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?
+            .ok_or(CustomError::NotFound(format!("{}: {}", TASK_NOT_FOUND, id)))?;
+        ensure_version(id, version, old.get("version"))?;
+
+        let new = transaction
+            .query_one(format!("{} RETURNING {};", sql, TASK_AUDIT_COLUMNS).as_str(), params)
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let changes = field_changes(&audit_values(&old), &audit_values(&new));
+        if !changes.is_empty() {
+            self.insert_history(transaction, id, TaskHistoryOperation::Update, changes, updated_by).await?;
+        }
+
+        Ok(())
+    }
+
+    // ย้ายไปถังขยะ ลบถาวรภายหลังโดย purge job
+    async fn delete_in_transaction(&self, transaction: &Transaction<'_>, id: i64, deleted_by: i64, version: Option<i32>) -> Result<(), CustomError> {
+        let current = transaction
+            .query_opt("SELECT version FROM public.task WHERE id = $1 AND deleted_at IS NULL FOR UPDATE;", &[&id])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?
+            .ok_or(CustomError::NotFound(format!("{}: {}", TASK_NOT_FOUND, id)))?;
+        ensure_version(id, version, current.get("version"))?;
+
+        let row = transaction
+            .query_one(
+                "UPDATE public.task SET deleted_at = NOW(), deleted_by = $2, version = version + 1 WHERE id = $1 RETURNING deleted_at, deleted_by;",
+                &[&id, &deleted_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let changes = field_changes(&[("deleted_at", Value::Null), ("deleted_by", Value::Null)], &deleted_values(&row));
+        self.insert_history(transaction, id, TaskHistoryOperation::Delete, changes, deleted_by).await?;
+
+        // subtask ทุกชั้นย้ายไปถังขยะด้วย deleted_at เดียวกัน เพื่อกู้คืนพร้อมกันได้
+        let subtask_ids = subtask_ids(transaction, id, None).await?;
+        let rows = transaction
+            .query(
+                "UPDATE public.task SET deleted_at = $2, deleted_by = $3, version = version + 1 WHERE id = ANY($1) RETURNING id, deleted_at, deleted_by;",
+                &[&subtask_ids, &row.get::<_, NaiveDateTime>("deleted_at"), &deleted_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        for row in &rows {
+            let changes = field_changes(&[("deleted_at", Value::Null), ("deleted_by", Value::Null)], &deleted_values(row));
+            self.insert_history(transaction, row.get("id"), TaskHistoryOperation::Delete, changes, deleted_by).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn assign_in_transaction(&self, transaction: &Transaction<'_>, assign: &TaskAssign) -> Result<(), CustomError> {
+        let old_assignees = lock_assignees(transaction, assign.task_id).await?;
+
+        // assign ซ้ำได้โดยไม่ error และไม่เปลี่ยนผู้มอบหมายเดิม
+        let inserted = transaction
+            .execute(
+                "INSERT INTO public.task_assignee (task_id, user_id, assigned_by, assigned_at)
+                 SELECT $1, user_id, $3, NOW() FROM UNNEST($2::bigint[]) AS user_id
+                 ON CONFLICT (task_id, user_id) DO NOTHING;",
+                &[&assign.task_id, &assign.user_ids, &assign.assigned_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        if inserted > 0 {
+            let mut new_assignees = old_assignees.clone();
+            new_assignees.extend(assign.user_ids.iter().filter(|user_id| !old_assignees.contains(user_id)));
+
+            let changes = field_changes(&[("assignees", json!(old_assignees))], &[("assignees", json!(new_assignees))]);
+            self.insert_history(transaction, assign.task_id, TaskHistoryOperation::Assign, changes, assign.assigned_by).await?;
+            bump_version(transaction, assign.task_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn insert_task(&self, transaction: &Transaction<'_>, task: &TaskCreateEntity) -> Result<i64, CustomError> {
+        let new_id = self.snowflake_id.generate() as i64;
+
+        let row = transaction
+            .query_one(
+                format!(
+                    "INSERT INTO public.task (id, project_id, title, description, task_status_id, priority_levels_id, start_date, due_date, created_by, parent_task_id, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW()) RETURNING id, {};",
+                    TASK_AUDIT_COLUMNS,
+                ).as_str(),
+                &[
+                    &new_id,
+                    &task.project_id,
+                    &task.title,
+                    &task.description,
+                    &task.task_status_id,
+                    &task.priority_levels_id,
+                    &task.start_date,
+                    &task.due_date,
+                    &task.created_by,
+                    &task.parent_task_id,
+                ],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let changes = field_changes(&null_values(), &audit_values(&row));
+        self.insert_history(transaction, new_id, TaskHistoryOperation::Create, changes, task.created_by).await?;
+
+        Ok(row.get("id"))
+    }
+
+    // รายการใน bulk ไม่ตรวจ version เพราะแต่ละ task ไม่มี If-Match ของตัวเอง
+    async fn bulk_item(&self, transaction: &Transaction<'_>, item: &TaskBulkItem, actor_id: i64) -> Result<(), CustomError> {
+        match &item.action {
+            TaskBulkAction::UpdateStatus(task_status_id) => {
+                self.update_in_transaction(transaction, item.task_id, None, actor_id, UPDATE_TASK_STATUS_SQL, &[task_status_id, &actor_id, &item.task_id]).await
+            }
+            TaskBulkAction::UpdatePriority(priority_levels_id) => {
+                self.update_in_transaction(transaction, item.task_id, None, actor_id, UPDATE_TASK_PRIORITY_LEVELS_SQL, &[priority_levels_id, &actor_id, &item.task_id]).await
+            }
+            TaskBulkAction::Assign(user_ids) => {
+                let assign = TaskAssign {
+                    task_id: item.task_id,
+                    user_ids: user_ids.clone(),
+                    assigned_by: actor_id,
+                };
+                self.assign_in_transaction(transaction, &assign).await
+            }
+            TaskBulkAction::Delete => self.delete_in_transaction(transaction, item.task_id, actor_id, None).await,
+        }
+    }
+}
+
+const UPDATE_TASK_STATUS_SQL: &str = "UPDATE public.task
+             SET task_status_id = $1,
+                 updated_at = NOW(),
+                 updated_by = $2,
+                 version = version + 1
+             WHERE id = $3";
+
+const UPDATE_TASK_PRIORITY_LEVELS_SQL: &str = "UPDATE public.task
+             SET priority_levels_id = $1,
+                 updated_at = NOW(),
+                 updated_by = $2,
+                 version = version + 1
+             WHERE id = $3";
+
+// column ที่เก็บค่าเก่า/ใหม่ไว้ใน task_history
+const TASK_AUDIT_COLUMNS: &str = "project_id, parent_task_id, title, description, task_status_id, priority_levels_id, start_date, due_date";
+
+fn audit_values(row: &Row) -> Vec<(&'static str, Value)> {
+    vec![
+        ("project_id", json!(row.get::<_, i64>("project_id"))),
+        ("parent_task_id", json!(row.get::<_, Option<i64>>("parent_task_id"))),
+        ("title", json!(row.get::<_, String>("title"))),
+        ("description", json!(row.get::<_, Option<String>>("description"))),
+        ("task_status_id", json!(row.get::<_, Option<i64>>("task_status_id"))),
+        ("priority_levels_id", json!(row.get::<_, Option<i64>>("priority_levels_id"))),
+        ("start_date", json!(row.get::<_, Option<NaiveDateTime>>("start_date"))),
+        ("due_date", json!(row.get::<_, Option<NaiveDateTime>>("due_date"))),
+    ]
+}
+
+// ค่าว่างทุก field ใช้เป็นค่าเก่าตอนสร้างและค่าใหม่ตอนลบ
+fn null_values() -> Vec<(&'static str, Value)> {
+    TASK_AUDIT_COLUMNS.split(", ").map(|column| (column, Value::Null)).collect()
+}
+
+fn deleted_values(row: &Row) -> Vec<(&'static str, Value)> {
+    vec![
+        ("deleted_at", json!(row.get::<_, Option<NaiveDateTime>>("deleted_at"))),
+        ("deleted_by", json!(row.get::<_, Option<i64>>("deleted_by"))),
+    ]
+}
+
+fn field_changes(old: &[(&'static str, Value)], new: &[(&'static str, Value)]) -> Vec<TaskFieldChange> {
+    old.iter()
+        .zip(new.iter())
+        .filter(|((_, old_value), (_, new_value))| old_value != new_value)
+        .map(|((field, old_value), (_, new_value))| TaskFieldChange {
+            field: field.to_string(),
+            old: old_value.clone(),
+            new: new_value.clone(),
+        })
+        .collect()
+}
+
+// version ที่ client ถืออยู่ต้องตรงกับใน database (None = ไม่ตรวจสอบ)
+fn ensure_version(id: i64, expected: Option<i32>, current: i32) -> Result<(), CustomError> {
+    match expected {
+        Some(expected) if expected != current => {
+            Err(CustomError::PreconditionFailed(format!("{}: {}", TASK_VERSION_MISMATCH, id)))
+        }
+        _ => Ok(()),
+    }
+}
+
+// การเปลี่ยนแปลงที่ไม่ผ่าน update_with_history (เช่น assign) ต้องเปลี่ยน ETag ด้วย
+async fn bump_version(transaction: &Transaction<'_>, task_id: i64) -> Result<(), CustomError> {
+    transaction
+        .execute("UPDATE public.task SET version = version + 1 WHERE id = $1;", &[&task_id])
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+    Ok(())
+}
+
+// id ของ subtask ทุกชั้นใต้ task นี้ (deleted_at = None คือที่ยังไม่ถูกลบ, Some คือที่ถูกลบไปพร้อมกันในเวลานั้น)
+async fn subtask_ids(transaction: &Transaction<'_>, task_id: i64, deleted_at: Option<NaiveDateTime>) -> Result<Vec<i64>, CustomError> {
+    let rows = transaction
+        .query(
+            "WITH RECURSIVE subtask AS (
+                 SELECT id FROM public.task WHERE parent_task_id = $1 AND deleted_at IS NOT DISTINCT FROM $2
+                 UNION
+                 SELECT t.id FROM public.task t JOIN subtask s ON t.parent_task_id = s.id WHERE t.deleted_at IS NOT DISTINCT FROM $2
+             )
+             SELECT id FROM subtask;",
+            &[&task_id, &deleted_at],
+        )
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+    Ok(rows.iter().map(|row| row.get("id")).collect())
+}
+
+async fn lock_task(transaction: &Transaction<'_>, task_id: i64) -> Result<(), CustomError> {
+    transaction
+        .query_opt("SELECT id FROM public.task WHERE id = $1 AND deleted_at IS NULL FOR UPDATE;", &[&task_id])
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?
+        .ok_or(CustomError::NotFound(format!("{}: {}", TASK_NOT_FOUND, task_id)))?;
+
+    Ok(())
+}
+
+// lock task ไว้กันการแก้ไข dependency พร้อมกัน แล้วคืน task ที่ block อยู่ปัจจุบัน
+async fn lock_blockers(transaction: &Transaction<'_>, task_id: i64) -> Result<Vec<i64>, CustomError> {
+    lock_task(transaction, task_id).await?;
+
+    let rows = transaction
+        .query(
+            "SELECT blocked_by_task_id FROM public.task_dependency WHERE task_id = $1 ORDER BY created_at, blocked_by_task_id;",
+            &[&task_id],
+        )
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+    Ok(rows.iter().map(|row| row.get("blocked_by_task_id")).collect())
+}
+
+// lock task ไว้กันการ assign พร้อมกัน แล้วคืนรายชื่อผู้รับผิดชอบปัจจุบัน
+async fn lock_assignees(transaction: &Transaction<'_>, task_id: i64) -> Result<Vec<i64>, CustomError> {
+    lock_task(transaction, task_id).await?;
+
+    let rows = transaction
+        .query(
+            "SELECT user_id FROM public.task_assignee WHERE task_id = $1 ORDER BY assigned_at, user_id;",
+            &[&task_id],
+        )
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+    Ok(rows.iter().map(|row| row.get("user_id")).collect())
+}
+
+// lock task ไว้กันการติด label พร้อมกัน แล้วคืนชื่อ label ปัจจุบัน
+async fn lock_labels(transaction: &Transaction<'_>, task_id: i64) -> Result<Vec<String>, CustomError> {
+    lock_task(transaction, task_id).await?;
+
+    task_label_names(transaction, task_id).await
+}
+
+async fn task_label_names(transaction: &Transaction<'_>, task_id: i64) -> Result<Vec<String>, CustomError> {
+    let rows = transaction
+        .query(
+            "SELECT l.name FROM public.task_label tl JOIN public.label l ON l.id = tl.label_id WHERE tl.task_id = $1 ORDER BY lower(l.name);",
+            &[&task_id],
+        )
+        .await
+        .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+    Ok(rows.iter().map(|row| row.get("name")).collect())
+}
+
+fn map_task_history(row: &Row) -> Result<TaskHistory, CustomError> {
+    let operation: String = row.get("operation");
+    let changes: Value = row.get("changes");
+
+    Ok(TaskHistory {
+        id: row.get("id"),
+        task_id: row.get("task_id"),
+        operation: TaskHistoryOperation::from_code(&operation)
+            .ok_or(CustomError::RepositoryError(format!("Unknown task history operation: {}", operation)))?,
+        changes: serde_json::from_value(changes)
+            .map_err(|e| CustomError::RepositoryError(format!("Invalid task history changes: {}", e)))?,
+        actor_id: row.get("actor_id"),
+        created_at: row.get("created_at"),
+    })
+}
+
+// เพดานของ recursive query กันวนไม่รู้จบ (ความลึกที่อนุญาตจริงตรวจใน use case)
+const MAX_SUBTASK_DEPTH: i32 = 100;
+
+// เลยกำหนดส่งและสถานะยังไม่ใช่ COMPLETED
+const OVERDUE_CONDITION: &str = "(due_date IS NOT NULL AND due_date < NOW() AND NOT EXISTS \
+    (SELECT 1 FROM public.master_data_task_status ts WHERE ts.id = task.task_status_id AND ts.code = 'COMPLETED'))";
+
+const TASK_COLUMNS: &str = "id, project_id, parent_task_id, title, description, task_status_id, priority_levels_id, created_by, created_at, updated_at, updated_by, start_date, due_date, deleted_at, deleted_by, version, \
+    ARRAY(SELECT ta.user_id FROM public.task_assignee ta WHERE ta.task_id = task.id ORDER BY ta.assigned_at, ta.user_id) AS assignees, \
+    ARRAY(SELECT d.blocked_by_task_id FROM public.task_dependency d JOIN public.task b ON b.id = d.blocked_by_task_id \
+          WHERE d.task_id = task.id AND b.deleted_at IS NULL ORDER BY d.created_at, d.blocked_by_task_id) AS blocked_by, \
+    ARRAY(SELECT d.task_id FROM public.task_dependency d JOIN public.task b ON b.id = d.task_id \
+          WHERE d.blocked_by_task_id = task.id AND b.deleted_at IS NULL ORDER BY d.created_at, d.task_id) AS blocks, \
+    COALESCE((SELECT json_agg(json_build_object('id', l.id, 'name', l.name, 'color', l.color) ORDER BY lower(l.name)) \
+              FROM public.task_label tl JOIN public.label l ON l.id = tl.label_id WHERE tl.task_id = task.id), '[]'::json) AS labels, \
+    (SELECT COUNT(*) FROM public.task st WHERE st.parent_task_id = task.id AND st.deleted_at IS NULL) AS subtask_count, \
+    (SELECT COUNT(*) FROM public.task st JOIN public.master_data_task_status ts ON ts.id = st.task_status_id AND ts.code = 'COMPLETED' \
+     WHERE st.parent_task_id = task.id AND st.deleted_at IS NULL) AS completed_subtask_count";
+
+// ข้อมูลที่ join เพิ่มตาม ?expand= (ไม่ขอ = ไม่ join)
+fn expand_columns(expand: TaskExpand) -> String {
+    let mut columns = String::new();
+    if expand.status {
+        columns.push_str(", (SELECT json_build_object('id', s.id, 'code', s.code, 'title', s.title) \
+            FROM public.master_data_task_status s WHERE s.id = task.task_status_id) AS task_status");
+    }
+    if expand.priority {
+        columns.push_str(", (SELECT json_build_object('id', p.id, 'code', p.code, 'title', p.title) \
+            FROM public.master_data_priority_levels p WHERE p.id = task.priority_levels_id) AS priority_levels");
+    }
+    if expand.created_by {
+        columns.push_str(", (SELECT json_build_object('id', u.id, 'username', u.username) \
+            FROM public.users u WHERE u.id = task.created_by) AS created_by_user");
+    }
+    columns
+}
+
+// column จาก expand_columns ถ้าไม่ได้ขอจะไม่มี column นี้ใน row (คืน None)
+// ส่วน column ที่ขอมาแต่แปลงค่าไม่ได้ต้องเป็น error ไม่ใช่ null
+fn expanded<T: DeserializeOwned>(row: &Row, column: &str) -> Result<Option<T>, CustomError> {
+    if !row.columns().iter().any(|row_column| row_column.name() == column) {
+        return Ok(None);
+    }
+
+    let value: Option<Value> = row
+        .try_get(column)
+        .map_err(|e| CustomError::RepositoryError(format!("Invalid task {}: {}", column, e)))?;
+    value
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| CustomError::RepositoryError(format!("Invalid task {}: {}", column, e)))
+}
+
+fn map_task(row: &Row) -> Result<Task, CustomError> {
+    let labels: Value = row.get("labels");
+
+    Ok(Task {
+        id: row.get("id"),
+        project_id: row.get("project_id"),
+        parent_task_id: row.get("parent_task_id"),
+        title: row.get("title"),
+        description: row.get("description"),
+        task_status_id: row.get("task_status_id"),
+        priority_levels_id: row.get("priority_levels_id"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        updated_by: row.get("updated_by"),
+        assignees: row.get("assignees"),
+        start_date: row.get("start_date"),
+        due_date: row.get("due_date"),
+        overdue: row.get("overdue"),
+        deleted_at: row.get("deleted_at"),
+        deleted_by: row.get("deleted_by"),
+        version: row.get("version"),
+        subtask_count: row.get("subtask_count"),
+        completed_subtask_count: row.get("completed_subtask_count"),
+        blocked_by: row.get("blocked_by"),
+        blocks: row.get("blocks"),
+        labels: serde_json::from_value(labels)
+            .map_err(|e| CustomError::RepositoryError(format!("Invalid task labels: {}", e)))?,
+        task_status: expanded(row, "task_status")?,
+        priority_levels: expanded(row, "priority_levels")?,
+        created_by_user: expanded(row, "created_by_user")?,
+    })
+}
+
+// ข้อความไม่ใช่ HTML: แปลง < > เป็น \x02 \x03 ก่อนส่งให้ parser (เหมือน search_vector ใน migration)
+// และครอบคำที่ตรงด้วย \x0e \x0f แล้วค่อยแปลงเป็น <mark> หลัง escape HTML ใน highlight_html
+const LESS_THAN: char = '\u{2}';
+const GREATER_THAN: char = '\u{3}';
+const HIGHLIGHT_START: char = '\u{e}';
+const HIGHLIGHT_STOP: char = '\u{f}';
+const TITLE_HEADLINE_OPTIONS: &str = r"E'HighlightAll=true, StartSel=\x0e, StopSel=\x0f'";
+const SNIPPET_HEADLINE_OPTIONS: &str = r#"E'StartSel=\x0e, StopSel=\x0f, MaxFragments=2, MaxWords=30, MinWords=10, FragmentDelimiter=" ... "'"#;
+
+fn search_text(column: &str) -> String {
+    format!(r"translate({}, '<>', E'\x02\x03')", column)
+}
+
+fn highlight_html(headline: &str) -> String {
+    let mut html = String::with_capacity(headline.len());
+    for c in headline.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' | LESS_THAN => html.push_str("&lt;"),
+            '>' | GREATER_THAN => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            HIGHLIGHT_START => html.push_str("<mark>"),
+            HIGHLIGHT_STOP => html.push_str("</mark>"),
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+// snippet ของ description มีเฉพาะเมื่อคำค้นตรงกับ description
+fn matched_highlight(headline: Option<&str>) -> Option<String> {
+    headline.filter(|headline| headline.contains(HIGHLIGHT_START)).map(highlight_html)
+}
+
+fn map_task_search_hit(row: &Row) -> Result<TaskSearchHit, CustomError> {
+    Ok(TaskSearchHit {
+        task: map_task(row)?,
+        rank: row.get("rank"),
+        highlight: TaskSearchHighlight {
+            title: highlight_html(row.get("title_highlight")),
+            description: matched_highlight(row.get("description_highlight")),
+            comment_id: row.get("comment_id"),
+            comment: row.get::<_, Option<&str>>("comment_highlight").map(highlight_html),
+        },
+    })
+}
+
+// column ที่ใช้ sort และแปลงค่าเป็น text สำหรับเก็บใน cursor
+fn sort_column(sort_by: TaskSortField) -> (&'static str, Option<&'static str>) {
+    match sort_by {
+        TaskSortField::Id => ("id", None),
+        TaskSortField::CreatedAt => ("created_at", Some("timestamp")),
+        TaskSortField::UpdatedAt => ("COALESCE(updated_at, created_at)", Some("timestamp")),
+        TaskSortField::Title => ("title", Some("text")),
+    }
+}
+
+fn cursor_value(task: &Task, sort_by: TaskSortField) -> Option<String> {
+    match sort_by {
+        TaskSortField::Id => None,
+        TaskSortField::CreatedAt => Some(task.created_at.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        TaskSortField::UpdatedAt => Some(task.updated_at.unwrap_or(task.created_at).format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        TaskSortField::Title => Some(task.title.clone()),
+    }
+}
+
+fn apply_task_filter(query: &mut QueryBuilder, filter: &TaskFilter) {
+    if filter.deleted {
+        query.and_where("deleted_at IS NOT NULL".to_string());
+    } else {
+        query.and_where("deleted_at IS NULL".to_string());
+    }
+    if let Some(project_id) = filter.project_id {
+        let p = query.bind(project_id);
+        query.and_where(format!("project_id = {}", p));
+    }
+    if let Some(parent_task_id) = filter.parent_task_id {
+        let p = query.bind(parent_task_id);
+        query.and_where(format!("parent_task_id = {}", p));
+    }
+    if let Some(task_status_id) = filter.task_status_id {
+        let p = query.bind(task_status_id);
+        query.and_where(format!("task_status_id = {}", p));
+    }
+    if let Some(priority_levels_id) = filter.priority_levels_id {
+        let p = query.bind(priority_levels_id);
+        query.and_where(format!("priority_levels_id = {}", p));
+    }
+    if let Some(created_by) = filter.created_by {
+        let p = query.bind(created_by);
+        query.and_where(format!("created_by = {}", p));
+    }
+    if let Some(assignee) = filter.assignee {
+        let p = query.bind(assignee);
+        query.and_where(format!("EXISTS (SELECT 1 FROM public.task_assignee ta WHERE ta.task_id = task.id AND ta.user_id = {})", p));
+    }
+    if let Some(member_id) = filter.member_id {
+        let p = query.bind(member_id);
+        // ตรงกับตารางสิทธิ์ใน use case: ถังขยะดูได้เฉพาะ Admin ของ project, Member เห็นเฉพาะที่สร้างเองหรือได้รับมอบหมาย
+        let role_condition = if filter.deleted {
+            "r.code = 'ADMIN'".to_string()
+        } else {
+            format!(
+                "(r.code <> 'MEMBER' OR task.created_by = {0} OR EXISTS (SELECT 1 FROM public.task_assignee ta WHERE ta.task_id = task.id AND ta.user_id = {0}))",
+                p
+            )
+        };
+        query.and_where(format!(
+            "EXISTS (SELECT 1 FROM public.project_member pm JOIN public.master_data_role r ON r.id = pm.role_id AND r.active = true \
+             WHERE pm.project_id = task.project_id AND pm.user_id = {} AND {})",
+            p,
+            role_condition,
+        ));
+    }
+    if let Some(created_from) = filter.created_from {
+        let p = query.bind(created_from);
+        query.and_where(format!("created_at >= {}", p));
+    }
+    if let Some(created_to) = filter.created_to {
+        let p = query.bind(created_to);
+        query.and_where(format!("created_at <= {}", p));
+    }
+    if let Some(updated_from) = filter.updated_from {
+        let p = query.bind(updated_from);
+        query.and_where(format!("updated_at >= {}", p));
+    }
+    if let Some(updated_to) = filter.updated_to {
+        let p = query.bind(updated_to);
+        query.and_where(format!("updated_at <= {}", p));
+    }
+    if let Some(due_before) = filter.due_before {
+        let p = query.bind(due_before);
+        query.and_where(format!("due_date < {}", p));
+    }
+    if filter.due_this_week {
+        // สัปดาห์ปัจจุบันเริ่มวันจันทร์
+        query.and_where("due_date >= date_trunc('week', NOW()) AND due_date < date_trunc('week', NOW()) + INTERVAL '1 week'".to_string());
+    }
+    match filter.overdue {
+        Some(true) => query.and_where(OVERDUE_CONDITION.to_string()),
+        Some(false) => query.and_where(format!("NOT {}", OVERDUE_CONDITION)),
+        None => {}
+    }
+    if let Some(search) = filter.search.as_deref().filter(|search| !search.is_empty()) {
+        let p = query.bind(like_pattern(search));
+        query.and_where(format!("(title ILIKE {0} OR description ILIKE {0})", p));
+    }
+    if !filter.labels.is_empty() {
+        let label_count = filter.labels.len() as i64;
+        let p = query.bind(filter.labels.clone());
+        match filter.label_match {
+            LabelMatch::Any => query.and_where(format!(
+                "EXISTS (SELECT 1 FROM public.task_label tl JOIN public.label l ON l.id = tl.label_id \
+                 WHERE tl.task_id = task.id AND lower(l.name) = ANY({}))",
+                p,
+            )),
+            LabelMatch::All => {
+                let count = query.bind(label_count);
+                query.and_where(format!(
+                    "(SELECT COUNT(*) FROM public.task_label tl JOIN public.label l ON l.id = tl.label_id \
+                     WHERE tl.task_id = task.id AND lower(l.name) = ANY({})) = {}",
+                    p,
+                    count,
+                ));
+            }
+        }
+    }
 }
 
 #[async_trait]
 impl<S: Snowflake + Send + Sync> TaskRepositories for TaskRepositoriesImpl<S> {
-    async fn list_task(&self) -> Result<Vec<Task>, CustomError> {
+    async fn list_task(&self, filter: TaskFilter) -> Result<TaskPage, CustomError> {
         let client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
 
+        let mut query = QueryBuilder::new();
+        apply_task_filter(&mut query, &filter);
+
+        let (column, cast) = sort_column(filter.sort_by);
+        let (operator, direction) = match filter.sort_direction {
+            SortDirection::Asc => (">", "ASC"),
+            SortDirection::Desc => ("<", "DESC"),
+        };
+
+        if let Some(cursor) = &filter.cursor {
+            let id = query.bind(cursor.id);
+            match (cast, &cursor.value) {
+                (None, _) => query.and_where(format!("id {} {}", operator, id)),
+                (Some(cast), Some(value)) => {
+                    let value = query.bind(value.clone());
+                    query.and_where(format!("({}, id) {} ({}::text::{}, {})", column, operator, value, cast, id));
+                }
+                (Some(_), None) => return Err(CustomError::ValidationError(INVALID_CURSOR.to_string())),
+            }
+        }
+
+        // ดึงเกิน 1 แถวเพื่อดูว่ายังมีหน้าถัดไปหรือไม่
+        let sql = format!(
+            "SELECT {}{}, {} AS overdue FROM public.task{} ORDER BY {} {}, id {} LIMIT {};",
+            TASK_COLUMNS,
+            expand_columns(filter.expand),
+            OVERDUE_CONDITION,
+            query.where_clause(),
+            column,
+            direction,
+            direction,
+            filter.limit + 1,
+        );
+
         let rows = client
-            .query(
-                "SELECT id, title, description, task_status_id, priority_levels_id, created_by, created_at, updated_at, updated_by FROM public.task;",
-                &[],
-            )
+            .query(sql.as_str(), &query.params())
             .await.map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
 
-        let tasks: Vec<Task> = rows
-            .iter()
-            .map(|row| Task {
-                id: row.get("id"),
-                title: row.get("title"),
-                description: row.get("description"),
-                task_status_id: row.get("task_status_id"),
-                priority_levels_id: row.get("priority_levels_id"),
-                created_by: row.get("created_by"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-                updated_by: row.get("updated_by"),
-            })
-            .collect();
-
-        Ok(tasks)
+        let mut tasks = rows.iter().map(map_task).collect::<Result<Vec<Task>, CustomError>>()?;
+
+        let has_more = tasks.len() as i64 > filter.limit;
+        tasks.truncate(filter.limit as usize);
+
+        let next_cursor = if has_more {
+            tasks.last().map(|task| TaskCursor {
+                id: task.id,
+                value: cursor_value(task, filter.sort_by),
+            }.encode())
+        } else {
+            None
+        };
+
+        Ok(TaskPage {
+            items: tasks,
+            next_cursor,
+            has_more,
+        })
     }
+    async fn search_task(&self, mut filter: TaskFilter) -> Result<TaskSearchPage, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let mut query = QueryBuilder::new();
+        // คำค้นใช้กับ tsvector แทน ILIKE ของ list_task
+        let search_query = query.bind(filter.search.take().unwrap_or_default());
+        apply_task_filter(&mut query, &filter);
+        query.and_where("(task.search_vector @@ search.query OR best_comment.comment_id IS NOT NULL)".to_string());
+
+        // rank ของ task ก่อนหน้าใน cursor เก็บเป็น text ของ real
+        let cursor_condition = match &filter.cursor {
+            Some(TaskCursor { id, value: Some(rank) }) => {
+                let id = query.bind(*id);
+                let rank = query.bind(rank.clone());
+                format!(" WHERE (rank, id) < ({}::text::real, {})", rank, id)
+            }
+            Some(TaskCursor { value: None, .. }) => return Err(CustomError::ValidationError(INVALID_CURSOR.to_string())),
+            None => String::new(),
+        };
+
+        // comment มีน้ำหนักครึ่งหนึ่งของ task และนับเฉพาะ comment ที่ตรงที่สุด
+        let sql = format!(
+            "SELECT * FROM (
+                 SELECT {columns}{expand_columns}, {overdue} AS overdue,
+                        (ts_rank(task.search_vector, search.query) + COALESCE(best_comment.comment_rank, 0) * 0.5)::real AS rank,
+                        ts_headline('simple', {title}, search.query, {title_options}) AS title_highlight,
+                        ts_headline('simple', {description}, search.query, {options}) AS description_highlight,
+                        best_comment.comment_id,
+                        best_comment.comment_highlight
+                 FROM public.task
+                 CROSS JOIN (SELECT websearch_to_tsquery('simple', {search_query}) AS query) search
+                 LEFT JOIN LATERAL (
+                     SELECT c.id AS comment_id,
+                            ts_rank(c.search_vector, search.query) AS comment_rank,
+                            ts_headline('simple', {body}, search.query, {options}) AS comment_highlight
+                     FROM public.task_comment c
+                     WHERE c.task_id = task.id AND c.search_vector @@ search.query
+                     ORDER BY comment_rank DESC, c.id ASC
+                     LIMIT 1
+                 ) best_comment ON true{where_clause}
+             ) result{cursor_condition}
+             ORDER BY rank DESC, id DESC
+             LIMIT {limit};",
+            columns = TASK_COLUMNS,
+            expand_columns = expand_columns(filter.expand),
+            overdue = OVERDUE_CONDITION,
+            title = search_text("task.title"),
+            description = search_text("task.description"),
+            body = search_text("c.body"),
+            title_options = TITLE_HEADLINE_OPTIONS,
+            options = SNIPPET_HEADLINE_OPTIONS,
+            search_query = search_query,
+            where_clause = query.where_clause(),
+            cursor_condition = cursor_condition,
+            limit = filter.limit + 1,
+        );
+
+        let rows = client
+            .query(sql.as_str(), &query.params())
+            .await.map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let mut hits = rows.iter().map(map_task_search_hit).collect::<Result<Vec<TaskSearchHit>, CustomError>>()?;
+
+        let has_more = hits.len() as i64 > filter.limit;
+        hits.truncate(filter.limit as usize);
+
+        let next_cursor = if has_more {
+            hits.last().map(|hit| TaskCursor {
+                id: hit.task.id,
+                value: Some(hit.rank.to_string()),
+            }.encode())
+        } else {
+            None
+        };
+
+        Ok(TaskSearchPage {
+            items: hits,
+            next_cursor,
+            has_more,
+        })
+    }
+
     async fn get_task(&self, id: i64) -> Result<Task, CustomError> {
+        self.get_task_expanded(id, TaskExpand::default()).await
+    }
+
+    async fn get_task_expanded(&self, id: i64, expand: TaskExpand) -> Result<Task, CustomError> {
         let client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
 
         let row = client
             .query_one(
-                "SELECT id, title, description, task_status_id, priority_levels_id, created_by, created_at, updated_at, updated_by FROM public.task WHERE id = $1;",
+                format!(
+                    "SELECT {}{}, {} AS overdue FROM public.task WHERE id = $1 AND deleted_at IS NULL;",
+                    TASK_COLUMNS,
+                    expand_columns(expand),
+                    OVERDUE_CONDITION,
+                ).as_str(),
                 &[&id],
             )
             .await.map_err(|e| {
@@ -67,143 +821,658 @@ impl<S: Snowflake + Send + Sync> TaskRepositories for TaskRepositoriesImpl<S> {
             CustomError::RepositoryError(format!("Database query failed: {}", e))
         })?;
 
-        let task = Task {
-            id: row.get("id"),
-            title: row.get("title"),
-            description: row.get("description"),
-            task_status_id: row.get("task_status_id"),
-            priority_levels_id: row.get("priority_levels_id"),
-            created_by: row.get("created_by"),
-            created_at: row.get("created_at"),
-            updated_at: row.get("updated_at"),
-            updated_by: row.get("updated_by"),
-        };
-
-        Ok(task)
+        map_task(&row)
     }
     async fn create_task(&self, task: TaskCreateEntity) -> Result<i64, CustomError> {
-        let client = self.db_conn.get().await.map_err(|e| {
+        let mut client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
-        let new_id = self.snowflake_id.generate() as i64;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
 
-        let row = client
-            .query_one(
-                "INSERT INTO public.task (id, title, description, task_status_id, priority_levels_id, created_by, created_at) VALUES ($1, $2, $3, $4, $5, $6, NOW()) RETURNING id;",
-                &[
-                    &new_id,
-                    &task.title,
-                    &task.description,
-                    &task.task_status_id,
-                    &task.priority_levels_id,
-                    &task.created_by,
-                ],
-            )
-            .await
-            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        let id = self.insert_task(&transaction, &task).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
 
-        Ok(row.get(0))
+        Ok(id)
     }
 
-    async fn update_task(&self, task: UpdateTask) -> Result<(), CustomError> {
-        let client = self.db_conn.get().await.map_err(|e| {
+    async fn create_tasks(&self, tasks: Vec<TaskCreateEntity>) -> Result<Vec<i64>, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
 
-        client
-            .execute(
-                "UPDATE public.task
+        let mut ids = Vec::with_capacity(tasks.len());
+        for task in &tasks {
+            ids.push(self.insert_task(&transaction, task).await?);
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(ids)
+    }
+
+    async fn update_task(&self, task: UpdateTask) -> Result<(), CustomError> {
+        self.update_with_history(
+            task.id,
+            task.version,
+            task.updated_by,
+            "UPDATE public.task
              SET title = $1,
                  description = $2,
                  task_status_id = $3,
                  priority_levels_id = $4,
+                 start_date = $5,
+                 due_date = $6,
                  updated_at = NOW(),
-                 updated_by = $5
-             WHERE id = $6;",
-                &[
-                    &task.title,
-                    &task.description,
-                    &task.task_status_id,
-                    &task.priority_levels_id,
-                    &task.updated_by,
-                    &task.id,
-                ],
+                 updated_by = $7,
+                 project_id = $9,
+                 parent_task_id = $10,
+                 version = version + 1
+             WHERE id = $8",
+            &[
+                &task.title,
+                &task.description,
+                &task.task_status_id,
+                &task.priority_levels_id,
+                &task.start_date,
+                &task.due_date,
+                &task.updated_by,
+                &task.id,
+                &task.project_id,
+                &task.parent_task_id,
+            ],
+        ).await
+    }
+
+    async fn patch_task(&self, task: PatchTask) -> Result<(), CustomError> {
+        // SET เฉพาะ column ที่ส่งมา
+        let mut query = QueryBuilder::new();
+        let mut columns = Vec::new();
+        if let Some(project_id) = task.project_id {
+            columns.push(format!("project_id = {}", query.bind(project_id)));
+        }
+        if let Some(parent_task_id) = task.parent_task_id {
+            columns.push(format!("parent_task_id = {}", query.bind(parent_task_id)));
+        }
+        if let Some(title) = task.title {
+            columns.push(format!("title = {}", query.bind(title)));
+        }
+        if let Some(description) = task.description {
+            columns.push(format!("description = {}", query.bind(description)));
+        }
+        if let Some(task_status_id) = task.task_status_id {
+            columns.push(format!("task_status_id = {}", query.bind(task_status_id)));
+        }
+        if let Some(priority_levels_id) = task.priority_levels_id {
+            columns.push(format!("priority_levels_id = {}", query.bind(priority_levels_id)));
+        }
+        if let Some(start_date) = task.start_date {
+            columns.push(format!("start_date = {}", query.bind(start_date)));
+        }
+        if let Some(due_date) = task.due_date {
+            columns.push(format!("due_date = {}", query.bind(due_date)));
+        }
+
+        let updated_by = query.bind(task.updated_by);
+        let id = query.bind(task.id);
+        let sql = format!(
+            "UPDATE public.task SET {}, updated_at = NOW(), updated_by = {}, version = version + 1 WHERE id = {}",
+            columns.join(", "),
+            updated_by,
+            id,
+        );
+
+        self.update_with_history(task.id, task.version, task.updated_by, &sql, &query.params()).await
+    }
+
+    async fn update_task_status(&self, task: UpdateTaskStatus) -> Result<(), CustomError> {
+        self.update_with_history(
+            task.id,
+            task.version,
+            task.updated_by,
+            UPDATE_TASK_STATUS_SQL,
+            &[&task.task_status_id, &task.updated_by, &task.id],
+        ).await
+    }
+
+    async fn update_task_priority_levels(
+        &self,
+        task: UpdateTaskPriorityLevels,
+    ) -> Result<(), CustomError> {
+        self.update_with_history(
+            task.id,
+            task.version,
+            task.updated_by,
+            UPDATE_TASK_PRIORITY_LEVELS_SQL,
+            &[&task.priority_levels_id, &task.updated_by, &task.id],
+        ).await
+    }
+
+    async fn delete_task(&self, id: i64, deleted_by: i64, version: Option<i32>) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        self.delete_in_transaction(&transaction, id, deleted_by, version).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn assign_task(&self, assign: TaskAssign) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        self.assign_in_transaction(&transaction, &assign).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn bulk_task(&self, items: Vec<TaskBulkItem>, mode: TaskBulkMode, actor_id: i64) -> Result<Vec<TaskBulkItemResult>, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let mut transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        let mut results = Vec::with_capacity(items.len());
+        for item in &items {
+            let error = match mode {
+                // error แรกยกเลิกทั้ง transaction (drop โดยไม่ commit = rollback)
+                TaskBulkMode::Atomic => {
+                    self.bulk_item(&transaction, item, actor_id)
+                        .await
+                        .map_err(|e| e.with_context(&item.context()))?;
+                    None
+                }
+                // รายการที่ล้มย้อนกลับเฉพาะ savepoint ของตัวเอง
+                TaskBulkMode::BestEffort => {
+                    let savepoint = transaction.savepoint("bulk_item").await.map_err(|e| {
+                        CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+                    })?;
+                    match self.bulk_item(&savepoint, item, actor_id).await {
+                        Ok(()) => {
+                            savepoint.commit().await.map_err(|e| {
+                                CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+                            })?;
+                            None
+                        }
+                        Err(e) => Some(e.to_string()),
+                    }
+                }
+            };
+            results.push(TaskBulkItemResult::new(item.operation_index, item.action.code(), item.task_id, error));
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(results)
+    }
+
+    async fn unassign_task(&self, task_id: i64, user_id: i64, unassigned_by: i64) -> Result<bool, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        let old_assignees = lock_assignees(&transaction, task_id).await?;
+
+        let deleted = transaction
+            .execute(
+                "DELETE FROM public.task_assignee WHERE task_id = $1 AND user_id = $2;",
+                &[&task_id, &user_id],
             )
             .await
             .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
 
-        Ok(())
+        if deleted == 0 {
+            return Ok(false);
+        }
+
+        let new_assignees: Vec<i64> = old_assignees.iter().copied().filter(|id| *id != user_id).collect();
+        let changes = field_changes(&[("assignees", json!(old_assignees))], &[("assignees", json!(new_assignees))]);
+        self.insert_history(&transaction, task_id, TaskHistoryOperation::Unassign, changes, unassigned_by).await?;
+        bump_version(&transaction, task_id).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(true)
     }
 
-    async fn update_task_status(&self, task: UpdateTaskStatus) -> Result<(), CustomError> {
-        let client = self.db_conn.get().await.map_err(|e| {
+    async fn add_labels(&self, labels: TaskLabelAdd) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
 
-        client
+        let old_labels = lock_labels(&transaction, labels.task_id).await?;
+
+        // ติด label ซ้ำได้โดยไม่ error
+        let inserted = transaction
             .execute(
-                "UPDATE public.task
-                 SET task_status_id = $1,
-                     updated_at = NOW(),
-                     updated_by = $2
-                 WHERE id = $3;",
-                &[&task.task_status_id, &task.updated_by, &task.id],
+                "INSERT INTO public.task_label (task_id, label_id, labeled_by, labeled_at)
+                 SELECT $1, label_id, $3, NOW() FROM UNNEST($2::bigint[]) AS label_id
+                 ON CONFLICT (task_id, label_id) DO NOTHING;",
+                &[&labels.task_id, &labels.label_ids, &labels.labeled_by],
             )
             .await
             .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
 
+        if inserted > 0 {
+            let new_labels = task_label_names(&transaction, labels.task_id).await?;
+
+            let changes = field_changes(&[("labels", json!(old_labels))], &[("labels", json!(new_labels))]);
+            self.insert_history(&transaction, labels.task_id, TaskHistoryOperation::Label, changes, labels.labeled_by).await?;
+            bump_version(&transaction, labels.task_id).await?;
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
         Ok(())
     }
 
-    async fn update_task_priority_levels(
-        &self,
-        task: UpdateTaskPriorityLevels,
-    ) -> Result<(), CustomError> {
+    async fn remove_label(&self, task_id: i64, label_id: i64, removed_by: i64) -> Result<bool, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        let old_labels = lock_labels(&transaction, task_id).await?;
+
+        let deleted = transaction
+            .execute(
+                "DELETE FROM public.task_label WHERE task_id = $1 AND label_id = $2;",
+                &[&task_id, &label_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        if deleted == 0 {
+            return Ok(false);
+        }
+
+        let new_labels = task_label_names(&transaction, task_id).await?;
+        let changes = field_changes(&[("labels", json!(old_labels))], &[("labels", json!(new_labels))]);
+        self.insert_history(&transaction, task_id, TaskHistoryOperation::Unlabel, changes, removed_by).await?;
+        bump_version(&transaction, task_id).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(true)
+    }
+
+    async fn existing_label_ids(&self, label_ids: Vec<i64>) -> Result<Vec<i64>, CustomError> {
         let client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
 
-        client
+        let rows = client
+            .query("SELECT id FROM public.label WHERE id = ANY($1);", &[&label_ids])
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
+    async fn add_dependency(&self, dependency: TaskDependency) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        let old_blockers = lock_blockers(&transaction, dependency.task_id).await?;
+
+        // เพิ่มซ้ำได้โดยไม่ error
+        let inserted = transaction
             .execute(
-                "UPDATE public.task
-                 SET priority_levels_id = $1,
-                     updated_at = NOW(),
-                     updated_by = $2
-                 WHERE id = $3;",
-                &[&task.priority_levels_id, &task.updated_by, &task.id],
+                "INSERT INTO public.task_dependency (task_id, blocked_by_task_id, created_by, created_at) VALUES ($1, $2, $3, NOW())
+                 ON CONFLICT (task_id, blocked_by_task_id) DO NOTHING;",
+                &[&dependency.task_id, &dependency.blocked_by_task_id, &dependency.created_by],
             )
             .await
             .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
 
+        if inserted > 0 {
+            let mut new_blockers = old_blockers.clone();
+            new_blockers.push(dependency.blocked_by_task_id);
+
+            let changes = field_changes(&[("blocked_by", json!(old_blockers))], &[("blocked_by", json!(new_blockers))]);
+            self.insert_history(&transaction, dependency.task_id, TaskHistoryOperation::Link, changes, dependency.created_by).await?;
+            bump_version(&transaction, dependency.task_id).await?;
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
         Ok(())
     }
 
-    async fn delete_task(&self, id: i64) -> Result<(), CustomError> {
+    async fn remove_dependency(&self, task_id: i64, blocked_by_task_id: i64, removed_by: i64) -> Result<bool, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        let old_blockers = lock_blockers(&transaction, task_id).await?;
+
+        let deleted = transaction
+            .execute(
+                "DELETE FROM public.task_dependency WHERE task_id = $1 AND blocked_by_task_id = $2;",
+                &[&task_id, &blocked_by_task_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        if deleted == 0 {
+            return Ok(false);
+        }
+
+        let new_blockers: Vec<i64> = old_blockers.iter().copied().filter(|id| *id != blocked_by_task_id).collect();
+        let changes = field_changes(&[("blocked_by", json!(old_blockers))], &[("blocked_by", json!(new_blockers))]);
+        self.insert_history(&transaction, task_id, TaskHistoryOperation::Unlink, changes, removed_by).await?;
+        bump_version(&transaction, task_id).await?;
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(true)
+    }
+
+    async fn transitive_blocker_ids(&self, id: i64) -> Result<Vec<i64>, CustomError> {
         let client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
 
-        client
-            .execute("DELETE FROM public.task WHERE id = $1;", &[&id])
+        let rows = client
+            .query(
+                "WITH RECURSIVE blocker AS (
+                     SELECT blocked_by_task_id AS id FROM public.task_dependency WHERE task_id = $1
+                     UNION
+                     SELECT d.blocked_by_task_id FROM public.task_dependency d JOIN blocker b ON d.task_id = b.id
+                 )
+                 SELECT id FROM blocker;",
+                &[&id],
+            )
             .await
             .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
 
-        Ok(())
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
+    async fn open_blocker_ids(&self, id: i64) -> Result<Vec<i64>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let rows = client
+            .query(
+                "SELECT b.id FROM public.task_dependency d
+                 JOIN public.task b ON b.id = d.blocked_by_task_id AND b.deleted_at IS NULL
+                 LEFT JOIN public.master_data_task_status ts ON ts.id = b.task_status_id
+                 WHERE d.task_id = $1 AND ts.code IS DISTINCT FROM 'COMPLETED'
+                 ORDER BY d.created_at, b.id;",
+                &[&id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
+    async fn active_user_ids(&self, user_ids: Vec<i64>, project_id: i64) -> Result<Vec<i64>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let rows = client
+            .query(
+                "SELECT u.id FROM public.users u
+                 JOIN public.project_member pm ON pm.user_id = u.id AND pm.project_id = $2
+                 WHERE u.id = ANY($1) AND u.active = true;",
+                &[&user_ids, &project_id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
+    async fn list_task_history(&self, filter: TaskHistoryFilter) -> Result<TaskHistoryPage, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let mut query = QueryBuilder::new();
+        let task_id = query.bind(filter.task_id);
+        query.and_where(format!("task_id = {}", task_id));
+        if let Some(cursor) = &filter.cursor {
+            let id = query.bind(cursor.id);
+            query.and_where(format!("id < {}", id));
+        }
+
+        // ใหม่สุดก่อน ดึงเกิน 1 แถวเพื่อดูว่ายังมีหน้าถัดไปหรือไม่
+        let sql = format!(
+            "SELECT id, task_id, operation, changes, actor_id, created_at FROM public.task_history{} ORDER BY id DESC LIMIT {};",
+            query.where_clause(),
+            filter.limit + 1,
+        );
+
+        let rows = client
+            .query(sql.as_str(), &query.params())
+            .await.map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let mut items = rows.iter().map(map_task_history).collect::<Result<Vec<TaskHistory>, CustomError>>()?;
+
+        let has_more = items.len() as i64 > filter.limit;
+        items.truncate(filter.limit as usize);
+
+        let next_cursor = if has_more {
+            items.last().map(|history| TaskCursor { id: history.id, value: None }.encode())
+        } else {
+            None
+        };
+
+        Ok(TaskHistoryPage {
+            items,
+            next_cursor,
+            has_more,
+        })
+    }
+
+    async fn task_ancestor_ids(&self, id: i64) -> Result<Vec<i64>, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        // UNION ตัดแถวซ้ำ จึงไม่วนไม่รู้จบแม้ข้อมูลจะมี cycle
+        let rows = client
+            .query(
+                "WITH RECURSIVE ancestor AS (
+                     SELECT id, parent_task_id FROM public.task WHERE id = $1
+                     UNION
+                     SELECT t.id, t.parent_task_id FROM public.task t JOIN ancestor a ON t.id = a.parent_task_id
+                 )
+                 SELECT id FROM ancestor;",
+                &[&id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
     }
 
-    async fn task_exists(&self, id: i64) -> Result<bool, CustomError> {
+    async fn subtask_depth(&self, id: i64) -> Result<i32, CustomError> {
         let client = self.db_conn.get().await.map_err(|e| {
             CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
         })?;
 
         let row = client
             .query_one(
-                "SELECT (COUNT(id) > 0) as is_already_exists FROM public.task WHERE id = $1;",
+                "WITH RECURSIVE subtask AS (
+                     SELECT id, 1 AS depth FROM public.task WHERE parent_task_id = $1 AND deleted_at IS NULL
+                     UNION
+                     SELECT t.id, s.depth + 1 FROM public.task t JOIN subtask s ON t.parent_task_id = s.id
+                     WHERE t.deleted_at IS NULL AND s.depth < $2
+                 )
+                 SELECT COALESCE(MAX(depth), 0) AS depth FROM subtask;",
+                &[&id, &MAX_SUBTASK_DEPTH],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        Ok(row.get("depth"))
+    }
+
+    async fn get_deleted_task(&self, id: i64) -> Result<Task, CustomError> {
+        let client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+
+        let row = client
+            .query_opt(
+                format!("SELECT {}, {} AS overdue FROM public.task WHERE id = $1 AND deleted_at IS NOT NULL;", TASK_COLUMNS, OVERDUE_CONDITION).as_str(),
                 &[&id],
             )
             .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?
+            .ok_or(CustomError::NotFound(format!("{}: {}", TASK_NOT_FOUND_IN_TRASH, id)))?;
+
+        map_task(&row)
+    }
+
+    async fn restore_task(&self, id: i64, restored_by: i64) -> Result<(), CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        let old = transaction
+            .query_opt(
+                "SELECT deleted_at, deleted_by FROM public.task WHERE id = $1 AND deleted_at IS NOT NULL FOR UPDATE;",
+                &[&id],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?
+            .ok_or(CustomError::NotFound(format!("{}: {}", TASK_NOT_FOUND_IN_TRASH, id)))?;
+
+        transaction
+            .execute(
+                "UPDATE public.task SET deleted_at = NULL, deleted_by = NULL, updated_at = NOW(), updated_by = $2, version = version + 1 WHERE id = $1;",
+                &[&id, &restored_by],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let changes = field_changes(&deleted_values(&old), &[("deleted_at", Value::Null), ("deleted_by", Value::Null)]);
+        self.insert_history(&transaction, id, TaskHistoryOperation::Restore, changes, restored_by).await?;
+
+        // subtask ที่ถูกลบไปพร้อมกับ task นี้ (ไม่รวมที่ถูกลบแยกไว้ก่อนหน้า)
+        let subtask_ids = subtask_ids(&transaction, id, old.get("deleted_at")).await?;
+        transaction
+            .execute(
+                "UPDATE public.task SET deleted_at = NULL, deleted_by = NULL, updated_at = NOW(), updated_by = $2, version = version + 1 WHERE id = ANY($1);",
+                &[&subtask_ids, &restored_by],
+            )
+            .await
             .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+        for subtask_id in subtask_ids {
+            let changes = field_changes(&deleted_values(&old), &[("deleted_at", Value::Null), ("deleted_by", Value::Null)]);
+            self.insert_history(&transaction, subtask_id, TaskHistoryOperation::Restore, changes, restored_by).await?;
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn purge_deleted_task(&self, deleted_before: NaiveDateTime) -> Result<TaskPurgeResult, CustomError> {
+        let mut client = self.db_conn.get().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to get database connection: {}", e))
+        })?;
+        let transaction = client.transaction().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to begin transaction: {}", e))
+        })?;
+
+        // ลบ metadata ไฟล์แนบเองแทนการ cascade เพื่อเก็บ key ไว้ลบไฟล์ใน storage
+        let attachment_rows = transaction
+            .query(
+                "DELETE FROM public.task_attachment
+                 WHERE task_id IN (SELECT id FROM public.task WHERE deleted_at IS NOT NULL AND deleted_at < $1)
+                 RETURNING storage_key;",
+                &[&deleted_before],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        let rows = transaction
+            .query(
+                format!("DELETE FROM public.task WHERE deleted_at IS NOT NULL AND deleted_at < $1 RETURNING id, {};", TASK_AUDIT_COLUMNS).as_str(),
+                &[&deleted_before],
+            )
+            .await
+            .map_err(|e| CustomError::RepositoryError(format!("Database query failed: {}", e)))?;
+
+        // เก็บค่าสุดท้ายไว้ในประวัติ ผู้ทำรายการเป็นระบบ (0)
+        for row in &rows {
+            let changes = field_changes(&audit_values(row), &null_values());
+            self.insert_history(&transaction, row.get("id"), TaskHistoryOperation::Purge, changes, 0).await?;
+        }
+
+        transaction.commit().await.map_err(|e| {
+            CustomError::RepositoryError(format!("Failed to commit transaction: {}", e))
+        })?;
 
-        Ok(row.get::<_, bool>("is_already_exists"))
+        Ok(TaskPurgeResult {
+            purged: rows.len() as u64,
+            storage_keys: attachment_rows.iter().map(|row| row.get("storage_key")).collect(),
+        })
     }
 }
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use std::time::Duration;
+use futures_util::{stream, StreamExt};
+use tokio_postgres::{AsyncMessage, Config, NoTls};
+use crate::domain::entities::master_data::MasterDataKind;
+use crate::infrastructure::database::master_data_cache::{MasterDataCache, MASTER_DATA_CHANGED_CHANNEL};
+
+const RECONNECT_INTERVAL_SECONDS: u64 = 5;
+
+// job รับ NOTIFY จาก instance อื่นที่แก้ไข master data แล้วล้าง cache ของ instance นี้
+// ใช้ connection แยกจาก pool เพราะ LISTEN ต้องค้าง connection ไว้ตลอด
+pub fn spawn_master_data_listener(db_config: Config, cache: Arc<MasterDataCache>, enabled: bool) {
+    if !enabled {
+        log::info!("Master data cache listener disabled");
+        return;
+    }
+
+    actix_web::rt::spawn(async move {
+        loop {
+            match listen(&db_config, &cache).await {
+                Ok(()) => log::warn!("Master data cache listener connection closed"),
+                Err(e) => log::error!("Master data cache listener failed: {}", e),
+            }
+
+            // ระหว่างที่หลุดอาจพลาด NOTIFY จึงล้าง cache ทั้งหมดก่อนเชื่อมต่อใหม่
+            cache.invalidate_all();
+            actix_web::rt::time::sleep(Duration::from_secs(RECONNECT_INTERVAL_SECONDS)).await;
+        }
+    });
+}
+
+async fn listen(db_config: &Config, cache: &Arc<MasterDataCache>) -> Result<(), String> {
+    let (client, mut connection) = db_config.connect(NoTls).await.map_err(|e| e.to_string())?;
+
+    // connection ต้องถูก poll ตลอดเพื่อให้ทั้ง query และ notification ทำงาน
+    let notification_cache = Arc::clone(cache);
+    let messages = actix_web::rt::spawn(async move {
+        let mut messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+        while let Some(message) = messages.next().await {
+            if let AsyncMessage::Notification(notification) = message.map_err(|e| e.to_string())? {
+                match MasterDataKind::from_code(notification.payload()) {
+                    Some(kind) => notification_cache.invalidate(kind),
+                    None => notification_cache.invalidate_all(),
+                }
+            }
+        }
+
+        Ok::<(), String>(())
+    });
+
+    client
+        .batch_execute(&format!("LISTEN {};", MASTER_DATA_CHANGED_CHANNEL))
+        .await
+        .map_err(|e| e.to_string())?;
+    cache.invalidate_all();
+    log::info!("Listening for master data changes on channel {}", MASTER_DATA_CHANGED_CHANNEL);
+
+    // client ต้องอยู่จนกว่า connection จะปิด
+    let result = messages.await.map_err(|e| e.to_string())?;
+    drop(client);
+    result
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod task_purge;
+pub mod master_data_listener;
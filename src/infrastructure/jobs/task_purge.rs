@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{NaiveDateTime, Utc};
+use crate::domain::repositories::file_storage::FileStorage;
+use crate::domain::repositories::task::TaskRepositories;
+use crate::shared::exceptions::custom_error::CustomError;
+
+// job ลบ task ในถังขยะถาวรเมื่อเกินระยะเวลาเก็บ (retention)
+pub fn spawn_task_purge_job<T: TaskRepositories + 'static>(repository: T, storage: Arc<dyn FileStorage>, retention_days: i64, interval_seconds: u64) {
+    // interval = 0 คือปิด job
+    if interval_seconds == 0 {
+        log::info!("Task purge job disabled");
+        return;
+    }
+
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(Duration::from_secs(interval_seconds));
+        loop {
+            interval.tick().await;
+            let deleted_before = Utc::now().naive_utc() - chrono::Duration::days(retention_days);
+            match purge_deleted_task(&repository, storage.as_ref(), deleted_before).await {
+                Ok(0) => {}
+                Ok(count) => log::info!("Purged {} task(s) deleted before {}", count, deleted_before),
+                Err(e) => log::error!("Failed to purge deleted task: {}", e),
+            }
+        }
+    });
+}
+
+// ลบไฟล์แนบหลัง commit แล้วเท่านั้น ถ้าลบไฟล์ไม่สำเร็จจะเหลือแค่ไฟล์ค้าง ไม่มี metadata ที่ชี้ไปหาไฟล์ที่หายไป
+pub async fn purge_deleted_task<T: TaskRepositories>(repository: &T, storage: &dyn FileStorage, deleted_before: NaiveDateTime) -> Result<u64, CustomError> {
+    let result = repository.purge_deleted_task(deleted_before).await?;
+
+    for storage_key in &result.storage_keys {
+        if let Err(e) = storage.delete_object(storage_key).await {
+            log::warn!("Failed to delete attachment file {}: {}", storage_key, e);
+        }
+    }
+
+    Ok(result.purged)
+}
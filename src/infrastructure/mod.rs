@@ -1,3 +1,5 @@
 pub mod config;
 pub mod database;
-pub mod api;
\ No newline at end of file
+pub mod api;
+pub mod jobs;
+pub mod storage;
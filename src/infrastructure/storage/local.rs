@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use crate::domain::repositories::file_storage::FileStorage;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{INVALID_STORAGE_KEY, STORAGE_OBJECT_NOT_FOUND};
+
+// เก็บไฟล์ไว้ใต้ root_dir โดยใช้ key เป็น path ย่อย
+pub struct LocalFileStorage {
+    root_dir: PathBuf,
+}
+
+impl LocalFileStorage {
+    pub fn new(root_dir: impl Into<PathBuf>) -> Self {
+        Self { root_dir: root_dir.into() }
+    }
+
+    // key ต้องเป็น path แบบ relative ที่ไม่มี .. เพื่อไม่ให้หลุดออกนอก root_dir
+    fn object_path(&self, key: &str) -> Result<PathBuf, CustomError> {
+        let key_path = Path::new(key);
+        if key.is_empty() || !key_path.components().all(|component| matches!(component, Component::Normal(_))) {
+            return Err(CustomError::SystemError(format!("{}: {}", INVALID_STORAGE_KEY, key)));
+        }
+
+        Ok(self.root_dir.join(key_path))
+    }
+}
+
+#[async_trait]
+impl FileStorage for LocalFileStorage {
+    async fn put_object(&self, key: &str, _content_type: &str, data: Vec<u8>) -> Result<(), CustomError> {
+        let path = self.object_path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| CustomError::SystemError(format!("Failed to create attachment directory: {}", e)))?;
+        }
+
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| CustomError::SystemError(format!("Failed to write attachment file: {}", e)))
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>, CustomError> {
+        let path = self.object_path(key)?;
+
+        tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            ErrorKind::NotFound => CustomError::NotFound(STORAGE_OBJECT_NOT_FOUND.to_string()),
+            _ => CustomError::SystemError(format!("Failed to read attachment file: {}", e)),
+        })
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), CustomError> {
+        let path = self.object_path(key)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(CustomError::SystemError(format!("Failed to delete attachment file: {}", e))),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod local;
+pub mod s3;
\ No newline at end of file
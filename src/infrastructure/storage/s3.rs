@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{Method, StatusCode, Url};
+use sha2::{Digest, Sha256};
+use crate::domain::repositories::file_storage::FileStorage;
+use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::STORAGE_OBJECT_NOT_FOUND;
+
+const SIGNING_ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+// S3 และ S3-compatible (MinIO ฯลฯ) แบบ path-style: {endpoint}/{bucket}/{key}
+// เซ็น request ด้วย Signature Version 4 เอง เพื่อไม่ต้องพึ่ง AWS SDK
+pub struct S3FileStorage {
+    client: reqwest::Client,
+    endpoint: String,
+    region: String,
+    bucket: String,
+    access_key: String,
+    secret_key: String,
+}
+
+impl S3FileStorage {
+    // endpoint = None คือใช้ AWS S3 ของ region นั้น
+    pub fn new(endpoint: Option<String>, region: String, bucket: String, access_key: String, secret_key: String) -> Self {
+        let endpoint = endpoint
+            .unwrap_or_else(|| format!("https://s3.{}.amazonaws.com", region))
+            .trim_end_matches('/')
+            .to_string();
+
+        Self { client: reqwest::Client::new(), endpoint, region, bucket, access_key, secret_key }
+    }
+
+    // ค่า header Authorization ตาม SigV4 (headers เป็นชื่อตัวเล็กทั้งหมด, ไม่มี query string)
+    pub fn authorization(&self, method: &str, canonical_uri: &str, headers: &[(&str, &str)], payload_hash: &str, amz_date: &str) -> String {
+        let mut headers = headers.to_vec();
+        headers.sort_by_key(|(name, _)| *name);
+
+        let canonical_headers: String = headers.iter().map(|(name, value)| format!("{}:{}\n", name, value.trim())).collect();
+        let signed_headers = headers.iter().map(|(name, _)| *name).collect::<Vec<&str>>().join(";");
+        let canonical_request = format!("{}\n{}\n\n{}\n{}\n{}", method, canonical_uri, canonical_headers, signed_headers, payload_hash);
+
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!("{}\n{}\n{}\n{}", SIGNING_ALGORITHM, amz_date, scope, hex::encode(Sha256::digest(canonical_request)));
+
+        let signing_key = [date, self.region.as_str(), "s3", "aws4_request"]
+            .iter()
+            .fold(format!("AWS4{}", self.secret_key).into_bytes(), |key, part| hmac_sha256(&key, part.as_bytes()));
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+        format!("{} Credential={}/{}, SignedHeaders={}, Signature={}", SIGNING_ALGORITHM, self.access_key, scope, signed_headers, signature)
+    }
+
+    async fn send(&self, method: Method, key: &str, content_type: Option<&str>, body: Vec<u8>) -> Result<reqwest::Response, CustomError> {
+        let url = Url::parse(&format!("{}/{}/{}", self.endpoint, self.bucket, encode_key(key)))
+            .map_err(|e| CustomError::SystemError(format!("Invalid S3 endpoint: {}", e)))?;
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let authorization = self.authorization(
+            method.as_str(),
+            url.path(),
+            &[("host", &host), ("x-amz-content-sha256", &payload_hash), ("x-amz-date", &amz_date)],
+            &payload_hash,
+            &amz_date,
+        );
+
+        let mut request = self.client
+            .request(method, url)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization);
+        if let Some(content_type) = content_type {
+            request = request.header("content-type", content_type);
+        }
+
+        request
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| CustomError::SystemError(format!("S3 request failed: {}", e)))
+    }
+}
+
+#[async_trait]
+impl FileStorage for S3FileStorage {
+    async fn put_object(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<(), CustomError> {
+        let response = self.send(Method::PUT, key, Some(content_type), data).await?;
+        ensure_success(response).await.map(|_| ())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>, CustomError> {
+        let response = self.send(Method::GET, key, None, Vec::new()).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(CustomError::NotFound(STORAGE_OBJECT_NOT_FOUND.to_string()));
+        }
+
+        let response = ensure_success(response).await?;
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| CustomError::SystemError(format!("S3 request failed: {}", e)))
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), CustomError> {
+        let response = self.send(Method::DELETE, key, None, Vec::new()).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+
+        ensure_success(response).await.map(|_| ())
+    }
+}
+
+async fn ensure_success(response: reqwest::Response) -> Result<reqwest::Response, CustomError> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Err(CustomError::SystemError(format!("S3 request failed: {} {}", status, body)))
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// URI-encode ทุกตัวอักษรยกเว้น unreserved และ / ตามที่ SigV4 ของ S3 กำหนด
+fn encode_key(key: &str) -> String {
+    key.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 use actix_web::{web, App, HttpServer};
 use actix_web::middleware::{ErrorHandlers, Logger};
 use env_logger::Env;
@@ -10,28 +11,38 @@ mod application;
 
 use crate::application::use_cases::{
     auth::AuthUseCaseImpl, health_check::HealthCheckUseCaseImpl,
-    master_data::MasterDataUseCaseImpl, task::TaskUseCaseImpl,
+    project::ProjectUseCaseImpl,
 };
 
 use crate::infrastructure::{
     api::{
         factories::{
-            auth::create_user_handler_data, health_check::create_health_check_handler_data,
-            master_data::create_master_data_handler_data, task::create_task_handler_data,
+            attachment::{create_attachment_handler_data, create_file_storage, AttachmentUseCaseType},
+            auth::{create_jwt_middleware, create_user_handler_data}, comment::{create_comment_handler_data, CommentUseCaseType},
+            health_check::create_health_check_handler_data,
+            label::{create_label_handler_data, LabelUseCaseType},
+            master_data::{create_master_data_handler_data, MasterDataUseCaseType}, project::create_project_handler_data,
+            task::{create_task_handler_data, TaskUseCaseType},
         },
         routes::{
-            auth::configure_user_routes, health_check::config_health_check_routes,
-            master_data_routes::configure_master_data_routes, task::configure_task_routes,
+            attachment::configure_attachment_routes, auth::configure_user_routes, comment::configure_comment_routes, health_check::config_health_check_routes,
+            label::configure_label_routes,
+            master_data_routes::configure_master_data_routes, project::configure_project_routes,
+            task::configure_task_routes,
         },
     },
     config::{load_env, ServerConfig},
     database::{
         auth::AuthRepositoriesImpl,
-        connection::{close_connection_db, create_db_pool},
+        connection::{close_connection_db, create_db_config, create_db_pool},
         health_check::HealthCheckRepositoriesImpl,
+        migration::{run_migration, MigrationCommand},
         master_data::MasterDataRepositoriesImpl,
+        master_data_cache::{CachedMasterDataRepositories, MasterDataCache},
+        project::ProjectRepositoriesImpl,
         task::TaskRepositoriesImpl,
     },
+    jobs::{master_data_listener::spawn_master_data_listener, task_purge::spawn_task_purge_job},
 };
 
 use crate::shared::{
@@ -53,22 +64,74 @@ async fn main() -> std::io::Result<()> {
     // สร้าง connection pool สำหรับ database
     let max_size: usize = 16;
     let pool = create_db_pool(&config, max_size)?;
+
+    // โหมด migrate: myapp migrate up|down|status
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("migrate") {
+        let command = args.get(2).map(String::as_str).unwrap_or("up").parse::<MigrationCommand>().map_err(|e| std::io::Error::other(e.to_string()))?;
+        run_migration(Arc::clone(&pool), command).await.map_err(|e| std::io::Error::other(e.to_string()))?;
+        close_connection_db(pool);
+        return Ok(());
+    }
+
+    // migrate อัตโนมัติตอน start (ใช้ advisory lock กันหลาย replica รันพร้อมกัน)
+    if config.auto_migrate {
+        run_migration(Arc::clone(&pool), MigrationCommand::Up).await.map_err(|e| std::io::Error::other(e.to_string()))?;
+    }
+
     let shutdown_pool = Arc::clone(&pool); // Clone Database Pool เพื่อใช้ใน Cleanup
 
     // สร้าง Sonyflake instance สำหรับการ generate unique ID
     // ใช้ Sonyflake สำหรับสร้าง Snowflake node
     let sonyflake = initialize_sonyflake()?;
-    let snowflake_node = SnowflakeImpl::new(sonyflake);
+    let snowflake_node = SnowflakeImpl::new(sonyflake.clone());
+
+    // cache ของ master data ใช้ร่วมกันทุก handler
+    let master_data_cache = Arc::new(MasterDataCache::new(Duration::from_secs(config.master_data_cache_ttl_seconds)));
 
     // เตรียม data handler สำหรับแต่ละ endpoint
     let health_check_handler_data = create_health_check_handler_data(Arc::clone(&pool));
-    let master_data_handler_data = create_master_data_handler_data(Arc::clone(&pool));
-    let task_handler_data = create_task_handler_data(Arc::clone(&pool), snowflake_node);
-    let user_handler_data = create_user_handler_data(Arc::clone(&pool), &config);
+    let master_data_handler_data = create_master_data_handler_data(
+        Arc::clone(&pool),
+        SnowflakeImpl::new(sonyflake.clone()),
+        Arc::clone(&master_data_cache),
+        config.master_data_cache_max_age_seconds,
+    );
+    let task_handler_data = create_task_handler_data(
+        Arc::clone(&pool),
+        snowflake_node,
+        SnowflakeImpl::new(sonyflake.clone()),
+        Arc::clone(&master_data_cache),
+    );
+    let project_handler_data = create_project_handler_data(Arc::clone(&pool), SnowflakeImpl::new(sonyflake.clone()), Arc::clone(&master_data_cache));
+    let label_handler_data = create_label_handler_data(Arc::clone(&pool), SnowflakeImpl::new(sonyflake.clone()));
+    let comment_handler_data = create_comment_handler_data(
+        Arc::clone(&pool),
+        SnowflakeImpl::new(sonyflake.clone()),
+        SnowflakeImpl::new(sonyflake.clone()),
+        SnowflakeImpl::new(sonyflake.clone()),
+    );
+    let attachment_handler_data = create_attachment_handler_data(
+        Arc::clone(&pool),
+        SnowflakeImpl::new(sonyflake.clone()),
+        SnowflakeImpl::new(sonyflake.clone()),
+        SnowflakeImpl::new(sonyflake.clone()),
+        &config,
+    )?;
+    let task_purge_repository = TaskRepositoriesImpl::new(Arc::clone(&pool), SnowflakeImpl::new(sonyflake.clone()));
+    let task_purge_storage = create_file_storage(&config)?;
+    let user_handler_data = create_user_handler_data(Arc::clone(&pool), SnowflakeImpl::new(sonyflake), Arc::clone(&master_data_cache), &config);
+    let jwt_middleware = create_jwt_middleware(Arc::clone(&pool), &config);
 
     // ตั้งค่า logging จาก environment
     env_logger::init_from_env(Env::default().default_filter_or("info"));
 
+    // job ลบ task ในถังขยะที่เกินระยะเวลาเก็บ
+    spawn_task_purge_job(task_purge_repository, task_purge_storage, config.task_trash_retention_days, config.task_purge_interval_seconds);
+
+    // job ล้าง cache master data เมื่อ instance อื่นแก้ไข
+    spawn_master_data_listener(create_db_config(&config), master_data_cache, config.master_data_cache_listen);
+
     // ===== Stage 2: Run Server =====
     let server =
         HttpServer::new(move || {
@@ -95,28 +158,52 @@ async fn main() -> std::io::Result<()> {
                         // User routes
                         .app_data(user_handler_data.clone())
                         .configure(|cfg| {
-                            configure_user_routes::<AuthUseCaseImpl<AuthRepositoriesImpl>>(cfg)
+                            configure_user_routes::<
+                                AuthUseCaseImpl<AuthRepositoriesImpl<SnowflakeImpl>, CachedMasterDataRepositories<MasterDataRepositoriesImpl>>,
+                            >(cfg, jwt_middleware.clone())
                         })
 
                         // Master Data routes
                         .app_data(master_data_handler_data.clone())
                         .configure(|cfg| {
-                            configure_master_data_routes::<
-                                MasterDataUseCaseImpl<MasterDataRepositoriesImpl>,
-                            >(cfg)
+                            configure_master_data_routes::<MasterDataUseCaseType>(cfg, jwt_middleware.clone())
+                        })
+
+                        // Label routes
+                        .app_data(label_handler_data.clone())
+                        .configure(|cfg| {
+                            configure_label_routes::<LabelUseCaseType>(cfg, jwt_middleware.clone())
+                        })
+
+                        // Comment routes (ก่อน task routes เพราะอยู่ใต้ /task/{task_id})
+                        .app_data(comment_handler_data.clone())
+                        .configure(|cfg| {
+                            configure_comment_routes::<CommentUseCaseType>(cfg, jwt_middleware.clone())
+                        })
+
+                        // Attachment routes (ก่อน task routes เพราะอยู่ใต้ /task/{task_id})
+                        .app_data(attachment_handler_data.clone())
+                        .configure(|cfg| {
+                            configure_attachment_routes::<AttachmentUseCaseType>(cfg, jwt_middleware.clone())
                         })
 
                         // Task Management routes
                         .app_data(task_handler_data.clone())
                         .configure(|cfg| {
-                            configure_task_routes::<
-                                TaskUseCaseImpl<TaskRepositoriesImpl<SnowflakeImpl>>,
-                            >(cfg, config.jwt_secret.clone())
+                            configure_task_routes::<TaskUseCaseType>(cfg, jwt_middleware.clone())
+                        })
+
+                        // Project routes (หลัง task routes เพราะ /projects/{project_id}/tasks อยู่ใน task routes)
+                        .app_data(project_handler_data.clone())
+                        .configure(|cfg| {
+                            configure_project_routes::<
+                                ProjectUseCaseImpl<ProjectRepositoriesImpl<SnowflakeImpl>, CachedMasterDataRepositories<MasterDataRepositoriesImpl>>,
+                            >(cfg, jwt_middleware.clone())
                         }),
                 )
         })
             .bind(("0.0.0.0", config.api_port))
-            .expect(&format!("Cannot bind to port {}", config.api_port));
+            .unwrap_or_else(|_| panic!("Cannot bind to port {}", config.api_port));
 
     let server = server.run();
 
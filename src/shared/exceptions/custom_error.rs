@@ -16,6 +16,10 @@ pub enum CustomError {
     Unauthorized(String),
     Forbidden(String),
     DataConflict(String),
+    PreconditionFailed(String),
+    PreconditionRequired(String),
+    PayloadTooLarge(String),
+    UnsupportedMediaType(String),
     SubNotfound,
 }
 
@@ -55,13 +59,53 @@ impl Display for CustomError {
             CustomError::DataConflict(message) => {
                 write!(f, "{}", message)
             }
+            CustomError::PreconditionFailed(message) => {
+                write!(f, "{}", message)
+            }
+            CustomError::PreconditionRequired(message) => {
+                write!(f, "{}", message)
+            }
+            CustomError::PayloadTooLarge(message) => {
+                write!(f, "{}", message)
+            }
+            CustomError::UnsupportedMediaType(message) => {
+                write!(f, "{}", message)
+            }
             CustomError::SubNotfound => {
-                write!(f, "{}", "sub not found")
+                write!(f, "sub not found")
             }
         }
     }
 }
 
+// ใช้เป็น error ของ body แบบ streaming ได้ (เช่น export task)
+impl std::error::Error for CustomError {}
+
+impl CustomError {
+    // เติมบริบทหน้าข้อความโดยคงชนิดของ error (และ status code) เดิมไว้
+    pub fn with_context(self, context: &str) -> CustomError {
+        let message = format!("{}: {}", context, self);
+        match self {
+            CustomError::ValidationError(_) => CustomError::ValidationError(message),
+            CustomError::InternalError(_) => CustomError::InternalError(message),
+            CustomError::DomainError(_) => CustomError::DomainError(message),
+            CustomError::BusinessError(_) => CustomError::BusinessError(message),
+            CustomError::SystemError(_) => CustomError::SystemError(message),
+            CustomError::NotFound(_) => CustomError::NotFound(message),
+            CustomError::RepositoryError(_) => CustomError::RepositoryError(message),
+            CustomError::UnknownError(_) => CustomError::UnknownError(message),
+            CustomError::Unauthorized(_) => CustomError::Unauthorized(message),
+            CustomError::Forbidden(_) => CustomError::Forbidden(message),
+            CustomError::DataConflict(_) => CustomError::DataConflict(message),
+            CustomError::PreconditionFailed(_) => CustomError::PreconditionFailed(message),
+            CustomError::PreconditionRequired(_) => CustomError::PreconditionRequired(message),
+            CustomError::PayloadTooLarge(_) => CustomError::PayloadTooLarge(message),
+            CustomError::UnsupportedMediaType(_) => CustomError::UnsupportedMediaType(message),
+            CustomError::SubNotfound => CustomError::SubNotfound,
+        }
+    }
+}
+
 impl error::ResponseError for CustomError {
     fn status_code(&self) -> StatusCode {
         match *self {
@@ -76,6 +120,10 @@ impl error::ResponseError for CustomError {
             CustomError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
             CustomError::Forbidden { .. } => StatusCode::FORBIDDEN,
             CustomError::DataConflict { .. } => StatusCode::CONFLICT,
+            CustomError::PreconditionFailed { .. } => StatusCode::PRECONDITION_FAILED,
+            CustomError::PreconditionRequired { .. } => StatusCode::PRECONDITION_REQUIRED,
+            CustomError::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            CustomError::UnsupportedMediaType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
             CustomError::SubNotfound => StatusCode::UNAUTHORIZED,
         }
     }
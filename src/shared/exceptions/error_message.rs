@@ -2,4 +2,78 @@
 pub const TASK_NOT_FOUND: &str = "Task ID not found";
 pub const RECORD_NOT_FOUND: &str = "query returned an unexpected number of rows";
 pub const USERNAME_NOT_FOUND: &str = "Username not found";
-pub const FAIL_TO_LOAD_ENV: &str = "Failed to load environment variables";
\ No newline at end of file
+pub const FAIL_TO_LOAD_ENV: &str = "Failed to load environment variables";
+pub const PERMISSION_DENIED: &str = "You do not have permission to perform this action";
+pub const ROLE_NOT_FOUND: &str = "User has no active role";
+pub const INVALID_CURSOR: &str = "Invalid cursor";
+pub const USER_NOT_FOUND: &str = "User ID not found";
+pub const INVALID_REFRESH_TOKEN: &str = "Invalid refresh token";
+pub const REFRESH_TOKEN_REUSED: &str = "Refresh token reuse detected";
+pub const TOKEN_REVOKED: &str = "Token has been revoked";
+pub const TOKEN_USER_CHANGED: &str = "User has been deactivated or role has changed";
+pub const USER_DEACTIVATED: &str = "User account is deactivated";
+pub const USERNAME_ALREADY_EXISTS: &str = "Username already exists";
+pub const REGISTRATION_DISABLED: &str = "Self-registration is disabled";
+pub const INVALID_ROLE: &str = "roleId: role not found or inactive";
+pub const INVALID_CURRENT_PASSWORD: &str = "Current password is incorrect";
+pub const CANNOT_DEACTIVATE_SELF: &str = "Cannot deactivate your own account";
+pub const INVALID_ASSIGNEE: &str = "userIds: user not found, inactive or not a project member";
+pub const ASSIGNEE_NOT_FOUND: &str = "User is not assigned to this task";
+pub const INVALID_TASK_SCHEDULE: &str = "startDate: must be before or equal to dueDate";
+pub const INVALID_TASK_STATUS: &str = "taskStatusId: task status not found or inactive";
+pub const INVALID_PRIORITY_LEVELS: &str = "priorityLevelsId: priority levels not found or inactive";
+pub const TASK_NOT_FOUND_IN_TRASH: &str = "Task ID not found in trash";
+pub const TASK_STATUS_NOT_FOUND: &str = "Task status ID not found";
+pub const INVALID_STATUS_TRANSITION: &str = "Task status transition not allowed";
+pub const INVALID_ASSIGNEE_FILTER: &str = "assignee: must be \"me\" or a user id";
+pub const IF_MATCH_REQUIRED: &str = "If-Match header is required";
+pub const INVALID_IF_MATCH: &str = "If-Match: must be an ETag from GET /task/{task_id} or *";
+pub const EMPTY_TASK_PATCH: &str = "Request body must contain at least one field to update";
+pub const TASK_VERSION_MISMATCH: &str = "Task has been modified by another request";
+pub const PROJECT_NOT_FOUND: &str = "Project ID not found";
+pub const PROJECT_ARCHIVED: &str = "Project is archived";
+pub const PROJECT_HAS_TASK: &str = "Project still has tasks, archive it instead";
+pub const PROJECT_MEMBER_NOT_FOUND: &str = "User is not a member of this project";
+pub const INVALID_PROJECT_MEMBER: &str = "userId: user not found or inactive";
+pub const LAST_PROJECT_ADMIN: &str = "Project must have at least one admin";
+pub const INVALID_PARENT_TASK: &str = "parentTaskId: parent task not found";
+pub const PARENT_TASK_PROJECT_MISMATCH: &str = "parentTaskId: parent task must be in the same project";
+pub const TASK_HIERARCHY_CYCLE: &str = "parentTaskId: task can not be a subtask of itself or its subtasks";
+pub const TASK_HIERARCHY_TOO_DEEP: &str = "parentTaskId: subtasks exceed maximum depth";
+pub const TASK_HAS_SUBTASK: &str = "Task with subtasks can not be moved to another project";
+pub const PARENT_TASK_IN_TRASH: &str = "Parent task is in trash, restore the parent task first";
+pub const INVALID_TASK_DEPENDENCY: &str = "blockedByTaskId: task not found";
+pub const TASK_DEPENDENCY_PROJECT_MISMATCH: &str = "blockedByTaskId: task must be in the same project";
+pub const TASK_DEPENDENCY_CYCLE: &str = "blockedByTaskId: dependency would create a cycle";
+pub const TASK_DEPENDENCY_NOT_FOUND: &str = "Task is not blocked by this task";
+pub const TASK_BLOCKED: &str = "Task is blocked by unfinished tasks";
+pub const COMMENT_NOT_FOUND: &str = "Comment ID not found";
+pub const ATTACHMENT_NOT_FOUND: &str = "Attachment ID not found";
+pub const ATTACHMENT_FILE_REQUIRED: &str = "file: multipart field is required";
+pub const ATTACHMENT_TOO_LARGE: &str = "file: exceeds maximum attachment size";
+pub const ATTACHMENT_CONTENT_TYPE_NOT_ALLOWED: &str = "file: content type is not allowed";
+pub const STORAGE_OBJECT_NOT_FOUND: &str = "Attachment file not found in storage";
+pub const INVALID_STORAGE_KEY: &str = "Invalid storage key";
+pub const LABEL_NOT_FOUND: &str = "Label ID not found";
+pub const LABEL_ALREADY_EXISTS: &str = "Label name already exists";
+pub const INVALID_LABEL_NAME: &str = "name: must not be empty or contain commas";
+pub const INVALID_LABEL_COLOR: &str = "color: must be a hex color such as #1f77b4";
+pub const INVALID_LABEL: &str = "labelIds: label not found";
+pub const TASK_LABEL_NOT_FOUND: &str = "Label is not attached to this task";
+pub const SEARCH_QUERY_REQUIRED: &str = "q: must not be empty";
+pub const MASTER_DATA_NOT_FOUND: &str = "Master data ID not found";
+pub const MASTER_DATA_CODE_ALREADY_EXISTS: &str = "Master data code already exists";
+pub const INVALID_MASTER_DATA_CODE: &str = "code: must start with a letter and contain only uppercase letters, digits and underscores";
+pub const INVALID_ROLE_CODE: &str = "code: must be one of ADMIN, MANAGER, MEMBER, VIEWER";
+pub const MASTER_DATA_IN_USE: &str = "Master data is still in use, supply replacementId to move existing records";
+pub const INVALID_MASTER_DATA_REPLACEMENT: &str = "replacementId: must be another active value of the same master data";
+pub const MASTER_DATA_REQUIRED: &str = "Master data is required by the system and cannot be deactivated";
+pub const LAST_ACTIVE_MASTER_DATA: &str = "At least one active value is required";
+pub const INVALID_MASTER_DATA_ORDER: &str = "ids: must contain every id of this master data exactly once";
+pub const INVALID_EXPAND: &str = "expand: must be a comma separated list of status, priority, createdBy";
+pub const TASK_BULK_TOO_LARGE: &str = "operations: must contain at most 500 task ids in total";
+pub const TASK_BULK_DUPLICATE_TASK: &str = "operations: a task can appear in only one operation";
+pub const INVALID_TASK_BULK_OPERATION: &str = "operations: updateStatus requires taskStatusId, updatePriority requires priorityLevelsId and assign requires userIds";
+pub const INVALID_TASK_IMPORT_SIZE: &str = "Import file must contain between 1 and 1000 tasks";
+pub const INVALID_TASK_IMPORT_FILE: &str = "Import file is not valid UTF-8 CSV, JSON or NDJSON";
+pub const TASK_IMPORT_MISSING_COLUMN: &str = "CSV header is missing a required column";
@@ -1,24 +1,31 @@
 use std::future::{ready, Ready};
+use std::rc::Rc;
+use std::sync::Arc;
 use actix_web::{dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform}, Error, HttpMessage};
 use futures_util::future::LocalBoxFuture;
+use crate::domain::entities::auth::{AccessTokenStatus, AuthUser};
+use crate::domain::repositories::auth::TokenDenylistRepositories;
 use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::exceptions::error_message::{TOKEN_REVOKED, TOKEN_USER_CHANGED};
 use crate::shared::middleware::jwt::validate_token;
 
 // Middleware structure
+#[derive(Clone)]
 pub struct JwtMiddleware {
     secret: String,
+    denylist: Arc<dyn TokenDenylistRepositories>,
 }
 
 impl JwtMiddleware {
-    pub fn new(secret: String) -> Self {
-        Self { secret }
+    pub fn new(secret: String, denylist: Arc<dyn TokenDenylistRepositories>) -> Self {
+        Self { secret, denylist }
     }
 }
 
 // Middleware factory implementation (Transform)
 impl<S, B> Transform<S, ServiceRequest> for JwtMiddleware
 where
-    S: Service<ServiceRequest, Response=ServiceResponse<B>, Error=Error>,
+    S: Service<ServiceRequest, Response=ServiceResponse<B>, Error=Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -30,21 +37,23 @@ where
 
     fn new_transform(&self, service: S) -> Self::Future {
         ready(Ok(JwtMiddlewareService {
-            service,
+            service: Rc::new(service),
             secret: self.secret.clone(),
+            denylist: Arc::clone(&self.denylist),
         }))
     }
 }
 
 pub struct JwtMiddlewareService<S> {
-    service: S,
+    service: Rc<S>,
     secret: String,
+    denylist: Arc<dyn TokenDenylistRepositories>,
 }
 
 // Middleware service implementation
 impl<S, B> Service<ServiceRequest> for JwtMiddlewareService<S>
 where
-    S: Service<ServiceRequest, Response=ServiceResponse<B>, Error=Error>,
+    S: Service<ServiceRequest, Response=ServiceResponse<B>, Error=Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -62,29 +71,43 @@ where
             .and_then(|header| header.to_str().ok());
 
         // Validate JWT
-        if let Some(auth_header) = auth_header {
+        let claims = if let Some(auth_header) = auth_header {
             if !auth_header.starts_with("Bearer ") {
-                return Box::pin(async { return Err(Error::from(CustomError::Unauthorized("Invalid Authorization header".to_string()))); });
+                return Box::pin(async { Err(Error::from(CustomError::Unauthorized("Invalid Authorization header".to_string()))) });
             }
 
             let token = &auth_header[7..];
 
             match validate_token(token, self.secret.as_str()) {
-                Ok(token_data) => {
-                    req.extensions_mut().insert(token_data.claims.sub);
-                }
+                Ok(token_data) => token_data.claims,
                 Err(_) => {
-                    return Box::pin(async { return Err(Error::from(CustomError::Unauthorized("Invalid JWT Token".to_string()))); });
+                    return Box::pin(async { Err(Error::from(CustomError::Unauthorized("Invalid JWT Token".to_string()))) });
                 }
             }
         } else {
             return Box::pin(async { Err(Error::from(CustomError::Unauthorized("No Authorization header found".to_string()))) });
-        }
+        };
 
-        let fut = self.service.call(req);
+        let service = Rc::clone(&self.service);
+        let denylist = Arc::clone(&self.denylist);
         Box::pin(async move {
-            let res = fut.await?;
+            // token ที่ logout ไปแล้ว หรือผู้ใช้ถูกปิดบัญชี/เปลี่ยน role ต้องใช้ไม่ได้ทันที แม้ยังไม่หมดอายุ
+            // (เปลี่ยน role แล้วต้อง refresh เพื่อรับ token ที่มี role ใหม่)
+            match denylist.access_token_status(&claims.jti, claims.sub, claims.role).await? {
+                AccessTokenStatus::Valid => {}
+                AccessTokenStatus::Revoked => return Err(Error::from(CustomError::Unauthorized(TOKEN_REVOKED.to_string()))),
+                AccessTokenStatus::UserChanged => return Err(Error::from(CustomError::Unauthorized(TOKEN_USER_CHANGED.to_string()))),
+            }
+
+            req.extensions_mut().insert(claims.sub);
+            req.extensions_mut().insert(AuthUser {
+                id: claims.sub,
+                role: claims.role,
+            });
+            req.extensions_mut().insert(claims);
+
+            let res = service.call(req).await?;
             Ok(res)
         })
     }
-}
\ No newline at end of file
+}
@@ -12,7 +12,7 @@ pub fn add_error_header<B>(mut res: ServiceResponse<B>) -> actix_web::Result<Err
     );
 
     let mut error_msg: String = match res.response().error() {
-        Some(e) => format!("{}", e.to_string()),
+        Some(e) => e.to_string(),
         None => String::from("Unknown Error")
     };
 
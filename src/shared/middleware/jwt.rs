@@ -3,24 +3,33 @@ use actix_web::{HttpMessage, HttpRequest};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, TokenData, Validation};
 use serde::{Deserialize, Serialize};
+use crate::domain::entities::auth::{AuthUser, Role};
 use crate::shared::exceptions::custom_error::CustomError;
+use crate::shared::utils::token::generate_token;
+
+// อายุของ access token (1 ชั่วโมง)
+pub const ACCESS_TOKEN_EXPIRE_SECONDS: i64 = 3600;
 
 // Struct ของ Claims
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: i64, // Subject (เช่น user ID)
+    pub role: Role, // Role ของผู้ใช้งาน (code จาก master_data_role)
+    pub jti: String, // JWT ID ใช้สำหรับยกเลิก token (logout)
     pub exp: usize,  // Expiration time
 }
 
 // ฟังก์ชันสำหรับสร้าง JWT
-pub fn create_token(user_id: i64, secret: &str) -> String {
+pub fn create_token(user_id: i64, role: Role, secret: &str) -> String {
     let expiration = Utc::now()
-        .checked_add_signed(Duration::seconds(3600)) // อายุของโทเค็น (1 ชั่วโมง)
+        .checked_add_signed(Duration::seconds(ACCESS_TOKEN_EXPIRE_SECONDS))
         .expect("Unable to calculate expiration time")
         .timestamp() as usize;
 
     let claims = Claims {
         sub: user_id,
+        role,
+        jti: generate_token(),
         exp: expiration,
     };
 
@@ -37,9 +46,18 @@ pub fn validate_token(token: &str, secret: &str) -> Result<TokenData<Claims>, js
     )
 }
 
-pub async fn extract_user_id(req: &HttpRequest) -> std::result::Result<i64, CustomError> {
+// ดึงผู้ใช้งานพร้อม role ที่ middleware ใส่ไว้ใน request
+pub async fn extract_auth_user(req: &HttpRequest) -> std::result::Result<AuthUser, CustomError> {
     req.extensions()
-        .get::<i64>()
+        .get::<AuthUser>()
         .copied()
         .ok_or(CustomError::SubNotfound)
 }
+
+// ดึง claims ของ access token ปัจจุบัน (ใช้ตอน logout)
+pub async fn extract_claims(req: &HttpRequest) -> std::result::Result<Claims, CustomError> {
+    req.extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or(CustomError::SubNotfound)
+}
@@ -5,6 +5,16 @@ pub struct ApiResponse<T> {
     pub status: String,
     pub message: String,
     pub data: T,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<PageMeta>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PageMeta {
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "hasMore")]
+    pub has_more: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq)]
@@ -19,6 +29,16 @@ pub fn response_success<T>(message: &str, data: T) -> ApiResponse<T> {
         status: "success".to_string(),
         message: message.to_string(),
         data,
+        meta: None,
+    }
+}
+
+pub fn response_success_with_meta<T>(message: &str, data: T, meta: PageMeta) -> ApiResponse<T> {
+    ApiResponse {
+        status: "success".to_string(),
+        message: message.to_string(),
+        data,
+        meta: Some(meta),
     }
 }
 
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Deserializer};
+
+// ใช้กับ field ของ JSON Merge Patch (RFC 7396) คู่กับ #[serde(default)]
+// - Option<T>: ไม่ส่งมา = None, ส่ง null = error (field ที่ล้างค่าไม่ได้)
+// - Option<Option<T>>: ไม่ส่งมา = None, ส่ง null = Some(None) คือล้างค่า
+pub fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
@@ -1 +1,4 @@
-pub mod snowflake;
\ No newline at end of file
+pub mod snowflake;
+pub mod token;
+pub mod merge_patch;
+pub mod csv;
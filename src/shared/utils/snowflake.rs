@@ -23,10 +23,7 @@ impl Snowflake for SnowflakeImpl {
 }
 pub fn initialize_sonyflake() -> Result<Sonyflake, std::io::Error> {
     let sonyflake = Sonyflake::new().map_err(|e| {
-        std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Failed to initialize Sonyflake: {}", e),
-        )
+        std::io::Error::other(format!("Failed to initialize Sonyflake: {}", e))
     })?;
     Ok(sonyflake)
 }
\ No newline at end of file
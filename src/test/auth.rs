@@ -0,0 +1,388 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use actix_web::dev::ServiceResponse;
+    use actix_web::middleware::ErrorHandlers;
+    use actix_web::{http::header::ContentType, http::StatusCode, test, web, App};
+    use chrono::{Duration, Utc};
+    use mockall::predicate::eq;
+    use crate::application::use_cases::auth::AuthUseCaseImpl;
+    use crate::domain::entities::auth::{AccessTokenStatus, LoginToken, RefreshToken, Role, User, UserID, UserProfile};
+    use crate::domain::repositories::auth::{MockAuthRepositories, MockTokenDenylistRepositories};
+    use crate::domain::repositories::master_data::MockMasterDataRepositories;
+    use crate::infrastructure::api::handlers::auth::AuthHandler;
+    use crate::infrastructure::api::routes::auth::configure_user_routes;
+    use crate::shared::exceptions::custom_error::CustomError;
+    use crate::shared::exceptions::error_message::USERNAME_ALREADY_EXISTS;
+    use crate::shared::middleware::auth::JwtMiddleware;
+    use crate::shared::middleware::errors::add_error_header;
+    use crate::shared::middleware::jwt::{create_token, validate_token};
+    use crate::shared::middleware::response::{ApiResponse, ApiResponseErr};
+    use crate::shared::utils::token::hash_token;
+    use crate::test::common::{mock_master_data, ADMIN_ROLE_ID, MEMBER_ROLE_ID};
+
+    const SECRET: &str = "secret-for-test";
+    const USER_ID: i64 = 1844994649115070464;
+    const FAMILY_ID: &str = "family";
+
+    fn mock_user() -> User {
+        User {
+            id: USER_ID,
+            password: bcrypt::hash("Vrz!3Xn8", 4).unwrap(),
+            role_id: Some(ADMIN_ROLE_ID),
+            active: true,
+        }
+    }
+
+    async fn call_user_api(mock_repo: MockAuthRepositories, denylist: MockTokenDenylistRepositories, req: test::TestRequest) -> ServiceResponse {
+        call_user_api_with_registration(mock_repo, denylist, req, false).await
+    }
+
+    async fn call_user_api_with_registration(
+        mock_repo: MockAuthRepositories,
+        denylist: MockTokenDenylistRepositories,
+        req: test::TestRequest,
+        allow_registration: bool,
+    ) -> ServiceResponse {
+        let use_case = AuthUseCaseImpl::new(mock_repo, mock_master_data(), SECRET.to_string(), 3600, allow_registration);
+        let handler_data = web::Data::new(AuthHandler::new(use_case));
+        let jwt_middleware = JwtMiddleware::new(SECRET.to_string(), Arc::new(denylist));
+
+        let app = test::init_service(
+            App::new()
+                .wrap(ErrorHandlers::new().default_handler(add_error_header))
+                .service(
+                    web::scope("/api/v1")
+                        .app_data(handler_data.clone())
+                        .configure(|cfg| {
+                            configure_user_routes::<AuthUseCaseImpl<MockAuthRepositories, MockMasterDataRepositories>>(cfg, jwt_middleware.clone())
+                        })
+                    ,
+                )
+        ).await;
+
+        // error จาก middleware ไม่ผ่าน handler จึงต้องแปลงเป็น response เอง
+        match test::try_call_service(&app, req.insert_header(ContentType::json()).to_request()).await {
+            Ok(resp) => resp.map_into_boxed_body(),
+            Err(e) => ServiceResponse::new(test::TestRequest::default().to_http_request(), e.error_response()),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_login_issue_refresh_token() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_user_exists().returning(|_| Ok(mock_user()));
+        mock_repo.expect_create_refresh_token().times(1).returning(|_| Ok(()));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/login")
+            .set_json(serde_json::json!({ "username": "admin", "password": "Vrz!3Xn8" }));
+        let resp = call_user_api(mock_repo, MockTokenDenylistRepositories::new(), req).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<LoginToken> = serde_json::from_slice(&body_bytes).unwrap();
+        let claims = validate_token(&body.data.token, SECRET).unwrap().claims;
+
+        assert_eq!(claims.sub, USER_ID);
+        assert_eq!(claims.role, Role::Admin);
+        assert!(!body.data.refresh_token.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_refresh_token_rotation() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo
+            .expect_get_refresh_token()
+            .with(eq(hash_token("current-token")))
+            .returning(|_| Ok(Some(RefreshToken {
+                user_id: USER_ID,
+                family_id: FAMILY_ID.to_string(),
+                expires_at: (Utc::now() + Duration::hours(1)).naive_utc(),
+                revoked_at: None,
+            })));
+        mock_repo.expect_get_user().with(eq(USER_ID)).returning(|_| Ok(mock_user()));
+        mock_repo
+            .expect_rotate_refresh_token()
+            .withf(|token_hash, new_token| token_hash == hash_token("current-token") && new_token.family_id == FAMILY_ID)
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/refresh")
+            .set_json(serde_json::json!({ "refreshToken": "current-token" }));
+        let resp = call_user_api(mock_repo, MockTokenDenylistRepositories::new(), req).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<LoginToken> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_ne!(body.data.refresh_token, "current-token");
+    }
+
+    #[actix_web::test]
+    async fn test_refresh_token_reuse_revoke_family() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_get_refresh_token().returning(|_| Ok(Some(RefreshToken {
+            user_id: USER_ID,
+            family_id: FAMILY_ID.to_string(),
+            expires_at: (Utc::now() + Duration::hours(1)).naive_utc(),
+            revoked_at: Some(Utc::now().naive_utc()),
+        })));
+        mock_repo.expect_rotate_refresh_token().never();
+        mock_repo
+            .expect_revoke_refresh_token_family()
+            .with(eq(FAMILY_ID))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/refresh")
+            .set_json(serde_json::json!({ "refreshToken": "stolen-token" }));
+        let resp = call_user_api(mock_repo, MockTokenDenylistRepositories::new(), req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, "Refresh token reuse detected");
+    }
+
+    #[actix_web::test]
+    async fn test_logout_revoke_access_token() {
+        let token = create_token(USER_ID, Role::Admin, SECRET);
+        let jti = validate_token(&token, SECRET).unwrap().claims.jti;
+
+        let mut denylist = MockTokenDenylistRepositories::new();
+        denylist
+            .expect_access_token_status()
+            .with(eq(jti.clone()), eq(USER_ID), eq(Role::Admin))
+            .times(1)
+            .returning(|_, _, _| Ok(AccessTokenStatus::Valid));
+
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo
+            .expect_revoke_access_token()
+            .withf(move |revoked_jti, user_id, _| revoked_jti == jti && *user_id == USER_ID)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/logout")
+            .insert_header(("Authorization", format!("Bearer {}", token)));
+        let resp = call_user_api(mock_repo, denylist, req).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_revoked_access_token_rejected() {
+        let token = create_token(USER_ID, Role::Admin, SECRET);
+
+        let mut denylist = MockTokenDenylistRepositories::new();
+        denylist.expect_access_token_status().returning(|_, _, _| Ok(AccessTokenStatus::Revoked));
+
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_revoke_access_token().never();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/logout")
+            .insert_header(("Authorization", format!("Bearer {}", token)));
+        let resp = call_user_api(mock_repo, denylist, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_access_token_rejected_after_user_changed() {
+        let token = create_token(USER_ID, Role::Admin, SECRET);
+        let jti = validate_token(&token, SECRET).unwrap().claims.jti;
+
+        // ผู้ใช้ถูกปิดบัญชีหรือเปลี่ยน role หลังออก token
+        let mut denylist = MockTokenDenylistRepositories::new();
+        denylist
+            .expect_access_token_status()
+            .with(eq(jti), eq(USER_ID), eq(Role::Admin))
+            .returning(|_, _, _| Ok(AccessTokenStatus::UserChanged));
+
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_get_user_profile().never();
+
+        let req = test::TestRequest::get()
+            .uri("/api/v1/users/me")
+            .insert_header(("Authorization", format!("Bearer {}", token)));
+        let resp = call_user_api(mock_repo, denylist, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, "User has been deactivated or role has changed");
+    }
+
+    fn allow_all_tokens() -> MockTokenDenylistRepositories {
+        let mut denylist = MockTokenDenylistRepositories::new();
+        denylist.expect_access_token_status().returning(|_, _, _| Ok(AccessTokenStatus::Valid));
+        denylist
+    }
+
+    #[actix_web::test]
+    async fn test_login_deactivated_user() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_user_exists().returning(|_| Ok(User { active: false, ..mock_user() }));
+        mock_repo.expect_create_refresh_token().never();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/login")
+            .set_json(serde_json::json!({"username": "admin", "password": "Vrz!3Xn8"}));
+        let resp = call_user_api(mock_repo, MockTokenDenylistRepositories::new(), req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_register_disabled() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_create_user().never();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/register")
+            .set_json(serde_json::json!({"username": "newbie", "password": "password123"}));
+        let resp = call_user_api(mock_repo, MockTokenDenylistRepositories::new(), req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_register_assign_member_role() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_username_exists().with(eq("newbie")).returning(|_| Ok(false));
+        mock_repo
+            .expect_create_user()
+            .withf(|user| user.role_id == MEMBER_ROLE_ID && user.password != "password123")
+            .times(1)
+            .returning(|_| Ok(USER_ID));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/register")
+            .set_json(serde_json::json!({"username": "newbie", "password": "password123"}));
+        let resp = call_user_api_with_registration(mock_repo, MockTokenDenylistRepositories::new(), req, true).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<UserID> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data.id, USER_ID);
+    }
+
+    #[actix_web::test]
+    async fn test_register_duplicate_username() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_username_exists().returning(|_| Ok(true));
+        mock_repo.expect_create_user().never();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/register")
+            .set_json(serde_json::json!({"username": "admin", "password": "password123"}));
+        let resp = call_user_api_with_registration(mock_repo, MockTokenDenylistRepositories::new(), req, true).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_register_duplicate_username_race() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_username_exists().returning(|_| Ok(false));
+        mock_repo
+            .expect_create_user()
+            .times(1)
+            .returning(|user| Err(CustomError::DataConflict(format!("{}: {}", USERNAME_ALREADY_EXISTS, user.username))));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users/register")
+            .set_json(serde_json::json!({"username": "newbie", "password": "password123"}));
+        let resp = call_user_api_with_registration(mock_repo, MockTokenDenylistRepositories::new(), req, true).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_get_me() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_get_user_profile().with(eq(USER_ID)).returning(|id| Ok(UserProfile {
+            id,
+            username: "member1".to_string(),
+            role_id: Some(MEMBER_ROLE_ID),
+            role: Some("MEMBER".to_string()),
+            active: true,
+            created_at: Utc::now().naive_utc(),
+            updated_at: None,
+        }));
+
+        let req = test::TestRequest::get()
+            .uri("/api/v1/users/me")
+            .insert_header(("Authorization", format!("Bearer {}", create_token(USER_ID, Role::Member, SECRET))));
+        let resp = call_user_api(mock_repo, allow_all_tokens(), req).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<UserProfile> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data.username, "member1");
+    }
+
+    #[actix_web::test]
+    async fn test_create_user_requires_admin() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_create_user().never();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users")
+            .insert_header(("Authorization", format!("Bearer {}", create_token(USER_ID, Role::Manager, SECRET))))
+            .set_json(serde_json::json!({"username": "newbie", "password": "password123", "roleId": MEMBER_ROLE_ID}));
+        let resp = call_user_api(mock_repo, allow_all_tokens(), req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_create_user_invalid_role() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_create_user().never();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/users")
+            .insert_header(("Authorization", format!("Bearer {}", create_token(USER_ID, Role::Admin, SECRET))))
+            .set_json(serde_json::json!({"username": "newbie", "password": "password123", "roleId": 1}));
+        let resp = call_user_api(mock_repo, allow_all_tokens(), req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_deactivate_user_revoke_refresh_tokens() {
+        const TARGET_ID: i64 = 1844994649115070465;
+
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_get_user_profile().returning(|id| Ok(UserProfile {
+            id,
+            username: "member1".to_string(),
+            role_id: Some(MEMBER_ROLE_ID),
+            role: Some("MEMBER".to_string()),
+            active: true,
+            created_at: Utc::now().naive_utc(),
+            updated_at: None,
+        }));
+        mock_repo
+            .expect_update_user()
+            .withf(|user| user.id == TARGET_ID && !user.active)
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo.expect_revoke_user_refresh_tokens().with(eq(TARGET_ID)).times(1).returning(|_| Ok(()));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/users/{}", TARGET_ID))
+            .insert_header(("Authorization", format!("Bearer {}", create_token(USER_ID, Role::Admin, SECRET))));
+        let resp = call_user_api(mock_repo, allow_all_tokens(), req).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_deactivate_self_rejected() {
+        let mut mock_repo = MockAuthRepositories::new();
+        mock_repo.expect_update_user().never();
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/users/{}", USER_ID))
+            .insert_header(("Authorization", format!("Bearer {}", create_token(USER_ID, Role::Admin, SECRET))));
+        let resp = call_user_api(mock_repo, allow_all_tokens(), req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
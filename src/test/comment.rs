@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{http::StatusCode, test, web};
+    use mockall::predicate::eq;
+    use crate::application::use_cases::comment::CommentUseCaseImpl;
+    use crate::domain::entities::auth::Role;
+    use crate::domain::entities::comment::{Comment, CommentPage};
+    use crate::domain::entities::task::TaskCursor;
+    use crate::domain::repositories::comment::MockCommentRepositories;
+    use crate::domain::repositories::project::MockProjectRepositories;
+    use crate::domain::repositories::task::MockTaskRepositories;
+    use crate::infrastructure::api::handlers::comment::CommentHandler;
+    use crate::infrastructure::api::routes::comment::configure_comment_routes;
+    use crate::shared::exceptions::error_message::COMMENT_NOT_FOUND;
+    use crate::shared::middleware::response::{ApiResponse, ApiResponseErr};
+    use crate::test::common::{call_api, mock_project_repo, mock_task, MANAGER_ID, MEMBER_ID};
+
+    type CommentUseCaseMock = CommentUseCaseImpl<MockCommentRepositories, MockTaskRepositories, MockProjectRepositories>;
+
+    const VIEWER_ID: i64 = 1844995732965167104;
+    const TASK_ID: i64 = 7255500000000000000;
+    const COMMENT_ID: i64 = 7255600000000000000;
+
+    fn mock_comment(task_id: i64, created_by: i64) -> Comment {
+        Comment {
+            id: COMMENT_ID,
+            task_id,
+            body: "comment".to_string(),
+            created_by,
+            created_at: Default::default(),
+            updated_at: None,
+        }
+    }
+
+    async fn call_comment_api(mock_repo: MockCommentRepositories, req: test::TestRequest, user_id: i64, role: Role) -> ServiceResponse {
+        let mut task_repo = MockTaskRepositories::new();
+        task_repo.expect_get_task().with(eq(TASK_ID)).returning(|_| Ok(mock_task(TASK_ID, MEMBER_ID)));
+        // MANAGER_ID เป็น Admin ของ project, MEMBER_ID เป็น Member และ VIEWER_ID เป็น Viewer
+        let project_repo = mock_project_repo(false, vec![(MANAGER_ID, Role::Admin), (MEMBER_ID, Role::Member), (VIEWER_ID, Role::Viewer)]);
+
+        let comment_handler_data = web::Data::new(CommentHandler::new(CommentUseCaseImpl::new(mock_repo, task_repo, project_repo)));
+        call_api(comment_handler_data, configure_comment_routes::<CommentUseCaseMock>, req, user_id, role).await
+    }
+
+    #[actix_web::test]
+    async fn test_member_create_comment() {
+        let mut mock_repo = MockCommentRepositories::new();
+        mock_repo
+            .expect_create_comment()
+            .withf(|comment| comment.task_id == TASK_ID && comment.body == "looks good" && comment.created_by == MEMBER_ID)
+            .times(1)
+            .returning(|_| Ok(COMMENT_ID));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/comments", TASK_ID))
+            .set_json(serde_json::json!({ "body": "looks good" }));
+        let resp = call_comment_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_viewer_can_not_create_comment() {
+        let mut mock_repo = MockCommentRepositories::new();
+        mock_repo.expect_create_comment().never();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/comments", TASK_ID))
+            .set_json(serde_json::json!({ "body": "looks good" }));
+        let resp = call_comment_api(mock_repo, req, VIEWER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_list_comment_with_cursor() {
+        let mut mock_repo = MockCommentRepositories::new();
+        mock_repo
+            .expect_list_comment()
+            .withf(|filter| filter.task_id == TASK_ID && filter.limit == 1 && filter.cursor.as_ref().map(|cursor| cursor.id) == Some(1))
+            .returning(|_| Ok(CommentPage {
+                items: vec![mock_comment(TASK_ID, MEMBER_ID)],
+                next_cursor: Some(TaskCursor { id: COMMENT_ID, value: None }.encode()),
+                has_more: true,
+            }));
+
+        let cursor = TaskCursor { id: 1, value: None }.encode();
+        let req = test::TestRequest::get().uri(&format!("/api/v1/task/{}/comments?limit=1&cursor={}", TASK_ID, cursor));
+        let resp = call_comment_api(mock_repo, req, VIEWER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<Comment>> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data, vec![mock_comment(TASK_ID, MEMBER_ID)]);
+        assert!(body.meta.unwrap().has_more);
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_update_other_comment() {
+        let mut mock_repo = MockCommentRepositories::new();
+        mock_repo.expect_get_comment().with(eq(COMMENT_ID)).returning(|_| Ok(mock_comment(TASK_ID, MANAGER_ID)));
+        mock_repo.expect_update_comment().never();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/task/{}/comments/{}", TASK_ID, COMMENT_ID))
+            .set_json(serde_json::json!({ "body": "edited" }));
+        let resp = call_comment_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_project_admin_delete_other_comment() {
+        let mut mock_repo = MockCommentRepositories::new();
+        mock_repo.expect_get_comment().with(eq(COMMENT_ID)).returning(|_| Ok(mock_comment(TASK_ID, MEMBER_ID)));
+        mock_repo.expect_delete_comment().with(eq(COMMENT_ID)).times(1).returning(|_| Ok(()));
+
+        let req = test::TestRequest::delete().uri(&format!("/api/v1/task/{}/comments/{}", TASK_ID, COMMENT_ID));
+        let resp = call_comment_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_delete_comment_of_other_task() {
+        let mut mock_repo = MockCommentRepositories::new();
+        mock_repo.expect_get_comment().with(eq(COMMENT_ID)).returning(|_| Ok(mock_comment(1, MEMBER_ID)));
+        mock_repo.expect_delete_comment().never();
+
+        let req = test::TestRequest::delete().uri(&format!("/api/v1/task/{}/comments/{}", TASK_ID, COMMENT_ID));
+        let resp = call_comment_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with(COMMENT_NOT_FOUND));
+    }
+}
@@ -0,0 +1,179 @@
+use actix_web::dev::ServiceResponse;
+use actix_web::middleware::ErrorHandlers;
+use actix_web::{test, web, App};
+use std::sync::Arc;
+use crate::domain::entities::auth::{AccessTokenStatus, Role};
+use crate::domain::entities::master_data::{MasterDataPriorityLevels, MasterDataRole, MasterDataTaskStatus, MasterDataTaskStatusTransition};
+use crate::domain::entities::project::Project;
+use crate::domain::entities::task::Task;
+use crate::domain::repositories::auth::MockTokenDenylistRepositories;
+use crate::domain::repositories::master_data::MockMasterDataRepositories;
+use crate::domain::repositories::project::MockProjectRepositories;
+use crate::infrastructure::config::{load_env, ServerConfig};
+use crate::shared::exceptions::error_message::FAIL_TO_LOAD_ENV;
+use crate::shared::middleware::auth::JwtMiddleware;
+use crate::shared::middleware::errors::add_error_header;
+use crate::shared::middleware::jwt::create_token;
+use crate::shared::middleware::response::ApiResponseErr;
+
+// id ของ user และ master data ตามข้อมูลตั้งต้นใน migration
+pub const ADMIN_ID: i64 = 1844994649115070464;
+pub const MANAGER_ID: i64 = 1844995500256792576;
+pub const MEMBER_ID: i64 = 1844995683120058368;
+pub const PROJECT_ID: i64 = 7255437512398848000;
+pub const ADMIN_ROLE_ID: i64 = 7250548959330963456;
+pub const MEMBER_ROLE_ID: i64 = 7250549977582145536;
+pub const PENDING_ID: i64 = 7250066646188953600;
+pub const IN_PROGRESS_ID: i64 = 7250066663482068992;
+pub const COMPLETED_ID: i64 = 7250066683811860480;
+// สถานะที่มีแค่ใน test ใช้ตรวจรายการปลายทางที่ซ้ำกัน
+pub const CANCELLED_ID: i64 = 7250066683811869999;
+pub const P3_ID: i64 = 7250065969870016512;
+
+// middleware ที่ยอมรับทุก token ที่ถอดรหัสได้
+pub fn jwt_middleware(secret: String) -> JwtMiddleware {
+    let mut denylist = MockTokenDenylistRepositories::new();
+    denylist.expect_access_token_status().returning(|_, _, _| Ok(AccessTokenStatus::Valid));
+    JwtMiddleware::new(secret, Arc::new(denylist))
+}
+
+pub fn transition(from_status_id: i64, to_status_id: i64, code: &str, role_code: Option<&str>) -> MasterDataTaskStatusTransition {
+    MasterDataTaskStatusTransition {
+        from_status_id,
+        to_status_id,
+        title: code.to_string(),
+        code: code.to_string(),
+        role_code: role_code.map(|role_code| role_code.to_string()),
+    }
+}
+
+pub fn mock_master_data() -> MockMasterDataRepositories {
+    let mut master_data = MockMasterDataRepositories::new();
+    master_data.expect_list_role().returning(|| Ok(vec![
+        MasterDataRole { id: ADMIN_ROLE_ID, title: "Admin".to_string(), code: "ADMIN".to_string() },
+        MasterDataRole { id: MEMBER_ROLE_ID, title: "Member".to_string(), code: "MEMBER".to_string() },
+    ]));
+    master_data.expect_list_task_status().returning(|| Ok(vec![
+        MasterDataTaskStatus { id: PENDING_ID, title: "Pending".to_string(), code: "PENDING".to_string() },
+        MasterDataTaskStatus { id: IN_PROGRESS_ID, title: "In Progress".to_string(), code: "IN_PROGRESS".to_string() },
+        MasterDataTaskStatus { id: COMPLETED_ID, title: "Completed".to_string(), code: "COMPLETED".to_string() },
+    ]));
+    // workflow เดียวกับค่าเริ่มต้นใน migration
+    master_data.expect_list_task_status_transition().returning(|from_status_id| Ok(match from_status_id {
+        PENDING_ID => vec![transition(PENDING_ID, IN_PROGRESS_ID, "IN_PROGRESS", None)],
+        IN_PROGRESS_ID => vec![
+            transition(IN_PROGRESS_ID, PENDING_ID, "PENDING", None),
+            transition(IN_PROGRESS_ID, COMPLETED_ID, "COMPLETED", None),
+        ],
+        COMPLETED_ID => vec![
+            transition(COMPLETED_ID, IN_PROGRESS_ID, "IN_PROGRESS", Some("ADMIN")),
+            transition(COMPLETED_ID, IN_PROGRESS_ID, "IN_PROGRESS", Some("MANAGER")),
+        ],
+        // สถานะที่มีปลายทางซ้ำกันแบบไม่ติดกัน
+        CANCELLED_ID => vec![
+            transition(CANCELLED_ID, PENDING_ID, "PENDING", Some("ADMIN")),
+            transition(CANCELLED_ID, IN_PROGRESS_ID, "IN_PROGRESS", None),
+            transition(CANCELLED_ID, PENDING_ID, "PENDING", None),
+        ],
+        _ => vec![],
+    }));
+    master_data.expect_list_priority_levels().returning(|| Ok(vec![
+        MasterDataPriorityLevels { id: P3_ID, title: "Medium".to_string(), code: "P3".to_string() },
+    ]));
+    master_data
+}
+
+pub fn mock_task(id: i64, created_by: i64) -> Task {
+    Task {
+        id,
+        title: "task".to_string(),
+        description: None,
+        task_status_id: Some(IN_PROGRESS_ID),
+        priority_levels_id: Some(P3_ID),
+        created_by,
+        created_at: Default::default(),
+        updated_at: None,
+        updated_by: None,
+        assignees: vec![],
+        start_date: None,
+        due_date: None,
+        overdue: false,
+        deleted_at: None,
+        deleted_by: None,
+        version: 1,
+        project_id: PROJECT_ID,
+        parent_task_id: None,
+        subtask_count: 0,
+        completed_subtask_count: 0,
+        blocked_by: vec![],
+        blocks: vec![],
+        labels: vec![],
+        task_status: None,
+        priority_levels: None,
+        created_by_user: None,
+    }
+}
+
+pub fn project(archived: bool) -> Project {
+    Project {
+        id: PROJECT_ID,
+        name: "General".to_string(),
+        description: None,
+        archived_at: archived.then(Default::default),
+        archived_by: archived.then_some(ADMIN_ID),
+        created_by: ADMIN_ID,
+        created_at: Default::default(),
+        updated_at: None,
+        updated_by: None,
+    }
+}
+
+// project เดียวที่มีสมาชิกตาม members (user ที่ไม่อยู่ในรายการไม่ใช่สมาชิก)
+pub fn mock_project_repo(archived: bool, members: Vec<(i64, Role)>) -> MockProjectRepositories {
+    let mut project_repo = MockProjectRepositories::new();
+    project_repo.expect_get_project().returning(move |_| Ok(project(archived)));
+    project_repo.expect_member_role().returning(move |_, user_id| Ok(members
+        .iter()
+        .find(|(member_id, _)| *member_id == user_id)
+        .map(|(_, role)| *role)));
+    project_repo
+}
+
+// สร้าง app จาก handler และ routes ของ module แล้วยิง request ด้วย token ของ user/role ที่กำหนด
+pub async fn call_api<H: 'static>(
+    handler_data: web::Data<H>,
+    configure_routes: impl FnOnce(&mut web::ServiceConfig, JwtMiddleware),
+    req: test::TestRequest,
+    user_id: i64,
+    role: Role,
+) -> ServiceResponse {
+    load_env(".env.local").expect(FAIL_TO_LOAD_ENV);
+    let config = ServerConfig::from_env().unwrap();
+    let token = create_token(user_id, role, config.jwt_secret.clone().as_str());
+    let jwt_middleware = jwt_middleware(config.jwt_secret.clone());
+
+    let app = test::init_service(
+        App::new()
+            .wrap(ErrorHandlers::new().default_handler(add_error_header))
+            .service(
+                web::scope("/api/v1")
+                    .app_data(handler_data)
+                    .configure(|cfg| configure_routes(cfg, jwt_middleware))
+                ,
+            )
+    ).await;
+
+    // content type มาจาก request ของแต่ละ test (set_json ใส่ application/json ให้เอง)
+    let req = req
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+
+    test::call_service(&app, req).await.map_into_boxed_body()
+}
+
+// อ่าน message จาก body ของ response ที่ผิดพลาด
+pub async fn error_message(resp: ServiceResponse) -> String {
+    let body_bytes = test::read_body(resp).await;
+    let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+    body.message
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{http::StatusCode, test, web};
+    use crate::application::use_cases::label::LabelUseCaseImpl;
+    use crate::domain::entities::auth::Role;
+    use crate::domain::repositories::label::MockLabelRepositories;
+    use crate::infrastructure::api::handlers::label::LabelHandler;
+    use crate::infrastructure::api::routes::label::configure_label_routes;
+    use crate::shared::exceptions::error_message::{INVALID_LABEL_COLOR, LABEL_ALREADY_EXISTS};
+    use crate::test::common::{call_api, error_message, MANAGER_ID, MEMBER_ID};
+
+    const LABEL_ID: i64 = 7256000000000000000;
+
+    async fn call_label_api(mock_repo: MockLabelRepositories, req: test::TestRequest, user_id: i64, role: Role) -> ServiceResponse {
+        let label_handler_data = web::Data::new(LabelHandler::new(LabelUseCaseImpl::new(mock_repo)));
+        call_api(label_handler_data, configure_label_routes::<LabelUseCaseImpl<MockLabelRepositories>>, req, user_id, role).await
+    }
+
+    #[actix_web::test]
+    async fn test_manager_create_label() {
+        let mut mock_repo = MockLabelRepositories::new();
+        mock_repo.expect_label_name_exists().returning(|_, _| Ok(false));
+        mock_repo
+            .expect_create_label()
+            .withf(|label| label.name == "bug" && label.color == "#d73a4a" && label.created_by == MANAGER_ID)
+            .times(1)
+            .returning(|_| Ok(LABEL_ID));
+
+        let req = test::TestRequest::post().uri("/api/v1/labels").set_json(serde_json::json!({ "name": " bug ", "color": "#D73A4A" }));
+        let resp = call_label_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_create_label() {
+        let mut mock_repo = MockLabelRepositories::new();
+        mock_repo.expect_create_label().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/labels").set_json(serde_json::json!({ "name": "bug", "color": "#d73a4a" }));
+        let resp = call_label_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_create_label_duplicate_name() {
+        let mut mock_repo = MockLabelRepositories::new();
+        mock_repo.expect_label_name_exists().returning(|_, exclude_id| {
+            assert!(exclude_id.is_none());
+            Ok(true)
+        });
+        mock_repo.expect_create_label().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/labels").set_json(serde_json::json!({ "name": "Bug", "color": "#d73a4a" }));
+        let resp = call_label_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        assert!(error_message(resp).await.starts_with(LABEL_ALREADY_EXISTS));
+    }
+
+    #[actix_web::test]
+    async fn test_create_label_invalid_color() {
+        let mut mock_repo = MockLabelRepositories::new();
+        mock_repo.expect_create_label().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/labels").set_json(serde_json::json!({ "name": "bug", "color": "red" }));
+        let resp = call_label_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error_message(resp).await, INVALID_LABEL_COLOR);
+    }
+}
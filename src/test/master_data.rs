@@ -1,15 +1,51 @@
 #[cfg(test)]
 mod tests {
+    use actix_web::dev::ServiceResponse;
     use actix_web::middleware::ErrorHandlers;
-    use actix_web::{http::header::ContentType, test, web, App};
+    use actix_web::{http::header, http::header::ContentType, http::StatusCode, test, web, App};
+    use chrono::NaiveDateTime;
+    use std::sync::Arc;
+    use std::time::Duration;
     use crate::application::use_cases::master_data::MasterDataUseCaseImpl;
-    use crate::domain::entities::master_data::MasterDataTaskStatus;
-    use crate::domain::repositories::master_data::MockMasterDataRepositories;
+    use crate::domain::entities::auth::Role;
+    use crate::domain::entities::master_data::{
+        MasterDataItem, MasterDataKind, MasterDataTaskStatus, MasterDataTaskStatusTransition, TaskStatusTransition,
+    };
+    use crate::domain::repositories::master_data::{MasterDataRepositories, MockMasterDataAdminRepositories, MockMasterDataRepositories};
     use crate::infrastructure::api::handlers::master_data_handler::MasterDataHandler;
     use crate::infrastructure::api::routes::master_data_routes::configure_master_data_routes;
+    use crate::infrastructure::database::master_data_cache::{CachedMasterDataRepositories, MasterDataCache};
     use crate::shared::exceptions::custom_error::CustomError;
+    use crate::shared::exceptions::error_message::{INVALID_MASTER_DATA_ORDER, MASTER_DATA_IN_USE, PERMISSION_DENIED};
     use crate::shared::middleware::errors::add_error_header;
     use crate::shared::middleware::response::{ApiResponse, ApiResponseErr};
+    use crate::test::common::{call_api, error_message, jwt_middleware, ADMIN_ID, COMPLETED_ID, IN_PROGRESS_ID, MANAGER_ID, PENDING_ID};
+
+    type MockMasterDataUseCase = MasterDataUseCaseImpl<MockMasterDataRepositories, MockMasterDataAdminRepositories>;
+
+    fn status_item(id: i64, seq: i32, code: &str) -> MasterDataItem {
+        MasterDataItem {
+            id,
+            seq,
+            title: code.to_string(),
+            code: code.to_string(),
+            active: true,
+            created_by: ADMIN_ID,
+            created_at: NaiveDateTime::parse_from_str("2024-10-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            updated_at: None,
+            updated_by: None,
+        }
+    }
+
+    fn task_statuses() -> Vec<MasterDataItem> {
+        vec![status_item(PENDING_ID, 1, "PENDING"), status_item(IN_PROGRESS_ID, 2, "IN_PROGRESS"), status_item(COMPLETED_ID, 3, "COMPLETED")]
+    }
+
+    async fn call_admin_api(mock_admin_repo: MockMasterDataAdminRepositories, req: test::TestRequest, user_id: i64, role: Role) -> ServiceResponse {
+        let use_case = MasterDataUseCaseImpl::new(MockMasterDataRepositories::new(), mock_admin_repo);
+        let master_data_handler_data = web::Data::new(MasterDataHandler::new(use_case, 60));
+        call_api(master_data_handler_data, configure_master_data_routes::<MockMasterDataUseCase>, req, user_id, role).await
+    }
 
     #[actix_web::test]
     async fn test_success_master_data_task_status() {
@@ -52,8 +88,8 @@ mod tests {
                 },
             ]));
 
-        let use_case = MasterDataUseCaseImpl::new(mock_repo);
-        let handler = MasterDataHandler::new(use_case);
+        let use_case = MasterDataUseCaseImpl::new(mock_repo, MockMasterDataAdminRepositories::new());
+        let handler = MasterDataHandler::new(use_case, 60);
         let master_data_handler_data = web::Data::new(handler);
 
         let app = test::init_service(
@@ -63,7 +99,7 @@ mod tests {
                     web::scope("/api/v1")
                         .app_data(master_data_handler_data.clone())
                         .configure(|cfg| {
-                            configure_master_data_routes::<MasterDataUseCaseImpl<MockMasterDataRepositories>>(cfg)
+                            configure_master_data_routes::<MockMasterDataUseCase>(cfg, jwt_middleware("secret".to_string()))
                         })
                     ,
                 )
@@ -76,6 +112,8 @@ mod tests {
 
         let resp = test::call_service(&app, req).await;
         assert!(resp.status().is_success());
+        assert_eq!(resp.headers().get(header::CACHE_CONTROL).unwrap(), "public, max-age=60");
+        let etag = resp.headers().get(header::ETAG).unwrap().clone();
 
         let body_bytes = test::read_body(resp).await;
         let body: ApiResponse<Vec<MasterDataTaskStatus>> = serde_json::from_slice(&body_bytes).unwrap();
@@ -83,6 +121,17 @@ mod tests {
         assert_eq!(body.status, "success");
         assert_eq!(body.message, "get list task status successfully");
         assert_eq!(body.data, mock_data);
+
+        // ข้อมูลไม่เปลี่ยนตอบ 304 โดยไม่มี body
+        let req = test::TestRequest::get()
+            .uri("/api/v1/master-data/task-status")
+            .insert_header((header::IF_NONE_MATCH, etag.clone()))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(resp.headers().get(header::ETAG).unwrap(), &etag);
+        assert!(test::read_body(resp).await.is_empty());
     }
 
     #[actix_web::test]
@@ -92,8 +141,8 @@ mod tests {
             .expect_list_task_status()
             .returning(|| Err(CustomError::RepositoryError("Database query failed".to_string())));
 
-        let use_case = MasterDataUseCaseImpl::new(mock_repo);
-        let handler = MasterDataHandler::new(use_case);
+        let use_case = MasterDataUseCaseImpl::new(mock_repo, MockMasterDataAdminRepositories::new());
+        let handler = MasterDataHandler::new(use_case, 60);
         let master_data_handler_data = web::Data::new(handler);
 
         let app = test::init_service(
@@ -103,7 +152,7 @@ mod tests {
                     web::scope("/api/v1")
                         .app_data(master_data_handler_data.clone())
                         .configure(|cfg| {
-                            configure_master_data_routes::<MasterDataUseCaseImpl<MockMasterDataRepositories>>(cfg)
+                            configure_master_data_routes::<MockMasterDataUseCase>(cfg, jwt_middleware("secret".to_string()))
                         })
                     ,
                 )
@@ -123,4 +172,183 @@ mod tests {
         assert_eq!(body.status, "error");
         assert_eq!(body.message, "Internal Server Error");
     }
-}
\ No newline at end of file
+
+    #[actix_web::test]
+    async fn test_success_master_data_task_status_transition() {
+        let mut mock_repo = MockMasterDataRepositories::new();
+        mock_repo
+            .expect_list_task_status()
+            .returning(|| Ok(vec![
+                MasterDataTaskStatus {
+                    id: 7250066683811860480,
+                    title: "Completed".to_string(),
+                    code: "COMPLETED".to_string(),
+                },
+            ]));
+        mock_repo
+            .expect_list_task_status_transition()
+            .returning(|from_status_id| Ok(vec![
+                MasterDataTaskStatusTransition {
+                    from_status_id,
+                    to_status_id: 7250066663482068992,
+                    title: "In Progress".to_string(),
+                    code: "IN_PROGRESS".to_string(),
+                    role_code: Some("ADMIN".to_string()),
+                },
+                MasterDataTaskStatusTransition {
+                    from_status_id,
+                    to_status_id: 7250066663482068992,
+                    title: "In Progress".to_string(),
+                    code: "IN_PROGRESS".to_string(),
+                    role_code: Some("MANAGER".to_string()),
+                },
+            ]));
+
+        let use_case = MasterDataUseCaseImpl::new(mock_repo, MockMasterDataAdminRepositories::new());
+        let handler = MasterDataHandler::new(use_case, 60);
+        let master_data_handler_data = web::Data::new(handler);
+
+        let app = test::init_service(
+            App::new()
+                .wrap(ErrorHandlers::new().default_handler(add_error_header))
+                .service(
+                    web::scope("/api/v1")
+                        .app_data(master_data_handler_data.clone())
+                        .configure(|cfg| {
+                            configure_master_data_routes::<MockMasterDataUseCase>(cfg, jwt_middleware("secret".to_string()))
+                        })
+                    ,
+                )
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/v1/master-data/task-status/7250066683811860480/transitions")
+            .insert_header(ContentType::json())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<TaskStatusTransition>> = serde_json::from_slice(&body_bytes).unwrap();
+
+        assert_eq!(body.data, vec![TaskStatusTransition {
+            id: 7250066663482068992,
+            title: "In Progress".to_string(),
+            code: "IN_PROGRESS".to_string(),
+            roles: vec!["ADMIN".to_string(), "MANAGER".to_string()],
+        }]);
+
+        let req = test::TestRequest::get()
+            .uri("/api/v1/master-data/task-status/1/transitions")
+            .insert_header(ContentType::json())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+    #[actix_web::test]
+    async fn test_admin_create_task_status() {
+        let mut mock_admin_repo = MockMasterDataAdminRepositories::new();
+        mock_admin_repo.expect_master_data_code_exists().returning(|_, _| Ok(false));
+        mock_admin_repo
+            .expect_create_master_data()
+            .withf(|kind, item| *kind == MasterDataKind::TaskStatus && item.code == "BLOCKED" && item.title == "Blocked" && item.created_by == ADMIN_ID)
+            .times(1)
+            .returning(|_, _| Ok(7257000000000000000));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/master-data/task-status")
+            .set_json(serde_json::json!({ "title": " Blocked ", "code": "BLOCKED" }));
+        let resp = call_admin_api(mock_admin_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        // code ต้องเป็นตัวพิมพ์ใหญ่
+        let req = test::TestRequest::post()
+            .uri("/api/v1/master-data/task-status")
+            .set_json(serde_json::json!({ "title": "Blocked", "code": "blocked" }));
+        let resp = call_admin_api(MockMasterDataAdminRepositories::new(), req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_manager_cannot_manage_master_data() {
+        let req = test::TestRequest::post()
+            .uri("/api/v1/master-data/priority-levels")
+            .set_json(serde_json::json!({ "title": "Urgent", "code": "P0" }));
+        let resp = call_admin_api(MockMasterDataAdminRepositories::new(), req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(error_message(resp).await, PERMISSION_DENIED);
+    }
+
+    #[actix_web::test]
+    async fn test_deactivate_task_status_in_use() {
+        let mut mock_admin_repo = MockMasterDataAdminRepositories::new();
+        mock_admin_repo
+            .expect_get_master_data()
+            .returning(|_, id| Ok(task_statuses().into_iter().find(|item| item.id == id).unwrap()));
+        mock_admin_repo.expect_list_master_data().returning(|_| Ok(task_statuses()));
+        mock_admin_repo
+            .expect_deactivate_master_data()
+            .withf(|_, deactivate| deactivate.id == IN_PROGRESS_ID && deactivate.replacement_id.is_none())
+            .times(1)
+            .returning(|_, _| Err(CustomError::DataConflict(format!("{}: {}", MASTER_DATA_IN_USE, 2))));
+
+        let req = test::TestRequest::post().uri(&format!("/api/v1/master-data/task-status/{}/deactivate", IN_PROGRESS_ID));
+        let resp = call_admin_api(mock_admin_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // COMPLETED ใช้ตรวจ dependency ของ task จึงปิดใช้งานไม่ได้
+        let mut mock_admin_repo = MockMasterDataAdminRepositories::new();
+        mock_admin_repo
+            .expect_get_master_data()
+            .returning(|_, id| Ok(task_statuses().into_iter().find(|item| item.id == id).unwrap()));
+        mock_admin_repo.expect_deactivate_master_data().never();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/master-data/task-status/{}/deactivate", COMPLETED_ID))
+            .set_json(serde_json::json!({ "replacementId": PENDING_ID }));
+        let resp = call_admin_api(mock_admin_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_reorder_task_status_requires_every_id() {
+        let mut mock_admin_repo = MockMasterDataAdminRepositories::new();
+        mock_admin_repo.expect_list_master_data().returning(|_| Ok(task_statuses()));
+        mock_admin_repo.expect_reorder_master_data().never();
+
+        let req = test::TestRequest::put()
+            .uri("/api/v1/master-data/task-status/order")
+            .set_json(serde_json::json!({ "ids": [COMPLETED_ID, PENDING_ID, PENDING_ID] }));
+        let resp = call_admin_api(mock_admin_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error_message(resp).await, INVALID_MASTER_DATA_ORDER);
+    }
+    #[actix_web::test]
+    async fn test_master_data_cache_invalidation() {
+        let mut mock_repo = MockMasterDataRepositories::new();
+        mock_repo
+            .expect_list_task_status()
+            .times(2)
+            .returning(|| Ok(vec![MasterDataTaskStatus {
+                id: PENDING_ID,
+                title: "Pending".to_string(),
+                code: "PENDING".to_string(),
+            }]));
+
+        let cache = Arc::new(MasterDataCache::new(Duration::from_secs(300)));
+        let repository = CachedMasterDataRepositories::new(mock_repo, Arc::clone(&cache));
+
+        // ครั้งที่สองอ่านจาก cache
+        assert_eq!(repository.list_task_status().await.unwrap().len(), 1);
+        assert_eq!(repository.list_task_status().await.unwrap().len(), 1);
+
+        // ล้าง kind อื่นไม่กระทบ
+        cache.invalidate(MasterDataKind::PriorityLevels);
+        assert_eq!(repository.list_task_status().await.unwrap().len(), 1);
+
+        cache.invalidate(MasterDataKind::TaskStatus);
+        assert_eq!(repository.list_task_status().await.unwrap().len(), 1);
+    }
+}
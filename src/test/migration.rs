@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::database::migration::{pending_versions, MigrationCommand, MIGRATIONS};
+
+    #[test]
+    fn test_migrations_are_ordered_and_complete() {
+        let versions: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).collect();
+        let mut sorted = versions.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(versions, sorted);
+        assert!(MIGRATIONS.iter().all(|m| !m.up.trim().is_empty() && !m.down.trim().is_empty()));
+    }
+
+    #[test]
+    fn test_pending_versions() {
+        let latest = MIGRATIONS.last().unwrap().version;
+
+        assert_eq!(pending_versions(None).len(), MIGRATIONS.len());
+        assert!(pending_versions(Some(latest)).is_empty());
+    }
+
+    #[test]
+    fn test_parse_migration_command() {
+        assert_eq!("up".parse::<MigrationCommand>().unwrap(), MigrationCommand::Up);
+        assert_eq!("down".parse::<MigrationCommand>().unwrap(), MigrationCommand::Down);
+        assert_eq!("status".parse::<MigrationCommand>().unwrap(), MigrationCommand::Status);
+        assert!("force".parse::<MigrationCommand>().is_err());
+    }
+}
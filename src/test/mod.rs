@@ -1,2 +1,10 @@
+mod attachment;
+mod auth;
+mod comment;
+#[cfg(test)]
+mod common;
+mod label;
 mod master_data;
+mod migration;
+mod project;
 mod task;
\ No newline at end of file
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{http::StatusCode, test, web};
+    use mockall::predicate::eq;
+    use crate::application::use_cases::project::ProjectUseCaseImpl;
+    use crate::domain::entities::auth::Role;
+    use crate::domain::entities::project::{Project, ProjectMember};
+    use crate::domain::repositories::master_data::MockMasterDataRepositories;
+    use crate::domain::repositories::project::MockProjectRepositories;
+    use crate::infrastructure::api::handlers::project::ProjectHandler;
+    use crate::infrastructure::api::routes::project::configure_project_routes;
+    use crate::shared::exceptions::error_message::{LAST_PROJECT_ADMIN, PROJECT_ARCHIVED, PROJECT_HAS_TASK};
+    use crate::shared::middleware::response::ApiResponse;
+    use crate::test::common::{call_api, error_message, mock_master_data, mock_project_repo, project, ADMIN_ID, ADMIN_ROLE_ID, MANAGER_ID, MEMBER_ID, MEMBER_ROLE_ID, PROJECT_ID};
+
+    fn member(user_id: i64, role_id: i64, role: &str) -> ProjectMember {
+        ProjectMember {
+            user_id,
+            username: "user".to_string(),
+            role_id,
+            role: role.to_string(),
+            added_by: ADMIN_ID,
+            added_at: Default::default(),
+        }
+    }
+
+    // MANAGER_ID เป็น Admin ของ project ส่วน MEMBER_ID เป็น Member
+    fn mock_repo(archived: bool) -> MockProjectRepositories {
+        mock_project_repo(archived, vec![(MANAGER_ID, Role::Admin), (MEMBER_ID, Role::Member)])
+    }
+
+    async fn call_project_api(mock_repo: MockProjectRepositories, req: test::TestRequest, user_id: i64, role: Role) -> ServiceResponse {
+        let project_handler_data = web::Data::new(ProjectHandler::new(ProjectUseCaseImpl::new(mock_repo, mock_master_data())));
+        call_api(project_handler_data, configure_project_routes::<ProjectUseCaseImpl<MockProjectRepositories, MockMasterDataRepositories>>, req, user_id, role).await
+    }
+
+    #[actix_web::test]
+    async fn test_manager_create_project() {
+        let mut mock_repo = MockProjectRepositories::new();
+        mock_repo
+            .expect_create_project()
+            .withf(|project| project.name == "Website" && project.created_by == MANAGER_ID)
+            .times(1)
+            .returning(|_| Ok(PROJECT_ID));
+
+        let req = test::TestRequest::post().uri("/api/v1/projects").set_json(serde_json::json!({ "name": "Website" }));
+        let resp = call_project_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_create_project() {
+        let mut mock_repo = MockProjectRepositories::new();
+        mock_repo.expect_create_project().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/projects").set_json(serde_json::json!({ "name": "Website" }));
+        let resp = call_project_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_member_list_project_limited_to_membership() {
+        let mut mock_repo = MockProjectRepositories::new();
+        mock_repo
+            .expect_list_project()
+            .withf(|filter| filter.member_id == Some(MEMBER_ID) && !filter.archived)
+            .returning(|_| Ok(vec![project(false)]));
+
+        let resp = call_project_api(mock_repo, test::TestRequest::get().uri("/api/v1/projects"), MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<Project>> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data, vec![project(false)]);
+    }
+
+    #[actix_web::test]
+    async fn test_non_member_can_not_get_project() {
+        let resp = call_project_api(
+            mock_repo(false),
+            test::TestRequest::get().uri(&format!("/api/v1/projects/{}", PROJECT_ID)),
+            1844995732965167104,
+            Role::Manager,
+        ).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_project_member_can_not_update_project() {
+        let mut mock_repo = mock_repo(false);
+        mock_repo.expect_update_project().never();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/projects/{}", PROJECT_ID))
+            .set_json(serde_json::json!({ "name": "Website" }));
+        let resp = call_project_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_update_archived_project() {
+        let mut mock_repo = mock_repo(true);
+        mock_repo.expect_update_project().never();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/projects/{}", PROJECT_ID))
+            .set_json(serde_json::json!({ "name": "Website" }));
+        let resp = call_project_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error_message(resp).await, PROJECT_ARCHIVED);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_project_with_task() {
+        let mut mock_repo = mock_repo(false);
+        mock_repo.expect_count_task().with(eq(PROJECT_ID)).returning(|_| Ok(1));
+        mock_repo.expect_delete_project().never();
+
+        let req = test::TestRequest::delete().uri(&format!("/api/v1/projects/{}", PROJECT_ID));
+        let resp = call_project_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        assert_eq!(error_message(resp).await, PROJECT_HAS_TASK);
+    }
+
+    #[actix_web::test]
+    async fn test_set_project_member() {
+        let mut mock_repo = mock_repo(false);
+        mock_repo.expect_is_active_user().with(eq(MEMBER_ID)).returning(|_| Ok(true));
+        mock_repo.expect_list_member().returning(|_| Ok(vec![member(MANAGER_ID, ADMIN_ROLE_ID, "ADMIN")]));
+        mock_repo
+            .expect_set_member()
+            .withf(|member| member.user_id == MEMBER_ID && member.role_id == ADMIN_ROLE_ID && member.added_by == MANAGER_ID)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/projects/{}/members/{}", PROJECT_ID, MEMBER_ID))
+            .set_json(serde_json::json!({ "roleId": ADMIN_ROLE_ID }));
+        let resp = call_project_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_remove_last_project_admin() {
+        let mut mock_repo = mock_repo(false);
+        mock_repo.expect_list_member().returning(|_| Ok(vec![
+            member(MANAGER_ID, ADMIN_ROLE_ID, "ADMIN"),
+            member(MEMBER_ID, MEMBER_ROLE_ID, "MEMBER"),
+        ]));
+        mock_repo.expect_remove_member().never();
+
+        let req = test::TestRequest::delete().uri(&format!("/api/v1/projects/{}/members/{}", PROJECT_ID, MANAGER_ID));
+        let resp = call_project_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error_message(resp).await, LAST_PROJECT_ADMIN);
+    }
+}
@@ -1,104 +1,105 @@
 #[cfg(test)]
 mod tests {
-    use actix_web::middleware::ErrorHandlers;
-    use actix_web::{http::header::ContentType, test, web, App};
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{http::StatusCode, test, web};
+    use mockall::predicate::eq;
     use crate::application::use_cases::task::TaskUseCaseImpl;
-    use crate::domain::entities::task::{Task, TaskID};
+    use crate::domain::entities::auth::Role;
+    use crate::domain::entities::master_data::MasterDataRef;
+    use crate::domain::repositories::master_data::MockMasterDataRepositories;
+    use crate::domain::repositories::project::MockProjectRepositories;
+    use crate::domain::entities::task::{LabelMatch, SortDirection, Task, TaskBulkAction, TaskImportError, TaskImportResult, TaskBulkItemResult, TaskBulkMode, TaskBulkResult, TaskCursor, TaskFieldChange, TaskHistory, TaskHistoryOperation, TaskExpand, TaskHistoryPage, TaskID, TaskMasterData, TaskPage, TaskSearchPage, TaskSortField, TaskUser};
     use crate::domain::repositories::task::MockTaskRepositories;
     use crate::infrastructure::api::handlers::task::TaskHandler;
     use crate::infrastructure::api::requests::task::TaskRequest;
     use crate::infrastructure::api::routes::task::configure_task_routes;
-    use crate::infrastructure::config::{load_env, ServerConfig};
-    use crate::shared::exceptions::error_message::FAIL_TO_LOAD_ENV;
-    use crate::shared::middleware::errors::add_error_header;
-    use crate::shared::middleware::jwt::create_token;
-    use crate::shared::middleware::response::ApiResponse;
+    use crate::shared::exceptions::custom_error::CustomError;
+    use crate::shared::exceptions::error_message::{
+        INVALID_TASK_BULK_OPERATION, INVALID_TASK_STATUS, PARENT_TASK_PROJECT_MISMATCH, PERMISSION_DENIED, PROJECT_ARCHIVED, TASK_BLOCKED, TASK_BULK_DUPLICATE_TASK, TASK_DEPENDENCY_CYCLE, TASK_HIERARCHY_CYCLE, TASK_HIERARCHY_TOO_DEEP, TASK_NOT_FOUND,
+        TASK_NOT_FOUND_IN_TRASH, TASK_VERSION_MISMATCH,
+    };
+    use crate::shared::middleware::response::{ApiResponse, ApiResponseErr};
+    use crate::test::common::{
+        call_api, mock_master_data, mock_project_repo, mock_task, project, ADMIN_ID, CANCELLED_ID, COMPLETED_ID, IN_PROGRESS_ID, MANAGER_ID, MEMBER_ID, PENDING_ID, PROJECT_ID,
+    };
+
+    type TaskUseCaseMock = TaskUseCaseImpl<MockTaskRepositories, MockMasterDataRepositories, MockProjectRepositories>;
+
+    const OTHER_MEMBER_ID: i64 = 1844995732965167104;
+    const VIEWER_ID: i64 = 1844995732965167999;
+    const TASK_ID: i64 = 548753961092383042;
+
+    // ทุก user เป็นสมาชิกของ project ด้วย role เดียวกับ role ของระบบ
+    fn mock_project_with(archived: bool) -> MockProjectRepositories {
+        mock_project_repo(archived, vec![
+            (MANAGER_ID, Role::Manager),
+            (MEMBER_ID, Role::Member),
+            (OTHER_MEMBER_ID, Role::Member),
+            (VIEWER_ID, Role::Viewer),
+        ])
+    }
+
+    fn mock_project() -> MockProjectRepositories {
+        mock_project_with(false)
+    }
+
+    fn task_request() -> TaskRequest {
+        TaskRequest {
+            project_id: PROJECT_ID,
+            parent_task_id: None,
+            title: "member".to_string(),
+            description: None,
+            task_status_id: MasterDataRef::Id(7250066646188953600),
+            priority_levels_id: MasterDataRef::Id(7250065969870016512),
+            start_date: None,
+            due_date: None,
+        }
+    }
+
+    // สร้าง app ด้วย mock repository แล้วยิง request ด้วย token ของ user/role ที่กำหนด
+    async fn call_task_api(mock_repo: MockTaskRepositories, req: test::TestRequest, user_id: i64, role: Role) -> ServiceResponse {
+        call_task_api_with_project(mock_repo, mock_project(), req, user_id, role).await
+    }
+
+    async fn call_task_api_with_project(
+        mock_repo: MockTaskRepositories,
+        project_repo: MockProjectRepositories,
+        req: test::TestRequest,
+        user_id: i64,
+        role: Role,
+    ) -> ServiceResponse {
+        let task_handler_data = web::Data::new(TaskHandler::new(TaskUseCaseImpl::new(mock_repo, mock_master_data(), project_repo)));
+        call_api(task_handler_data, configure_task_routes::<TaskUseCaseMock>, req, user_id, role).await
+    }
+
+    async fn assert_forbidden(resp: ServiceResponse) {
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.status, "error");
+    }
 
     #[actix_web::test]
     async fn test_success_get_task() {
         let mock_data = vec![
-            Task {
-                id: 548753961092383042,
-                title: "member".to_string(),
-                description: None,
-                task_status_id: Some(7250066663482068992),
-                priority_levels_id: Some(7250065969870016512),
-                created_by: CREATED_BY,
-                created_at: Default::default(),
-                updated_at: None,
-                updated_by: None,
-            },
-            Task {
-                id: 548753961092383042,
-                title: "member2".to_string(),
-                description: None,
-                task_status_id: Some(7250066663482068992),
-                priority_levels_id: Some(7250065969870016512),
-                created_by: CREATED_BY,
-                created_at: Default::default(),
-                updated_at: None,
-                updated_by: None,
-            },
+            Task { title: "member".to_string(), ..mock_task(548753961092383042, CREATED_BY) },
+            Task { title: "member2".to_string(), ..mock_task(548753961092383042, CREATED_BY) },
         ];
 
-        load_env(".env.local").expect(FAIL_TO_LOAD_ENV);
-        let config = ServerConfig::from_env().unwrap();
-
         const CREATED_BY: i64 = 1844995683120058368;
-        let token = create_token(CREATED_BY, config.jwt_secret.clone().as_str());
 
         let mut mock_repo = MockTaskRepositories::new();
         mock_repo
             .expect_list_task()
-            .returning(|| Ok(vec![
-                Task {
-                    id: 548753961092383042,
-                    title: "member".to_string(),
-                    description: None,
-                    task_status_id: Some(7250066663482068992),
-                    priority_levels_id: Some(7250065969870016512),
-                    created_by: CREATED_BY,
-                    created_at: Default::default(),
-                    updated_at: None,
-                    updated_by: None,
-                },
-                Task {
-                    id: 548753961092383042,
-                    title: "member2".to_string(),
-                    description: None,
-                    task_status_id: Some(7250066663482068992),
-                    priority_levels_id: Some(7250065969870016512),
-                    created_by: CREATED_BY,
-                    created_at: Default::default(),
-                    updated_at: None,
-                    updated_by: None,
-                },
-            ]));
-
-        let use_case = TaskUseCaseImpl::new(mock_repo);
-        let handler = TaskHandler::new(use_case);
-        let master_data_handler_data = web::Data::new(handler);
-
-        let app = test::init_service(
-            App::new()
-                .wrap(ErrorHandlers::new().default_handler(add_error_header))
-                .service(
-                    web::scope("/api/v1")
-                        .app_data(master_data_handler_data.clone())
-                        .configure(|cfg| {
-                            configure_task_routes::<TaskUseCaseImpl<MockTaskRepositories>>(cfg, config.jwt_secret.clone())
-                        })
-                    ,
-                )
-        ).await;
-
-        let req = test::TestRequest::get()
-            .uri("/api/v1/task")
-            .insert_header(ContentType::json())
-            .insert_header(("Authorization", format!("Bearer {}", token)))
-            .to_request();
-
-        let resp = test::call_service(&app, req).await;
+            .withf(|filter| filter.member_id == Some(CREATED_BY))
+            .returning(|_| Ok(TaskPage { items: vec![
+                Task { title: "member".to_string(), ..mock_task(548753961092383042, CREATED_BY) },
+                Task { title: "member2".to_string(), ..mock_task(548753961092383042, CREATED_BY) },
+            ], next_cursor: None, has_more: false }));
+
+        let req = test::TestRequest::get().uri("/api/v1/task");
+        let resp = call_task_api(mock_repo, req, CREATED_BY, Role::Member).await;
         assert!(resp.status().is_success());
 
         let body_bytes = test::read_body(resp).await;
@@ -114,51 +115,26 @@ mod tests {
         let task = TaskRequest {
             title: "member".to_string(),
             description: None,
-            task_status_id: 7250066646188953600,
-            priority_levels_id: 7250065969870016512,
+            task_status_id: MasterDataRef::Id(7250066646188953600),
+            priority_levels_id: MasterDataRef::Id(7250065969870016512),
+            ..task_request()
         };
 
-
-        load_env(".env.local").expect(FAIL_TO_LOAD_ENV);
-        let config = ServerConfig::from_env().unwrap();
-
         const CREATED_BY: i64 = 1844995683120058368;
         const ID: i64 = 549543142173442373;
         let mock_data = TaskID {
             id: ID
         };
-        let token = create_token(CREATED_BY, config.jwt_secret.clone().as_str());
 
         let mut mock_repo = MockTaskRepositories::new();
         mock_repo
             .expect_create_task()
             .returning(|_| Ok(ID));
 
-        let use_case = TaskUseCaseImpl::new(mock_repo);
-        let handler = TaskHandler::new(use_case);
-        let master_data_handler_data = web::Data::new(handler);
-
-        let app = test::init_service(
-            App::new()
-                .wrap(ErrorHandlers::new().default_handler(add_error_header))
-                .service(
-                    web::scope("/api/v1")
-                        .app_data(master_data_handler_data.clone())
-                        .configure(|cfg| {
-                            configure_task_routes::<TaskUseCaseImpl<MockTaskRepositories>>(cfg, config.jwt_secret.clone())
-                        })
-                    ,
-                )
-        ).await;
-
         let req = test::TestRequest::post()
             .set_json(task)
-            .uri("/api/v1/task")
-            .insert_header(ContentType::json())
-            .insert_header(("Authorization", format!("Bearer {}", token)))
-            .to_request();
-
-        let resp = test::call_service(&app, req).await;
+            .uri("/api/v1/task");
+        let resp = call_task_api(mock_repo, req, CREATED_BY, Role::Member).await;
         assert!(resp.status().is_success());
 
         let body_bytes = test::read_body(resp).await;
@@ -168,4 +144,1237 @@ mod tests {
         assert_eq!(body.message, "Task created successfully");
         assert_eq!(body.data, mock_data);
     }
-}
\ No newline at end of file
+
+    #[actix_web::test]
+    async fn test_admin_list_all_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.created_by.is_none())
+            .returning(|_| Ok(TaskPage { items: vec![mock_task(TASK_ID, MEMBER_ID)], next_cursor: None, has_more: false }));
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task"), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_admin_delete_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_delete_task().with(eq(TASK_ID), eq(ADMIN_ID), eq(Some(1))).times(1).returning(|_, _, _| Ok(()));
+
+        let req = test::TestRequest::delete().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID));
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_manager_update_task_of_other_user() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, OTHER_MEMBER_ID)));
+        mock_repo.expect_update_task().times(1).returning(|_| Ok(()));
+
+        let req = test::TestRequest::put().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(task_request());
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_manager_can_not_delete_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_delete_task().never();
+
+        let req = test::TestRequest::delete().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_delete_own_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_delete_task().never();
+
+        let req = test::TestRequest::delete().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_member_update_own_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_open_blocker_ids().returning(|_| Ok(vec![]));
+        mock_repo.expect_update_task_status().times(1).returning(|_| Ok(()));
+
+        let req = test::TestRequest::patch().insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": 7250066683811860480_i64 }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_update_task_of_other_user() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, OTHER_MEMBER_ID)));
+        mock_repo.expect_update_task().never();
+
+        let req = test::TestRequest::put().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(task_request());
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_get_task_of_other_user() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, OTHER_MEMBER_ID)));
+
+        let req = test::TestRequest::get().uri(&format!("/api/v1/task/{}", TASK_ID));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_viewer_can_not_create_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_create_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(task_request());
+        let resp = call_task_api(mock_repo, req, VIEWER_ID, Role::Viewer).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_with_filter_and_pagination() {
+        let cursor = TaskCursor { id: TASK_ID, value: Some("task".to_string()) }.encode();
+        let next_cursor = TaskCursor { id: 548753961092383000, value: Some("task".to_string()) }.encode();
+
+        let mut mock_repo = MockTaskRepositories::new();
+        let expected_cursor = cursor.clone();
+        let returned_cursor = next_cursor.clone();
+        mock_repo
+            .expect_list_task()
+            .withf(move |filter| {
+                filter.task_status_id == Some(7250066663482068992)
+                    && filter.search.as_deref() == Some("bug")
+                    && filter.sort_by == TaskSortField::Title
+                    && filter.sort_direction == SortDirection::Asc
+                    && filter.limit == 1
+                    && filter.cursor.as_ref().map(|c| c.encode()) == Some(expected_cursor.clone())
+            })
+            .returning(move |_| Ok(TaskPage {
+                items: vec![mock_task(548753961092383000, MANAGER_ID)],
+                next_cursor: Some(returned_cursor.clone()),
+                has_more: true,
+            }));
+
+        let uri = format!("/api/v1/task?taskStatusId=7250066663482068992&q=bug&sortBy=title&sortDirection=asc&limit=1&cursor={}", cursor);
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(&uri), MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<Task>> = serde_json::from_slice(&body_bytes).unwrap();
+        let meta = body.meta.unwrap();
+
+        assert_eq!(body.data.len(), 1);
+        assert!(meta.has_more);
+        assert_eq!(meta.next_cursor, Some(next_cursor));
+    }
+
+    #[actix_web::test]
+    async fn test_member_list_task_limited_to_owned_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.member_id == Some(MEMBER_ID) && filter.created_by == Some(OTHER_MEMBER_ID))
+            .returning(|_| Ok(TaskPage { items: vec![], next_cursor: None, has_more: false }));
+
+        let uri = format!("/api/v1/task?createdBy={}", OTHER_MEMBER_ID);
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(&uri), MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_invalid_query() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_list_task().never();
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task?cursor=not-a-cursor"), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_list_task().never();
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task?limit=1000"), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_assignee_me() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.assignee == Some(MANAGER_ID) && filter.member_id == Some(MANAGER_ID))
+            .returning(|_| Ok(TaskPage { items: vec![], next_cursor: None, has_more: false }));
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task?assignee=me"), MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_list_task().never();
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task?assignee=someone"), MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_member_update_assigned_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(Task {
+            assignees: vec![MEMBER_ID],
+            ..mock_task(id, OTHER_MEMBER_ID)
+        }));
+        mock_repo.expect_update_task().times(1).returning(|_| Ok(()));
+
+        let req = test::TestRequest::put().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(task_request());
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_assign_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_active_user_ids().returning(|user_ids, _| Ok(user_ids));
+        mock_repo
+            .expect_assign_task()
+            .withf(|assign| assign.task_id == TASK_ID && assign.user_ids == vec![MEMBER_ID, OTHER_MEMBER_ID] && assign.assigned_by == MANAGER_ID)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/assignees", TASK_ID))
+            .set_json(serde_json::json!({"userIds": [OTHER_MEMBER_ID, MEMBER_ID, OTHER_MEMBER_ID]}));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_assign_task_inactive_user() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_active_user_ids().returning(|_, _| Ok(vec![MEMBER_ID]));
+        mock_repo.expect_assign_task().never();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/assignees", TASK_ID))
+            .set_json(serde_json::json!({"userIds": [MEMBER_ID, OTHER_MEMBER_ID]}));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.contains(&OTHER_MEMBER_ID.to_string()));
+    }
+
+    #[actix_web::test]
+    async fn test_viewer_can_not_assign_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_assign_task().never();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/assignees", TASK_ID))
+            .set_json(serde_json::json!({"userIds": [MEMBER_ID]}));
+        assert_forbidden(call_task_api(mock_repo, req, VIEWER_ID, Role::Viewer).await).await;
+    }
+
+    #[actix_web::test]
+    async fn test_unassign_task_not_assigned() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_unassign_task().with(eq(TASK_ID), eq(MEMBER_ID), eq(MANAGER_ID)).returning(|_, _, _| Ok(false));
+
+        let req = test::TestRequest::delete().uri(&format!("/api/v1/task/{}/assignees/{}", TASK_ID, MEMBER_ID));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_label_filter() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.labels == vec!["bug".to_string(), "frontend".to_string()] && filter.label_match == LabelMatch::All)
+            .returning(|_| Ok(TaskPage { items: vec![], next_cursor: None, has_more: false }));
+
+        let uri = "/api/v1/task?label=Frontend,bug,,frontend&labelMatch=all";
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(uri), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_member_search_task_limited_to_visible_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_search_task()
+            .withf(|filter| filter.search.as_deref() == Some("login redirect") && filter.member_id == Some(MEMBER_ID) && !filter.deleted)
+            .times(1)
+            .returning(|_| Ok(TaskSearchPage { items: vec![], next_cursor: None, has_more: false }));
+
+        let uri = "/api/v1/task/search?q=%20login%20redirect%20";
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(uri), MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_search_task_without_query() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_search_task().never();
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task/search?q=%20"), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_add_unknown_label_to_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_existing_label_ids().returning(|_| Ok(vec![1]));
+        mock_repo.expect_add_labels().never();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/labels", TASK_ID))
+            .set_json(serde_json::json!({"labelIds": [1, 2]}));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.ends_with(": 2"));
+    }
+
+    #[actix_web::test]
+    async fn test_create_task_start_date_after_due_date() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_create_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(serde_json::json!({
+            "projectId": PROJECT_ID,
+            "title": "task",
+            "taskStatusId": 7250066646188953600_i64,
+            "priorityLevelsId": 7250065969870016512_i64,
+            "startDate": "2026-10-20T09:00:00",
+            "dueDate": "2026-10-19T18:00:00",
+        }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_with_schedule() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MANAGER_ID)));
+        mock_repo
+            .expect_update_task()
+            .withf(|task| task.start_date.is_some() && task.due_date.is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = test::TestRequest::put().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(serde_json::json!({
+            "projectId": PROJECT_ID,
+            "title": "task",
+            "taskStatusId": 7250066646188953600_i64,
+            "priorityLevelsId": 7250065969870016512_i64,
+            "startDate": "2026-10-19T09:00:00",
+            "dueDate": "2026-10-19T18:00:00",
+        }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_due_filter() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.due_before.is_some() && filter.due_this_week && filter.overdue == Some(true))
+            .returning(|_| Ok(TaskPage { items: vec![], next_cursor: None, has_more: false }));
+
+        let uri = "/api/v1/task?dueBefore=2026-10-25T00:00:00&dueThisWeek=true&overdue=true";
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(uri), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_create_task_with_master_data_code() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_create_task()
+            .withf(|task| task.task_status_id == 7250066663482068992 && task.priority_levels_id == 7250065969870016512)
+            .times(1)
+            .returning(|_| Ok(TASK_ID));
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(serde_json::json!({
+            "projectId": PROJECT_ID,
+            "title": "task",
+            "taskStatusId": "IN_PROGRESS",
+            "priorityLevelsId": "7250065969870016512",
+        }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_task_invalid_master_data() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_create_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(serde_json::json!({
+            "projectId": PROJECT_ID,
+            "title": "task",
+            "taskStatusId": 1,
+            "priorityLevelsId": "P3",
+        }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with("taskStatusId"));
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_priority_levels_inactive() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_update_task_priority_levels().never();
+
+        let req = test::TestRequest::patch().insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/priority-levels", TASK_ID))
+            .set_json(serde_json::json!({"priorityLevelsId": "P1"}));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with("priorityLevelsId"));
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_status_not_allowed_transition() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { task_status_id: Some(PENDING_ID), ..mock_task(id, MEMBER_ID) }));
+        mock_repo.expect_update_task_status().never();
+
+        let req = test::TestRequest::patch().insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": "COMPLETED" }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, "Task status transition not allowed. Allowed next status: IN_PROGRESS");
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_status_allowed_codes_unique() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { task_status_id: Some(CANCELLED_ID), ..mock_task(id, MEMBER_ID) }));
+        mock_repo.expect_update_task_status().never();
+
+        let req = test::TestRequest::patch().insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": "COMPLETED" }));
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, "Task status transition not allowed. Allowed next status: IN_PROGRESS, PENDING");
+    }
+
+    #[actix_web::test]
+    async fn test_reopen_completed_task_restricted_by_role() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { task_status_id: Some(COMPLETED_ID), ..mock_task(id, MEMBER_ID) }));
+        mock_repo.expect_update_task_status().never();
+
+        let req = test::TestRequest::patch().insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": "IN_PROGRESS" }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { task_status_id: Some(COMPLETED_ID), ..mock_task(id, MEMBER_ID) }));
+        mock_repo
+            .expect_update_task_status()
+            .withf(|task| task.task_status_id == IN_PROGRESS_ID)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = test::TestRequest::patch().insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": "IN_PROGRESS" }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_enforces_status_transition() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { task_status_id: Some(COMPLETED_ID), ..mock_task(id, MANAGER_ID) }));
+        mock_repo.expect_update_task().never();
+
+        // task_request ส่งสถานะ PENDING ซึ่งไปจาก COMPLETED ตรงๆ ไม่ได้
+        let req = test::TestRequest::put().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(task_request());
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_history() {
+        let next_cursor = TaskCursor { id: 2, value: None }.encode();
+        let cursor = next_cursor.clone();
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo
+            .expect_list_task_history()
+            .withf(|filter| filter.task_id == TASK_ID && filter.limit == 1 && filter.cursor.is_none())
+            .returning(move |_| Ok(TaskHistoryPage {
+                items: vec![TaskHistory {
+                    id: 2,
+                    task_id: TASK_ID,
+                    operation: TaskHistoryOperation::Update,
+                    changes: vec![TaskFieldChange {
+                        field: "task_status_id".to_string(),
+                        old: serde_json::json!(PENDING_ID),
+                        new: serde_json::json!(IN_PROGRESS_ID),
+                    }],
+                    actor_id: MANAGER_ID,
+                    created_at: Default::default(),
+                }],
+                next_cursor: Some(cursor.clone()),
+                has_more: true,
+            }));
+
+        let uri = format!("/api/v1/task/{}/history?limit=1", TASK_ID);
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(&uri), VIEWER_ID, Role::Viewer).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<TaskHistory>> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data[0].operation, TaskHistoryOperation::Update);
+        assert_eq!(body.data[0].changes[0].field, "task_status_id");
+        assert_eq!(body.meta.unwrap().next_cursor, Some(next_cursor));
+    }
+
+    #[actix_web::test]
+    async fn test_member_can_not_get_history_of_other_user_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, OTHER_MEMBER_ID)));
+        mock_repo.expect_list_task_history().never();
+
+        let uri = format!("/api/v1/task/{}/history", TASK_ID);
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(&uri), MEMBER_ID, Role::Member).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_admin_list_trash() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.deleted)
+            .returning(|_| Ok(TaskPage {
+                items: vec![Task { deleted_at: Some(Default::default()), deleted_by: Some(ADMIN_ID), ..mock_task(TASK_ID, MEMBER_ID) }],
+                next_cursor: None,
+                has_more: false,
+            }));
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task/trash"), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<Task>> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data[0].deleted_by, Some(ADMIN_ID));
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_excludes_deleted_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| !filter.deleted)
+            .returning(|_| Ok(TaskPage { items: vec![], next_cursor: None, has_more: false }));
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task"), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_manager_can_not_restore_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_deleted_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_restore_task().never();
+
+        let req = test::TestRequest::post().uri(&format!("/api/v1/task/{}/restore", TASK_ID));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_forbidden(resp).await;
+    }
+
+    #[actix_web::test]
+    async fn test_restore_task_not_in_trash() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_get_deleted_task()
+            .with(eq(TASK_ID))
+            .returning(|_| Err(CustomError::NotFound(TASK_NOT_FOUND_IN_TRASH.to_string())));
+        mock_repo.expect_restore_task().never();
+
+        let req = test::TestRequest::post().uri(&format!("/api/v1/task/{}/restore", TASK_ID));
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_get_task_returns_etag() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(Task { version: 3, ..mock_task(id, MEMBER_ID) }));
+
+        let uri = format!("/api/v1/task/{}", TASK_ID);
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(&uri), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+        assert_eq!(resp.headers().get("ETag").unwrap(), "\"3\"");
+    }
+
+    #[actix_web::test]
+    async fn test_get_task_with_expand() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().never();
+        mock_repo
+            .expect_get_task_expanded()
+            .withf(|id, expand| *id == TASK_ID && *expand == TaskExpand { status: true, priority: false, created_by: true })
+            .times(1)
+            .returning(|id, _| Ok(Task {
+                task_status: Some(TaskMasterData { id: IN_PROGRESS_ID, code: "IN_PROGRESS".to_string(), title: "In Progress".to_string() }),
+                created_by_user: Some(TaskUser { id: MEMBER_ID, username: "member1".to_string() }),
+                ..mock_task(id, MEMBER_ID)
+            }));
+
+        let uri = format!("/api/v1/task/{}?expand=createdBy,status", TASK_ID);
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri(&uri), MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body["data"]["task_status_id"], IN_PROGRESS_ID);
+        assert_eq!(body["data"]["task_status"]["code"], "IN_PROGRESS");
+        assert_eq!(body["data"]["created_by_user"]["username"], "member1");
+        // ไม่ได้ขอ priority จึงไม่มี field นี้
+        assert!(body["data"].get("priority_levels").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_list_task_invalid_expand() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_list_task().never();
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task?expand=status,assignees"), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_without_if_match() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_update_task().never();
+
+        let req = test::TestRequest::put().uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(task_request());
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::PRECONDITION_REQUIRED);
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_with_stale_version() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { version: 2, ..mock_task(id, MEMBER_ID) }));
+        mock_repo
+            .expect_update_task()
+            .withf(|task| task.version == Some(1))
+            .returning(|task| Err(CustomError::PreconditionFailed(format!("{}: {}", TASK_VERSION_MISMATCH, task.id))));
+
+        let req = test::TestRequest::put().insert_header(("If-Match", "\"1\"")).uri(&format!("/api/v1/task/{}", TASK_ID)).set_json(task_request());
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_task_with_if_match_any() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_delete_task().with(eq(TASK_ID), eq(ADMIN_ID), eq(None)).times(1).returning(|_, _, _| Ok(()));
+
+        let req = test::TestRequest::delete().insert_header(("If-Match", "*")).uri(&format!("/api/v1/task/{}", TASK_ID));
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_update_task_status_invalid_if_match() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_update_task_status().never();
+
+        let req = test::TestRequest::patch()
+            .insert_header(("If-Match", "W/\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": "COMPLETED" }));
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    fn patch_request(body: serde_json::Value) -> test::TestRequest {
+        test::TestRequest::patch()
+            .insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}", TASK_ID))
+            .set_json(body)
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_clear_description() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo
+            .expect_patch_task()
+            .withf(|task| {
+                task.description == Some(None)
+                    && task.title.is_none()
+                    && task.task_status_id.is_none()
+                    && task.priority_levels_id.is_none()
+                    && task.due_date.is_none()
+                    && task.version == Some(1)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let resp = call_task_api(mock_repo, patch_request(serde_json::json!({ "description": null })), MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_with_master_data_code() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_open_blocker_ids().returning(|_| Ok(vec![]));
+        mock_repo
+            .expect_patch_task()
+            .withf(|task| task.task_status_id == Some(COMPLETED_ID) && task.description == Some(Some("done".to_string())))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let body = serde_json::json!({ "taskStatusId": "COMPLETED", "description": "done" });
+        let resp = call_task_api(mock_repo, patch_request(body), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_merge_patch_content_type() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo
+            .expect_patch_task()
+            .withf(|task| task.title == Some("renamed".to_string()) && task.description.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = patch_request(serde_json::json!({ "title": "renamed" }))
+            .insert_header(("Content-Type", "application/merge-patch+json"));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_title_can_not_be_null() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_patch_task().never();
+
+        let resp = call_task_api(mock_repo, patch_request(serde_json::json!({ "title": null })), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_empty_body() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_patch_task().never();
+
+        let resp = call_task_api(mock_repo, patch_request(serde_json::json!({})), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_validates_schedule_with_current_value() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task {
+            due_date: Some("2024-01-10T00:00:00".parse().unwrap()),
+            ..mock_task(id, MEMBER_ID)
+        }));
+        mock_repo.expect_patch_task().never();
+
+        let body = serde_json::json!({ "startDate": "2024-02-01T00:00:00" });
+        let resp = call_task_api(mock_repo, patch_request(body), ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_create_task_in_archived_project() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_create_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(task_request());
+        let resp = call_task_api_with_project(mock_repo, mock_project_with(true), req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, PROJECT_ARCHIVED);
+    }
+
+    #[actix_web::test]
+    async fn test_list_project_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.project_id == Some(PROJECT_ID) && filter.member_id == Some(MEMBER_ID))
+            .returning(|_| Ok(TaskPage { items: vec![mock_task(TASK_ID, MEMBER_ID)], next_cursor: None, has_more: false }));
+
+        let req = test::TestRequest::get().uri(&format!("/api/v1/projects/{}/tasks", PROJECT_ID));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_non_member_can_not_list_project_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_list_task().never();
+
+        let req = test::TestRequest::get().uri(&format!("/api/v1/projects/{}/tasks", PROJECT_ID));
+        assert_forbidden(call_task_api(mock_repo, req, 1844995732965167000, Role::Manager).await).await;
+    }
+
+    #[actix_web::test]
+    async fn test_list_subtask() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(Task { subtask_count: 1, ..mock_task(id, MEMBER_ID) }));
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.parent_task_id == Some(TASK_ID) && filter.project_id == Some(PROJECT_ID) && filter.member_id == Some(MEMBER_ID))
+            .returning(|_| Ok(TaskPage { items: vec![Task { parent_task_id: Some(TASK_ID), ..mock_task(1, MEMBER_ID) }], next_cursor: None, has_more: false }));
+
+        let req = test::TestRequest::get().uri(&format!("/api/v1/task/{}/subtasks", TASK_ID));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<Vec<Task>> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data[0].parent_task_id, Some(TASK_ID));
+    }
+
+    #[actix_web::test]
+    async fn test_create_subtask_parent_in_other_project() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().with(eq(TASK_ID)).returning(|id| Ok(Task { project_id: 1, ..mock_task(id, MANAGER_ID) }));
+        mock_repo.expect_create_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(TaskRequest { parent_task_id: Some(TASK_ID), ..task_request() });
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with(PARENT_TASK_PROJECT_MISMATCH));
+    }
+
+    #[actix_web::test]
+    async fn test_create_subtask_too_deep() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MANAGER_ID)));
+        // task แม่อยู่ชั้นที่ 5 แล้ว
+        mock_repo.expect_task_ancestor_ids().with(eq(TASK_ID)).returning(|id| Ok(vec![id, 4, 3, 2, 1]));
+        mock_repo.expect_create_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task").set_json(TaskRequest { parent_task_id: Some(TASK_ID), ..task_request() });
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with(TASK_HIERARCHY_TOO_DEEP));
+    }
+
+    #[actix_web::test]
+    async fn test_patch_task_parent_cycle() {
+        const SUBTASK_ID: i64 = 548753961092383043;
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MANAGER_ID)));
+        // SUBTASK_ID เป็น subtask ของ TASK_ID อยู่แล้ว
+        mock_repo.expect_task_ancestor_ids().with(eq(SUBTASK_ID)).returning(|id| Ok(vec![id, TASK_ID]));
+        mock_repo.expect_patch_task().never();
+
+        let req = test::TestRequest::patch()
+            .insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}", TASK_ID))
+            .set_json(serde_json::json!({ "parentTaskId": SUBTASK_ID }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with(TASK_HIERARCHY_CYCLE));
+    }
+
+    #[actix_web::test]
+    async fn test_restore_subtask_with_parent_in_trash() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_deleted_task().returning(|id| Ok(Task { parent_task_id: Some(1), ..mock_task(id, MEMBER_ID) }));
+        mock_repo.expect_get_task().with(eq(1)).returning(|_| Err(CustomError::NotFound(TASK_NOT_FOUND.to_string())));
+        mock_repo.expect_restore_task().never();
+
+        let req = test::TestRequest::post().uri(&format!("/api/v1/task/{}/restore", TASK_ID));
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_add_task_dependency() {
+        const BLOCKER_ID: i64 = 548753961092383043;
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_transitive_blocker_ids().with(eq(BLOCKER_ID)).returning(|_| Ok(vec![1, 2]));
+        mock_repo
+            .expect_add_dependency()
+            .withf(|dependency| dependency.task_id == TASK_ID && dependency.blocked_by_task_id == BLOCKER_ID && dependency.created_by == MANAGER_ID)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/dependencies", TASK_ID))
+            .set_json(serde_json::json!({ "blockedByTaskId": BLOCKER_ID }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_add_task_dependency_cycle() {
+        const BLOCKER_ID: i64 = 548753961092383043;
+
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        // BLOCKER_ID ถูก TASK_ID block อยู่แล้วผ่าน task อื่น
+        mock_repo.expect_transitive_blocker_ids().with(eq(BLOCKER_ID)).returning(|_| Ok(vec![1, TASK_ID]));
+        mock_repo.expect_add_dependency().never();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/task/{}/dependencies", TASK_ID))
+            .set_json(serde_json::json!({ "blockedByTaskId": BLOCKER_ID }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(body.message.starts_with(TASK_DEPENDENCY_CYCLE));
+    }
+
+    #[actix_web::test]
+    async fn test_remove_task_dependency_not_found() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(mock_task(id, MEMBER_ID)));
+        mock_repo.expect_remove_dependency().with(eq(TASK_ID), eq(1), eq(MANAGER_ID)).returning(|_, _, _| Ok(false));
+
+        let req = test::TestRequest::delete().uri(&format!("/api/v1/task/{}/dependencies/1", TASK_ID));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_complete_blocked_task() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(Task { blocked_by: vec![1], ..mock_task(id, MEMBER_ID) }));
+        mock_repo.expect_open_blocker_ids().with(eq(TASK_ID)).returning(|_| Ok(vec![1]));
+        mock_repo.expect_update_task_status().never();
+
+        let req = test::TestRequest::patch()
+            .insert_header(("If-Match", "\"1\""))
+            .uri(&format!("/api/v1/task/{}/task-status", TASK_ID))
+            .set_json(serde_json::json!({ "taskStatusId": "COMPLETED" }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, format!("{}: 1", TASK_BLOCKED));
+    }
+    #[actix_web::test]
+    async fn test_bulk_task_best_effort() {
+        const OTHER_TASK_ID: i64 = 2;
+        const THIRD_TASK_ID: i64 = 3;
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(match id {
+            OTHER_TASK_ID => mock_task(id, OTHER_MEMBER_ID),
+            _ => mock_task(id, MEMBER_ID),
+        }));
+        mock_repo.expect_open_blocker_ids().returning(|_| Ok(vec![]));
+        // task ของคนอื่นไม่ถูกส่งไปเขียน ส่วนที่เหลือทำใน transaction เดียว
+        mock_repo
+            .expect_bulk_task()
+            .withf(|items, mode, actor_id| {
+                *mode == TaskBulkMode::BestEffort
+                    && *actor_id == MEMBER_ID
+                    && items.len() == 2
+                    && items[0].task_id == TASK_ID
+                    && items[0].action == TaskBulkAction::UpdateStatus(COMPLETED_ID)
+                    && items[1].task_id == THIRD_TASK_ID
+                    && items[1].action == TaskBulkAction::UpdatePriority(7250065969870016512)
+            })
+            .times(1)
+            .returning(|items, _, _| Ok(items
+                .iter()
+                .map(|item| TaskBulkItemResult::new(item.operation_index, item.action.code(), item.task_id, None))
+                .collect()));
+
+        let req = test::TestRequest::post().uri("/api/v1/task/bulk").set_json(serde_json::json!({
+            "mode": "bestEffort",
+            "operations": [
+                { "op": "updateStatus", "taskIds": [TASK_ID, OTHER_TASK_ID, TASK_ID], "taskStatusId": "COMPLETED" },
+                { "op": "updatePriority", "taskIds": [THIRD_TASK_ID], "priorityLevelsId": "P3" },
+            ],
+        }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<TaskBulkResult> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!((body.data.succeeded, body.data.failed), (2, 1));
+        let items: Vec<(usize, i64, bool)> = body.data.items.iter().map(|item| (item.operation_index, item.task_id, item.success)).collect();
+        assert_eq!(items, vec![(0, TASK_ID, true), (0, OTHER_TASK_ID, false), (1, THIRD_TASK_ID, true)]);
+        assert_eq!(body.data.items[1].error.as_deref(), Some(PERMISSION_DENIED));
+    }
+
+    #[actix_web::test]
+    async fn test_bulk_task_atomic_rejects_all() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().returning(|id| Ok(match id {
+            2 => mock_task(id, OTHER_MEMBER_ID),
+            _ => mock_task(id, MEMBER_ID),
+        }));
+        mock_repo.expect_bulk_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task/bulk").set_json(serde_json::json!({
+            "operations": [
+                { "op": "updatePriority", "taskIds": [TASK_ID], "priorityLevelsId": "P3" },
+                { "op": "updatePriority", "taskIds": [3, 2], "priorityLevelsId": "P3" },
+            ],
+        }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, format!("operations[1] task 2: {}", PERMISSION_DENIED));
+    }
+
+    #[actix_web::test]
+    async fn test_bulk_task_rejects_status_chain_on_same_task() {
+        // PENDING -> IN_PROGRESS -> COMPLETED ถ้าตรวจกับสถานะก่อน bulk ขั้นที่สองจะไม่ผ่าน จึงปฏิเสธทั้ง request
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().never();
+        mock_repo.expect_bulk_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task/bulk").set_json(serde_json::json!({
+            "mode": "bestEffort",
+            "operations": [
+                { "op": "updateStatus", "taskIds": [TASK_ID], "taskStatusId": "IN_PROGRESS" },
+                { "op": "updateStatus", "taskIds": [TASK_ID], "taskStatusId": "COMPLETED" },
+            ],
+        }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, format!("{}: operations[1] task {}", TASK_BULK_DUPLICATE_TASK, TASK_ID));
+    }
+
+    #[actix_web::test]
+    async fn test_bulk_task_rejects_update_after_delete() {
+        // task ที่ถูกลบใน operation ก่อนหน้าต้องไม่ถูกแก้ไขต่อในถังขยะ
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_get_task().never();
+        mock_repo.expect_bulk_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task/bulk").set_json(serde_json::json!({
+            "operations": [
+                { "op": "delete", "taskIds": [2, TASK_ID] },
+                { "op": "updatePriority", "taskIds": [TASK_ID], "priorityLevelsId": "P3" },
+            ],
+        }));
+        let resp = call_task_api(mock_repo, req, MEMBER_ID, Role::Member).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, format!("{}: operations[1] task {}", TASK_BULK_DUPLICATE_TASK, TASK_ID));
+    }
+
+    #[actix_web::test]
+    async fn test_bulk_task_missing_operation_field() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_bulk_task().never();
+
+        let req = test::TestRequest::post().uri("/api/v1/task/bulk").set_json(serde_json::json!({
+            "operations": [{ "op": "assign", "taskIds": [TASK_ID] }],
+        }));
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponseErr = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.message, INVALID_TASK_BULK_OPERATION);
+    }
+
+    #[actix_web::test]
+    async fn test_export_task_csv_streams_all_pages() {
+        let mut mock_repo = MockTaskRepositories::new();
+        let mut seq = mockall::Sequence::new();
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.cursor.is_none() && filter.limit == 100 && filter.expand.status && filter.expand.priority)
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(TaskPage {
+                items: vec![Task { description: Some("line 1\nsay \"hi\", ok".to_string()), ..mock_task(TASK_ID, MEMBER_ID) }],
+                next_cursor: Some(TaskCursor { id: TASK_ID, value: None }.encode()),
+                has_more: true,
+            }));
+        mock_repo
+            .expect_list_task()
+            .withf(|filter| filter.cursor == Some(TaskCursor { id: TASK_ID, value: None }))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(TaskPage { items: vec![mock_task(2, MEMBER_ID)], next_cursor: None, has_more: false }));
+
+        let req = test::TestRequest::get().uri("/api/v1/task/export?format=csv&limit=5");
+        let resp = call_task_api(mock_repo, req, ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+        assert_eq!(resp.headers().get("content-type").unwrap(), "text/csv; charset=utf-8");
+
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        let lines: Vec<&str> = body.split("\r\n").collect();
+        assert!(lines[0].starts_with("id,project_id,parent_task_id,title,description,task_status_id,task_status,"));
+        assert!(lines[1].starts_with(&format!("{},{},,task,\"line 1\nsay \"\"hi\"\", ok\",", TASK_ID, PROJECT_ID)));
+        assert!(lines[2].starts_with(&format!("2,{},", PROJECT_ID)));
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[actix_web::test]
+    async fn test_export_task_json() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_list_task()
+            .returning(|_| Ok(TaskPage { items: vec![mock_task(TASK_ID, MEMBER_ID), mock_task(2, MEMBER_ID)], next_cursor: None, has_more: false }));
+
+        let resp = call_task_api(mock_repo, test::TestRequest::get().uri("/api/v1/task/export"), ADMIN_ID, Role::Admin).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let tasks: Vec<Task> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(tasks, vec![mock_task(TASK_ID, MEMBER_ID), mock_task(2, MEMBER_ID)]);
+    }
+
+    #[actix_web::test]
+    async fn test_import_task_reports_row_errors() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_create_tasks().never();
+
+        let csv = format!(
+            "project_id,title,description,task_status,priority_levels_id,due_date\r\n\
+             {0},first,\"multi\nline, quoted\",PENDING,P3,2030-01-01T00:00:00\r\n\
+             {0},second,,UNKNOWN,P3,\r\n\
+             {0},,,PENDING,P3,\r\n\
+             {0},fourth,,PENDING,P3,tomorrow\r\n",
+            PROJECT_ID,
+        );
+        let req = test::TestRequest::post().uri("/api/v1/task/import?format=csv").set_payload(csv);
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<TaskImportResult> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!((body.data.total, body.data.valid, body.data.imported), (4, 1, 0));
+        let rows: Vec<usize> = body.data.errors.iter().map(|error| error.row).collect();
+        assert_eq!(rows, vec![2, 3, 4]);
+        assert_eq!(body.data.errors[0], TaskImportError { row: 2, error: format!("{}: UNKNOWN", INVALID_TASK_STATUS) });
+        assert!(body.data.errors[1].error.starts_with("title"));
+        assert_eq!(body.data.errors[2].error, "due_date: invalid value tomorrow");
+    }
+
+    #[actix_web::test]
+    async fn test_import_task_ndjson() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo
+            .expect_create_tasks()
+            .withf(|tasks| {
+                tasks.len() == 2
+                    && tasks[0].title == "first"
+                    && tasks[0].task_status_id == PENDING_ID
+                    && tasks[1].task_status_id == IN_PROGRESS_ID
+                    && tasks.iter().all(|task| task.created_by == MANAGER_ID)
+            })
+            .times(1)
+            .returning(|_| Ok(vec![11, 12]));
+
+        let ndjson = format!(
+            "{{\"project_id\":{0},\"title\":\"first\",\"task_status_id\":\"PENDING\",\"priority_levels_id\":\"P3\"}}\n\n\
+             {{\"project_id\":{0},\"title\":\"second\",\"task_status_id\":{1},\"priority_levels_id\":\"P3\",\"id\":1}}\n",
+            PROJECT_ID, IN_PROGRESS_ID,
+        );
+        let req = test::TestRequest::post().uri("/api/v1/task/import?format=ndjson").set_payload(ndjson);
+        let resp = call_task_api(mock_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<TaskImportResult> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data.task_ids, vec![11, 12]);
+        assert_eq!(body.data.imported, 2);
+        assert!(body.data.errors.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_import_task_checks_project_once() {
+        let mut mock_repo = MockTaskRepositories::new();
+        mock_repo.expect_create_tasks().times(1).returning(|tasks| Ok((1..=tasks.len() as i64).collect()));
+        let mut project_repo = MockProjectRepositories::new();
+        project_repo.expect_get_project().times(1).returning(|_| Ok(project(false)));
+        project_repo.expect_member_role().times(1).returning(|_, _| Ok(Some(Role::Manager)));
+
+        let csv = format!(
+            "project_id,title,task_status,priority_levels_id\r\n\
+             {0},first,PENDING,P3\r\n\
+             {0},second,PENDING,P3\r\n\
+             {0},third,IN_PROGRESS,P3\r\n",
+            PROJECT_ID,
+        );
+        let req = test::TestRequest::post().uri("/api/v1/task/import?format=csv").set_payload(csv);
+        let resp = call_task_api_with_project(mock_repo, project_repo, req, MANAGER_ID, Role::Manager).await;
+        assert!(resp.status().is_success());
+
+        let body_bytes = test::read_body(resp).await;
+        let body: ApiResponse<TaskImportResult> = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body.data.imported, 3);
+    }
+}